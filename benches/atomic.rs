@@ -0,0 +1,60 @@
+use std::thread;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use reactivate::{AtomicReactive, Reactive};
+
+const THREADS: usize = 4;
+const UPDATES_PER_THREAD: usize = 10_000;
+
+/// Spawns `THREADS` threads that each call `op` `UPDATES_PER_THREAD` times on the same reactive.
+fn contended<R: Clone + Send + 'static>(reactive: &R, op: fn(&R)) {
+    let threads: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let reactive = reactive.clone();
+            thread::spawn(move || {
+                for _ in 0..UPDATES_PER_THREAD {
+                    op(&reactive);
+                }
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
+
+fn reads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("value");
+
+    let mutex = Reactive::new(0u64);
+    group.bench_function("Reactive", |b| b.iter(|| black_box(mutex.value())));
+
+    let atomic = AtomicReactive::new(0u64);
+    group.bench_function("AtomicReactive", |b| b.iter(|| black_box(atomic.value())));
+
+    group.finish();
+}
+
+fn contended_increments(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended_increments");
+
+    let mutex = Reactive::new(0u64);
+    group.bench_function(BenchmarkId::new("Reactive::update", THREADS), |b| {
+        b.iter(|| contended(&mutex, |r| r.update(|n| n + 1)))
+    });
+
+    let atomic = AtomicReactive::new(0u64);
+    group.bench_function(BenchmarkId::new("AtomicReactive::update", THREADS), |b| {
+        b.iter(|| contended(&atomic, |r| _ = r.update(|n| n + 1)))
+    });
+    group.bench_function(
+        BenchmarkId::new("AtomicReactive::fetch_add", THREADS),
+        |b| b.iter(|| contended(&atomic, |r| _ = r.fetch_add(1))),
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, reads, contended_increments);
+criterion_main!(benches);
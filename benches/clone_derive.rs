@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use reactivate::Reactive;
+
+fn clone(c: &mut Criterion) {
+    let r = Reactive::new(0u64);
+    c.bench_function("clone", |b| b.iter(|| black_box(r.clone())));
+}
+
+fn derive_and_drop(c: &mut Criterion) {
+    c.bench_function("derive_and_drop", |b| {
+        b.iter(|| {
+            let r = Reactive::new(0u64);
+            let d = r.derive(|n| n + 1);
+            black_box(d.value())
+        })
+    });
+}
+
+fn derive_chain(c: &mut Criterion) {
+    c.bench_function("derive_chain_100", |b| {
+        b.iter(|| {
+            let root = Reactive::new(0u64);
+            let mut last = root.clone();
+            for _ in 0..100 {
+                last = last.derive(|n| n + 1);
+            }
+            root.update(|n| n + 1);
+            black_box(last.value())
+        })
+    });
+}
+
+criterion_group!(benches, clone, derive_and_drop, derive_chain);
+criterion_main!(benches);
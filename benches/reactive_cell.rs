@@ -0,0 +1,64 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use reactivate::{Reactive, ReactiveCell};
+
+const READERS: usize = 4;
+const READS_PER_THREAD: usize = 100_000;
+
+/// Spawns `READERS` threads that each call `value()` `READS_PER_THREAD` times
+/// while a writer keeps updating it in the background.
+fn contended_values<R: Clone + Send + 'static>(reactive: &R, read: fn(&R) -> u64, write: fn(&R)) {
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = thread::spawn({
+        let reactive = reactive.clone();
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::Relaxed) {
+                write(&reactive);
+            }
+        }
+    });
+
+    let readers: Vec<_> = (0..READERS)
+        .map(|_| {
+            let reactive = reactive.clone();
+            thread::spawn(move || {
+                for _ in 0..READS_PER_THREAD {
+                    black_box(read(&reactive));
+                }
+            })
+        })
+        .collect();
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    done.store(true, Ordering::Relaxed);
+    writer.join().unwrap();
+}
+
+fn value_under_contention(c: &mut Criterion) {
+    let mut group = c.benchmark_group("value_under_contention");
+
+    let mutex = Reactive::new(0u64);
+    group.bench_function(BenchmarkId::new("Reactive", READERS), |b| {
+        b.iter(|| contended_values(&mutex, |r| r.value(), |r| r.update_unchecked(|n| n + 1)))
+    });
+
+    let cell = ReactiveCell::new(0u64);
+    group.bench_function(BenchmarkId::new("ReactiveCell", READERS), |b| {
+        b.iter(|| contended_values(&cell, |r| r.value(), |r| r.update_unchecked(|n| n + 1)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, value_under_contention);
+criterion_main!(benches);
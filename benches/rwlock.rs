@@ -0,0 +1,76 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use reactivate::{Reactive, RwReactive};
+
+const READERS: usize = 4;
+const READS_PER_THREAD: usize = 1_000;
+
+/// Spawns `READERS` threads that each read the value `READS_PER_THREAD` times
+/// while a writer keeps updating it in the background.
+fn contended_reads<R: Clone + Send + 'static>(reactive: &R, read: fn(&R) -> usize, write: fn(&R)) {
+    let done = Arc::new(AtomicBool::new(false));
+    let writer = thread::spawn({
+        let reactive = reactive.clone();
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::Relaxed) {
+                write(&reactive);
+            }
+        }
+    });
+
+    let readers: Vec<_> = (0..READERS)
+        .map(|_| {
+            let reactive = reactive.clone();
+            thread::spawn(move || {
+                for _ in 0..READS_PER_THREAD {
+                    black_box(read(&reactive));
+                }
+            })
+        })
+        .collect();
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    done.store(true, Ordering::Relaxed);
+    writer.join().unwrap();
+}
+
+fn read_throughput(c: &mut Criterion) {
+    let mut group = c.benchmark_group("read_throughput");
+
+    let mutex = Reactive::new(vec![0usize; 64]);
+    group.bench_function(BenchmarkId::new("Reactive", READERS), |b| {
+        b.iter(|| {
+            contended_reads(
+                &mutex,
+                |r| r.borrowed().iter().sum(),
+                |r| r.update_inplace_unchecked(|v| v[0] += 1),
+            )
+        })
+    });
+
+    let rwlock = RwReactive::new(vec![0usize; 64]);
+    group.bench_function(BenchmarkId::new("RwReactive", READERS), |b| {
+        b.iter(|| {
+            contended_reads(
+                &rwlock,
+                |r| r.borrowed().iter().sum(),
+                |r| r.update_inplace_unchecked(|v| v[0] += 1),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, read_throughput);
+criterion_main!(benches);
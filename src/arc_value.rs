@@ -0,0 +1,95 @@
+use crate::Reactive;
+use alloc::sync::Arc;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: 'static,
+        #[cfg(feature = "threadsafe")] T: Send + Sync + 'static,
+    > Reactive<Arc<T>>
+{
+    /// Constructs a new reactive that keeps its value in an `Arc<T>`.
+    ///
+    /// Meant for large values: [`Reactive::value_arc`] hands out cheap snapshots
+    /// that readers can hold (and work on) without blocking the writers,
+    /// and the observers are notified with a clone of the `Arc` instead of a deep copy of the value.
+    ///
+    /// Updates are copy-on-write. Use `update` or `set` to install a new `Arc<T>`,
+    /// or [`Reactive::update_cow`] to change the value in place, which clones it
+    /// only while a snapshot of it is still held somewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let log = Reactive::new_shared(vec![String::from("started")]);
+    ///
+    /// let snapshot = log.value_arc();
+    /// log.update_cow(|lines| lines.push(String::from("running")));
+    ///
+    /// assert_eq!(1, snapshot.len());
+    /// assert_eq!(2, log.value_arc().len());
+    /// ```
+    pub fn new_shared(value: T) -> Self {
+        Reactive::new(Arc::new(value))
+    }
+
+    /// Returns the current snapshot of the value. (only the `Arc` is cloned)
+    ///
+    /// The snapshot doesn't change when the reactive is updated later on
+    /// and holding it doesn't keep anyone from updating the reactive.
+    pub fn value_arc(&self) -> Arc<T> {
+        self.acq_val().clone()
+    }
+
+    /// Changes the value in place through `f` and notifies all the observers
+    /// without checking if the value is changed. (same as `with_mut`)
+    ///
+    /// The value is cloned first if a snapshot of it (from `value_arc`, an observer, ...)
+    /// is still alive, so that the snapshot stays untouched. (see `Arc::make_mut`)
+    /// Otherwise it is changed without any copy.
+    pub fn update_cow(&self, f: impl FnOnce(&mut T))
+    where
+        T: Clone,
+    {
+        self.with_mut(|val| f(Arc::make_mut(val)));
+    }
+
+    /// derive a new child reactive from the snapshots of the value.
+    ///
+    /// Unlike `derive`, `f` receives `&T` and runs on a snapshot,
+    /// so the value of this reactive is not locked while `f` runs.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let log = Reactive::new_shared(vec![1, 2, 3]);
+    /// let total = log.derive_shared(|nums| nums.iter().sum::<i32>());
+    ///
+    /// log.update_cow(|nums| nums.push(4));
+    ///
+    /// assert_eq!(10, total.value());
+    /// ```
+    pub fn derive_shared<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+    >(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] f: impl Fn(&T) -> U + 'static,
+        #[cfg(feature = "threadsafe")] f: impl Fn(&T) -> U + Send + 'static,
+    ) -> Reactive<U> {
+        let derived = self.observe_with_current(|snapshot, observers| {
+            let derived = Reactive::new(f(snapshot));
+            observers.add({
+                let derived = derived.clone();
+                move |snapshot: &Arc<T>| {
+                    let new_val = f(snapshot);
+                    derived.update(|_| new_val);
+                }
+            });
+            derived
+        });
+        self.add_child(&derived);
+
+        derived
+    }
+}
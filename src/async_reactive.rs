@@ -0,0 +1,377 @@
+use crate::hash::DefaultHashBuilder;
+use paste::paste;
+use std::{
+    future::Future,
+    hash::{BuildHasher, Hash},
+    ops::{Deref, DerefMut},
+    pin::Pin,
+    sync::Arc,
+};
+use tokio::sync::{Mutex, MutexGuard};
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+type AsyncObserver<T> = Box<dyn FnMut(&T) -> BoxFuture + Send>;
+
+/// Async twin of [`crate::Reactive`] built on `tokio::sync::Mutex`.
+///
+/// Every method that needs the lock is an `async fn`, so contention suspends the task
+/// instead of blocking the executor thread. Observers may be async too: they are awaited
+/// one after the other (in the sequence they were added) on every notification.
+/// They get a clone of the new value after the value is released, so they can read the reactive.
+/// (except for `update_inplace` and `update_inplace_unchecked`, which don't need `T: Clone`
+/// and therefore await the observers while the value is still locked, like their sync twins)
+///
+/// # Deadlocks
+/// The observers stay locked while they are awaited, and updates made by them are not deferred
+/// like [`crate::Reactive`] does. So an observer (or a task it awaits) must not update the reactive
+/// it observes, nor add or clear its observers: the update waits for the notification in progress,
+/// which waits for the update, and both hang forever.
+///
+/// # Cancellation
+/// The value is written before the observers are awaited, so dropping the future of an update
+/// (`set`, `update`, ...) part way through its notification (a `select!` or `timeout` losing the race)
+/// keeps the new value but skips the observers that weren't awaited yet:
+/// derived and merged reactives stay stale until the next notification. (no error is reported)
+/// Let these futures run to completion, or call `notify` afterwards to bring the observers up to date.
+///
+/// # Examples
+/// ```
+/// use reactivate::AsyncReactive;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let r = AsyncReactive::new(10);
+/// let d = r.derive(|val| val + 5).await;
+///
+/// r.update(|val| val * 2).await;
+///
+/// assert_eq!(20, r.value().await);
+/// assert_eq!(25, d.value().await);
+/// # });
+/// ```
+#[derive(Default)]
+pub struct AsyncReactive<T> {
+    value: Arc<Mutex<T>>,
+    observers: Arc<Mutex<Vec<AsyncObserver<T>>>>,
+    hasher: DefaultHashBuilder,
+}
+
+impl<T> Clone for AsyncReactive<T> {
+    /// Returns a new handle to the same reactive. (the value and observers are shared, not copied)
+    #[allow(clippy::clone_on_copy)] // the hasher is only `Copy` with some of the features
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            observers: self.observers.clone(),
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<T: Send + 'static> AsyncReactive<T> {
+    /// Constructs a new `AsyncReactive<T>`
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Arc::new(Mutex::new(value)),
+            observers: Default::default(),
+            hasher: DefaultHashBuilder::default(),
+        }
+    }
+
+    /// Returns a clone/copy of the value inside the reactive
+    pub async fn value(&self) -> T
+    where
+        T: Clone,
+    {
+        self.value.lock().await.clone()
+    }
+
+    /// Perform some action with the reference to the inner value.
+    pub async fn with_value(&self, f: impl FnOnce(&T)) {
+        f(self.value.lock().await.deref());
+    }
+
+    /// derive a new child reactive that changes whenever the parent reactive changes.
+    /// (same as [`crate::Reactive::derive`])
+    pub async fn derive<U: Clone + PartialEq + Send + Sync + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + 'static,
+    ) -> AsyncReactive<U> {
+        // same invariant as `Reactive::observe_with_current`
+        let mut observers = self.observers.lock().await;
+        let derived = AsyncReactive::new(f(self.value.lock().await.deref()));
+
+        observers.push(Box::new({
+            let derived = derived.clone();
+            move |val| {
+                let new_val = f(val);
+                let derived = derived.clone();
+                Box::pin(async move { derived.update(|_| new_val).await })
+            }
+        }));
+
+        derived
+    }
+
+    /// Adds a new (synchronous) observer to the reactive.
+    pub async fn add_observer(&self, mut f: impl FnMut(&T) + Send + 'static) {
+        self.observers.lock().await.push(Box::new(move |val| {
+            f(val);
+            Box::pin(std::future::ready(()))
+        }));
+    }
+
+    /// Adds a new async observer to the reactive.
+    ///
+    /// It receives a clone of the new value and the returned future is awaited
+    /// before the next observer is notified.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::AsyncReactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = AsyncReactive::new(10);
+    ///
+    /// r.add_async_observer(|val| async move {
+    ///     println!("saving {} to the database", val);
+    /// })
+    /// .await;
+    ///
+    /// r.update(|val| val + 1).await;
+    /// # });
+    /// ```
+    pub async fn add_async_observer<F>(&self, mut f: impl FnMut(T) -> F + Send + 'static)
+    where
+        T: Clone,
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.observers
+            .lock()
+            .await
+            .push(Box::new(move |val| Box::pin(f(val.clone()))));
+    }
+
+    /// Clears all observers from the reactive.
+    pub async fn clear_observers(&self) {
+        self.observers.lock().await.clear();
+    }
+
+    /// Set the value inside the reactive to something new and notify all the observers.
+    /// (even if the provided value is the same as the current one)
+    ///
+    /// Not cancellation safe, see [Cancellation](AsyncReactive#cancellation).
+    pub async fn set(&self, val: T)
+    where
+        T: Clone,
+    {
+        let (mut observers, mut guard) = self.acq_both().await;
+        *guard = val;
+        Self::call_observers(&mut observers, guard).await;
+    }
+
+    /// Update the value inside the reactive and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function
+    ///
+    /// Not cancellation safe, see [Cancellation](AsyncReactive#cancellation).
+    pub async fn update(&self, f: impl FnOnce(&T) -> T)
+    where
+        T: Clone + PartialEq,
+    {
+        let (mut observers, mut guard) = self.acq_both().await;
+        let new_val = f(&guard);
+        if &new_val != guard.deref() {
+            *guard = new_val;
+            Self::call_observers(&mut observers, guard).await;
+        }
+    }
+
+    /// Updates the value inside inplace and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function.
+    /// (changes are detected by comparing hashes, same as [`crate::Reactive::update_inplace`])
+    ///
+    /// To avoid cloning, the observers are awaited while the value is still locked,
+    /// so they must not access the value of this reactive.
+    ///
+    /// Not cancellation safe, see [Cancellation](AsyncReactive#cancellation).
+    pub async fn update_inplace(&self, f: impl FnOnce(&mut T))
+    where
+        T: Hash,
+    {
+        let (mut observers, mut guard) = self.acq_both().await;
+
+        let old_hash = self.hasher.hash_one(guard.deref());
+        f(guard.deref_mut());
+        let new_hash = self.hasher.hash_one(guard.deref());
+
+        if old_hash != new_hash {
+            Self::call_observers_locked(&mut observers, guard).await;
+        }
+    }
+
+    /// Update the value inside the reactive and notify all the observers
+    /// without checking if the value is changed after applying the provided function
+    ///
+    /// Not cancellation safe, see [Cancellation](AsyncReactive#cancellation).
+    pub async fn update_unchecked(&self, f: impl FnOnce(&T) -> T)
+    where
+        T: Clone,
+    {
+        let (mut observers, mut guard) = self.acq_both().await;
+        *guard = f(&guard);
+        Self::call_observers(&mut observers, guard).await;
+    }
+
+    /// Updates the value inside inplace and notify all the observers
+    /// without checking if the value is changed after applying the provided function.
+    ///
+    /// Just like `update_inplace`, the observers are awaited while the value is still locked.
+    ///
+    /// Not cancellation safe, see [Cancellation](AsyncReactive#cancellation).
+    pub async fn update_inplace_unchecked(&self, f: impl FnOnce(&mut T)) {
+        let (mut observers, mut guard) = self.acq_both().await;
+        f(guard.deref_mut());
+        Self::call_observers_locked(&mut observers, guard).await;
+    }
+
+    /// Mutates the value in place and notifies the observers with a clone of it,
+    /// like [`crate::Reactive::with_mut`]. (used by `AsyncMerge`, whose observers can read the reactive)
+    async fn mutate(&self, f: impl FnOnce(&mut T))
+    where
+        T: Clone,
+    {
+        let (mut observers, mut guard) = self.acq_both().await;
+        f(guard.deref_mut());
+        Self::call_observers(&mut observers, guard).await;
+    }
+
+    /// Notify all the observers of the current value
+    ///
+    /// Not cancellation safe, see [Cancellation](AsyncReactive#cancellation).
+    pub async fn notify(&self)
+    where
+        T: Clone,
+    {
+        let (mut observers, guard) = self.acq_both().await;
+        Self::call_observers(&mut observers, guard).await;
+    }
+
+    /// Locks the observers and then the value. (same order as [`crate::Reactive`])
+    async fn acq_both(&self) -> (MutexGuard<'_, Vec<AsyncObserver<T>>>, MutexGuard<'_, T>) {
+        let observers = self.observers.lock().await;
+        (observers, self.value.lock().await)
+    }
+
+    /// Awaits the observers one after the other with a clone of the value held by `guard`,
+    /// **after** releasing it, so that they can read the reactive.
+    ///
+    /// `observers` stays locked until every observer is done,
+    /// so notifications still arrive in the same order as the updates.
+    async fn call_observers(
+        observers: &mut MutexGuard<'_, Vec<AsyncObserver<T>>>,
+        guard: MutexGuard<'_, T>,
+    ) where
+        T: Clone,
+    {
+        let val = guard.clone();
+        drop(guard);
+
+        for obs in observers.iter_mut() {
+            obs(&val).await;
+        }
+    }
+
+    /// Same as `call_observers` but the observers get the value **while it is still locked**,
+    /// so that `T` doesn't need to be `Clone`. (used by the in-place updates)
+    async fn call_observers_locked(
+        observers: &mut MutexGuard<'_, Vec<AsyncObserver<T>>>,
+        guard: MutexGuard<'_, T>,
+    ) {
+        for obs in observers.iter_mut() {
+            obs(guard.deref()).await;
+        }
+    }
+}
+
+/// Async twin of [`crate::Merge`] for [`AsyncReactive`]
+///
+/// ```
+/// use reactivate::{AsyncMerge, AsyncReactive};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let a = AsyncReactive::new(String::from("hazash"));
+/// let b = AsyncReactive::new(0);
+/// let d = (&a, &b).merge().await.derive(|(s, n)| s.len() + n).await;
+///
+/// b.update(|_| 5).await;
+///
+/// assert_eq!(11, d.value().await);
+/// # });
+/// ```
+pub trait AsyncMerge {
+    type Output;
+    fn merge(self) -> impl Future<Output = AsyncReactive<Self::Output>> + Send;
+}
+
+impl<T: Clone + Send + Sync + 'static> AsyncMerge for &AsyncReactive<T> {
+    type Output = T;
+    async fn merge(self) -> AsyncReactive<Self::Output> {
+        self.clone()
+    }
+}
+
+macro_rules! impl_async_merge_for_nested_tuple {
+    ( $($i:literal),* ) => { paste!{
+    impl < $( [<T $i>], )* > AsyncMerge for ( $( [<T $i>], )* )
+    where
+        $( [<T $i>]: AsyncMerge + Send, ) *
+        $( [<T $i>]::Output: Clone + Send + Sync + 'static, ) *
+    {
+        type Output = ( $([<T $i>]::Output,)* );
+
+        async fn merge(self) -> AsyncReactive<Self::Output> {
+            let reactives = ( $(self.$i.merge().await,)* );
+            let values = ( $(reactives.$i.value().await,)* );
+            let combined = AsyncReactive::new(values);
+
+            $( {
+                // same as the sync `Merge`: holding the observers of the source keeps any change
+                // of it from slipping in between reading its value and registering the observer
+                let mut observers = reactives.$i.observers.lock().await;
+                combined.value.lock().await.$i = reactives.$i.value().await;
+
+                observers.push(Box::new({
+                    let combined = combined.clone();
+                    // the combined value definitely changes whenever one of its parts changes,
+                    // so 'unchecked' is fine.
+                    move |val| {
+                        let val = val.clone();
+                        let combined = combined.clone();
+                        Box::pin(async move {
+                            combined.mutate(|c| c.$i = val).await
+                        })
+                    }
+                }));
+            } )*
+
+            combined
+        }
+    }
+    }};
+}
+
+impl_async_merge_for_nested_tuple!(0);
+impl_async_merge_for_nested_tuple!(0, 1);
+impl_async_merge_for_nested_tuple!(0, 1, 2);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14);
+impl_async_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
@@ -0,0 +1,333 @@
+use crate::{
+    observers::Observers,
+    reactive::{NotifyingOnThread, MAX_DEFERRED_NOTIFICATIONS},
+    sync::{lock, Shared},
+    Reactive,
+};
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{
+            AtomicBool, AtomicI32, AtomicI64, AtomicIsize, AtomicU32, AtomicU64, AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Primitive types that have a matching atomic type in `std::sync::atomic`
+/// and can therefore be stored in an [`AtomicReactive`].
+///
+/// Implemented for `bool`, `u32`, `i32`, `u64`, `i64`, `usize` and `isize`.
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait AtomicRepr: Copy + PartialEq + private::Sealed + 'static {
+    #[doc(hidden)]
+    type Atomic: Send + Sync;
+
+    #[doc(hidden)]
+    fn new_atomic(val: Self) -> Self::Atomic;
+
+    #[doc(hidden)]
+    fn load(atomic: &Self::Atomic) -> Self;
+
+    #[doc(hidden)]
+    fn swap(atomic: &Self::Atomic, val: Self) -> Self;
+
+    #[doc(hidden)]
+    fn fetch_update(
+        atomic: &Self::Atomic,
+        f: impl FnMut(Self) -> Option<Self>,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_repr {
+    ( $($t:ty => $atomic:ty),* ) => { $(
+        impl private::Sealed for $t {}
+
+        impl AtomicRepr for $t {
+            type Atomic = $atomic;
+
+            fn new_atomic(val: Self) -> Self::Atomic {
+                <$atomic>::new(val)
+            }
+
+            fn load(atomic: &Self::Atomic) -> Self {
+                atomic.load(Ordering::Acquire)
+            }
+
+            fn swap(atomic: &Self::Atomic, val: Self) -> Self {
+                atomic.swap(val, Ordering::AcqRel)
+            }
+
+            fn fetch_update(
+                atomic: &Self::Atomic,
+                f: impl FnMut(Self) -> Option<Self>,
+            ) -> Result<Self, Self> {
+                atomic.fetch_update(Ordering::AcqRel, Ordering::Acquire, f)
+            }
+        }
+    )* };
+}
+
+impl_atomic_repr!(
+    bool => AtomicBool,
+    u32 => AtomicU32,
+    i32 => AtomicI32,
+    u64 => AtomicU64,
+    i64 => AtomicI64,
+    usize => AtomicUsize,
+    isize => AtomicIsize
+);
+
+/// Integer types supported by [`AtomicReactive`], which additionally get [`AtomicReactive::fetch_add`].
+///
+/// Implemented for `u32`, `i32`, `u64`, `i64`, `usize` and `isize`. (every [`AtomicRepr`] except `bool`)
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait AtomicInteger: AtomicRepr {
+    #[doc(hidden)]
+    const ZERO: Self;
+
+    #[doc(hidden)]
+    fn fetch_add(atomic: &Self::Atomic, val: Self) -> Self;
+}
+
+macro_rules! impl_atomic_integer {
+    ( $($t:ty),* ) => { $(
+        impl AtomicInteger for $t {
+            const ZERO: Self = 0;
+
+            fn fetch_add(atomic: &Self::Atomic, val: Self) -> Self {
+                atomic.fetch_add(val, Ordering::AcqRel)
+            }
+        }
+    )* };
+}
+
+impl_atomic_integer!(u32, i32, u64, i64, usize, isize);
+
+/// Specialization of [`Reactive`] for primitives (see [`AtomicRepr`])
+/// that keeps the value in an atomic instead of behind a lock.
+///
+/// Reading the value is a single atomic load and updates are atomic read-modify-write operations,
+/// so updating threads never wait for each other. Only the observers are behind a lock.
+///
+/// Only available with the `threadsafe` feature, since it exists to be shared between threads.
+///
+/// Supported types are `bool`, `u32`, `i32`, `u64`, `i64`, `usize` and `isize` (see [`AtomicRepr`]).
+/// The integers also get [`AtomicReactive::fetch_add`] (see [`AtomicInteger`]).
+///
+/// # Concurrent updates
+/// Every update that changes the value notifies the observers afterwards,
+/// with the value loaded **while holding the lock on the observers** (not necessarily
+/// the one this update produced). So under contention observers may see the same value twice,
+/// but never an older value after a newer one, and the last call always carries the final value.
+///
+/// # Re-entrant updates
+/// Observers can update the reactive they observe. The new value is stored right away,
+/// but instead of notifying (the observers are busy), the notification in progress goes around
+/// once more when it is done, with whatever the value is by then.
+/// (so several updates made by the observers of a single pass are notified as one)
+///
+/// # Panics
+/// if the observers keep updating the reactive forever (more than 1024 passes in a row)
+///
+/// # Examples
+/// ```
+/// use reactivate::AtomicReactive;
+///
+/// let frames = AtomicReactive::new(0u64);
+/// let seconds = frames.derive(|n| n / 60);
+///
+/// for _ in 0..120 {
+///     frames.update(|n| n + 1);
+/// }
+///
+/// assert_eq!(120, frames.value());
+/// assert_eq!(2, seconds.value());
+/// ```
+pub struct AtomicReactive<T: AtomicRepr> {
+    value: Arc<T::Atomic>,
+    observers: Shared<Observers<T>>,
+
+    /// set by updates made by the observers while they are being called (see `notify`)
+    renotify: Arc<AtomicBool>,
+}
+
+impl<T: AtomicRepr> Clone for AtomicReactive<T> {
+    /// Returns a new handle to the same reactive. (the value and observers are shared, not copied)
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            observers: self.observers.clone(),
+            renotify: self.renotify.clone(),
+        }
+    }
+}
+
+impl<T: AtomicRepr + Default> Default for AtomicReactive<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: AtomicRepr> AtomicReactive<T> {
+    /// Constructs a new `AtomicReactive<T>`
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Arc::new(T::new_atomic(value)),
+            observers: Default::default(),
+            renotify: Default::default(),
+        }
+    }
+
+    /// Returns the current value. (a single atomic load, no locks involved)
+    pub fn value(&self) -> T {
+        T::load(&self.value)
+    }
+
+    /// Same as [`AtomicReactive::value`], named after the atomic operation.
+    pub fn load(&self) -> T {
+        self.value()
+    }
+
+    /// derive a new child reactive that changes whenever this reactive changes.
+    pub fn derive<U: Clone + PartialEq + Send + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + 'static,
+    ) -> Reactive<U> {
+        // same invariant as `Reactive::observe_with_current`
+        let mut observers = lock(&self.observers);
+        let derived = Reactive::new(f(&self.value()));
+
+        observers.push({
+            let derived = derived.clone();
+            move |val: &T| derived.update(|_| f(val))
+        });
+
+        derived
+    }
+
+    /// Adds a new observer to the reactive.
+    pub fn add_observer(&self, f: impl FnMut(&T) + Send + 'static) {
+        lock(&self.observers).push(f);
+    }
+
+    /// Clears all observers from the reactive.
+    pub fn clear_observers(&self) {
+        lock(&self.observers).clear();
+    }
+
+    /// Atomically replaces the value and notifies all the observers.
+    /// (even if the provided value is the same as the current one)
+    pub fn set(&self, val: T) {
+        T::swap(&self.value, val);
+        self.notify();
+    }
+
+    /// Atomically replaces the value and notifies all the observers
+    /// **ONLY** if it is different from the previous one.
+    ///
+    /// Returns the previous value.
+    pub fn store(&self, val: T) -> T {
+        let prev = T::swap(&self.value, val);
+        if prev != val {
+            self.notify();
+        }
+        prev
+    }
+
+    /// Atomically updates the value and notifies all the observers
+    /// **ONLY** if the value changes after applying the provided function.
+    ///
+    /// Under contention `f` may be called more than once (it is retried until no
+    /// other thread changed the value in between), so it shouldn't have side effects.
+    ///
+    /// Returns the previous value.
+    pub fn update(&self, mut f: impl FnMut(T) -> T) -> T {
+        let result = T::fetch_update(&self.value, |val| {
+            let new_val = f(val);
+            (new_val != val).then_some(new_val)
+        });
+
+        match result {
+            Ok(prev) => {
+                self.notify();
+                prev
+            }
+            Err(prev) => prev,
+        }
+    }
+
+    /// Notify all the observers of the current value.
+    ///
+    /// When called by one of the observers (directly or through an update), the notification
+    /// in progress calls the observers once more after it is done instead. (see the type level docs)
+    pub fn notify(&self) {
+        let addr = Arc::as_ptr(&self.observers) as usize;
+        if NotifyingOnThread::contains_addr(addr) {
+            self.renotify.store(true, Ordering::Release);
+            return;
+        }
+
+        let mut observers = lock(&self.observers);
+        let _notifying = NotifyingOnThread::enter_addr(addr);
+        // left over by an observer that panicked during the previous pass
+        self.renotify.store(false, Ordering::Release);
+
+        for _ in 0..MAX_DEFERRED_NOTIFICATIONS {
+            let val = self.value();
+            observers.call(&val);
+            if !self.renotify.swap(false, Ordering::AcqRel) {
+                return;
+            }
+        }
+
+        panic!(
+            "observers updated their own reactive more than {} times in a row",
+            MAX_DEFERRED_NOTIFICATIONS
+        );
+    }
+}
+
+impl<T: AtomicInteger> AtomicReactive<T> {
+    /// Atomically adds `val` to the value (wrapping around on overflow, like the std atomics)
+    /// and notifies all the observers **ONLY** if the value changed. (i.e. `val` isn't zero)
+    ///
+    /// A single atomic instruction, so unlike `update` it never has to retry under contention.
+    ///
+    /// Returns the previous value.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::AtomicReactive;
+    ///
+    /// let hits = AtomicReactive::new(0u64);
+    /// let milestone = hits.derive(|n| n / 1000);
+    ///
+    /// for _ in 0..2000 {
+    ///     hits.fetch_add(1);
+    /// }
+    ///
+    /// assert_eq!(2000, hits.load());
+    /// assert_eq!(2, milestone.value());
+    /// ```
+    pub fn fetch_add(&self, val: T) -> T {
+        let prev = T::fetch_add(&self.value, val);
+        if val != T::ZERO {
+            self.notify();
+        }
+        prev
+    }
+}
+
+impl<T: AtomicRepr + Debug> Debug for AtomicReactive<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AtomicReactive")
+            .field(&self.value())
+            .finish()
+    }
+}
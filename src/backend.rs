@@ -0,0 +1,339 @@
+//! The two ways a [`crate::GenericReactive`] can share its state between its clones.
+//!
+//! [`Local`] keeps it in `Rc<RefCell<_>>`: no locking, but the reactive stays on its thread
+//! and so its observers don't need to be `Send`. [`ThreadSafe`] (with `std`) keeps it in
+//! `Arc<Mutex<_>>` and its observers must be `Send`. Both are always available,
+//! the `threadsafe` feature only picks the one [`crate::Reactive`] stands for.
+
+use crate::reactive::NotifyingFlag;
+#[cfg(feature = "std")]
+use crate::reactive::NotifyingOnThread;
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::{
+    cell::{Cell, Ref, RefCell, RefMut},
+    ops::{Deref, DerefMut},
+};
+
+/// Backend of a [`crate::GenericReactive`]: either [`Local`] or [`ThreadSafe`].
+///
+/// Sealed, its items are implementation details of the crate.
+pub trait Backend: sealed::Sealed + Sized + 'static {
+    #[doc(hidden)]
+    type Ptr<T>: Deref<Target = T> + Clone;
+    #[doc(hidden)]
+    type WeakPtr<T>;
+    #[doc(hidden)]
+    type Lock<T>;
+    #[doc(hidden)]
+    type Guard<'a, T: 'a>: DerefMut<Target = T>;
+    /// guard the value is lent to the observers with by the in-place updates
+    #[doc(hidden)]
+    type ReadGuard<'a, T: 'a>: Deref<Target = T>;
+
+    #[doc(hidden)]
+    type Observer<T>: FnMut(&T);
+    #[doc(hidden)]
+    type Invariant<T>: Fn(&mut T);
+    #[doc(hidden)]
+    type Hook: FnOnce();
+    /// executor installed with `set_executor` (only the thread safe backend has them)
+    #[doc(hidden)]
+    type Executor<T>: Default;
+
+    /// tells whether the observers of a reactive are being called (see `Notifying`)
+    #[doc(hidden)]
+    type Flag: Default;
+    #[doc(hidden)]
+    type Notifying<'a>;
+
+    #[doc(hidden)]
+    fn new_ptr<T>(val: T) -> Self::Ptr<T>;
+    #[doc(hidden)]
+    fn downgrade<T>(ptr: &Self::Ptr<T>) -> Self::WeakPtr<T>;
+    #[doc(hidden)]
+    fn upgrade<T>(weak: &Self::WeakPtr<T>) -> Option<Self::Ptr<T>>;
+    #[doc(hidden)]
+    fn dangling<T>() -> Self::WeakPtr<T>;
+    /// the address of the pointee, identifying it for as long as it is alive
+    #[doc(hidden)]
+    fn addr<T>(ptr: &Self::Ptr<T>) -> usize;
+
+    #[doc(hidden)]
+    fn new_lock<T>(val: T) -> Self::Lock<T>;
+    /// Locks ignoring poisoning (see `GenericReactive::is_poisoned`)
+    #[doc(hidden)]
+    fn lock<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T>;
+    /// Locks for reading only, ignoring poisoning
+    #[doc(hidden)]
+    fn lock_read<T>(lock: &Self::Lock<T>) -> Self::ReadGuard<'_, T>;
+    /// Turns the guard of `lock` into a read-only one
+    #[doc(hidden)]
+    fn read<'a, T>(lock: &'a Self::Lock<T>, guard: Self::Guard<'a, T>) -> Self::ReadGuard<'a, T>;
+
+    /// Wraps an observer with the executor, if there is one
+    #[doc(hidden)]
+    fn execute<T>(executor: &Self::Executor<T>, f: Self::Observer<T>) -> Self::Observer<T>;
+
+    #[doc(hidden)]
+    fn enter(flag: &Self::Flag, addr: usize) -> Self::Notifying<'_>;
+    #[doc(hidden)]
+    fn is_notifying(flag: &Self::Flag, addr: usize) -> bool;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// `Rc<RefCell<_>>` based backend of [`crate::LocalReactive`]. (see [`Backend`])
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Local;
+
+/// `Arc<Mutex<_>>` based backend of [`crate::SyncReactive`]. (see [`Backend`])
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadSafe;
+
+/// The backend of [`crate::Reactive`], picked by the `threadsafe` feature
+#[cfg(not(feature = "threadsafe"))]
+pub(crate) type DefaultBackend = Local;
+
+/// The backend of [`crate::Reactive`], picked by the `threadsafe` feature
+#[cfg(feature = "threadsafe")]
+pub(crate) type DefaultBackend = ThreadSafe;
+
+/// `Ptr<Lock<T>>` of the backend `B`
+pub(crate) type SharedIn<B, T> = <B as Backend>::Ptr<<B as Backend>::Lock<T>>;
+
+/// `WeakPtr<Lock<T>>` of the backend `B`
+pub(crate) type WeakSharedIn<B, T> = <B as Backend>::WeakPtr<<B as Backend>::Lock<T>>;
+
+/// Closures that can be stored as observers by the backend `B`. (any of them for `Local`, `Send` ones for `ThreadSafe`)
+pub(crate) trait IntoObserver<T, B: Backend>: FnMut(&T) + 'static {
+    fn into_observer(self) -> B::Observer<T>;
+}
+
+/// Same as `IntoObserver` but for the observers of `add_retaining_observer`,
+/// which push their own `id` to `expired` the first time they return `false`.
+pub(crate) trait IntoRetaining<T, B: Backend>: FnMut(&T) -> bool + 'static {
+    fn into_retaining(self, expired: SharedIn<B, Vec<usize>>, id: usize) -> B::Observer<T>;
+}
+
+impl sealed::Sealed for Local {}
+
+impl Backend for Local {
+    type Ptr<T> = Rc<T>;
+    type WeakPtr<T> = alloc::rc::Weak<T>;
+    type Lock<T> = RefCell<T>;
+    type Guard<'a, T: 'a> = RefMut<'a, T>;
+    type ReadGuard<'a, T: 'a> = Ref<'a, T>;
+
+    type Observer<T> = Box<dyn FnMut(&T)>;
+    type Invariant<T> = Box<dyn Fn(&mut T)>;
+    type Hook = Box<dyn FnOnce()>;
+    type Executor<T> = ();
+
+    type Flag = Cell<bool>;
+    type Notifying<'a> = NotifyingFlag<'a>;
+
+    #[inline]
+    fn new_ptr<T>(val: T) -> Rc<T> {
+        Rc::new(val)
+    }
+
+    #[inline]
+    fn downgrade<T>(ptr: &Rc<T>) -> alloc::rc::Weak<T> {
+        Rc::downgrade(ptr)
+    }
+
+    #[inline]
+    fn upgrade<T>(weak: &alloc::rc::Weak<T>) -> Option<Rc<T>> {
+        weak.upgrade()
+    }
+
+    #[inline]
+    fn dangling<T>() -> alloc::rc::Weak<T> {
+        alloc::rc::Weak::new()
+    }
+
+    #[inline]
+    fn addr<T>(ptr: &Rc<T>) -> usize {
+        Rc::as_ptr(ptr) as usize
+    }
+
+    #[inline]
+    fn new_lock<T>(val: T) -> RefCell<T> {
+        RefCell::new(val)
+    }
+
+    #[inline]
+    fn lock<T>(lock: &RefCell<T>) -> RefMut<'_, T> {
+        lock.borrow_mut()
+    }
+
+    #[inline]
+    fn lock_read<T>(lock: &RefCell<T>) -> Ref<'_, T> {
+        lock.borrow()
+    }
+
+    /// the observers can still read the value while it is lent to them
+    #[inline]
+    fn read<'a, T>(lock: &'a RefCell<T>, guard: RefMut<'a, T>) -> Ref<'a, T> {
+        drop(guard);
+        lock.borrow()
+    }
+
+    #[inline]
+    fn execute<T>(_: &(), f: Self::Observer<T>) -> Self::Observer<T> {
+        f
+    }
+
+    /// an `Rc` based reactive never leaves its thread, so a flag is enough
+    #[inline]
+    fn enter(flag: &Cell<bool>, _: usize) -> NotifyingFlag<'_> {
+        NotifyingFlag::enter(flag)
+    }
+
+    #[inline]
+    fn is_notifying(flag: &Cell<bool>, _: usize) -> bool {
+        flag.get()
+    }
+}
+
+impl<T, F: FnMut(&T) + 'static> IntoObserver<T, Local> for F {
+    fn into_observer(self) -> Box<dyn FnMut(&T)> {
+        Box::new(self)
+    }
+}
+
+impl<T, F: FnMut(&T) -> bool + 'static> IntoRetaining<T, Local> for F {
+    fn into_retaining(mut self, expired: Rc<RefCell<Vec<usize>>>, id: usize) -> Box<dyn FnMut(&T)> {
+        let mut alive = true;
+        Box::new(move |val: &T| {
+            if alive && !self(val) {
+                alive = false;
+                Local::lock(&expired).push(id);
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl sealed::Sealed for ThreadSafe {}
+
+// `loom` (`RUSTFLAGS="--cfg loom"`) swaps the mutexes for its own model checked ones.
+// its `Arc` doesn't support weak references, so `Ptr` stays the std one.
+#[cfg(feature = "std")]
+impl Backend for ThreadSafe {
+    type Ptr<T> = std::sync::Arc<T>;
+    type WeakPtr<T> = std::sync::Weak<T>;
+    #[cfg(not(loom))]
+    type Lock<T> = std::sync::Mutex<T>;
+    #[cfg(loom)]
+    type Lock<T> = loom::sync::Mutex<T>;
+    #[cfg(not(loom))]
+    type Guard<'a, T: 'a> = std::sync::MutexGuard<'a, T>;
+    #[cfg(loom)]
+    type Guard<'a, T: 'a> = loom::sync::MutexGuard<'a, T>;
+    type ReadGuard<'a, T: 'a> = Self::Guard<'a, T>;
+
+    type Observer<T> = Box<dyn FnMut(&T) + Send>;
+    type Invariant<T> = Box<dyn Fn(&mut T) + Send>;
+    type Hook = Box<dyn FnOnce() + Send>;
+    type Executor<T> = Option<crate::executor::Executor<T>>;
+
+    type Flag = ();
+    type Notifying<'a> = NotifyingOnThread;
+
+    #[inline]
+    fn new_ptr<T>(val: T) -> std::sync::Arc<T> {
+        std::sync::Arc::new(val)
+    }
+
+    #[inline]
+    fn downgrade<T>(ptr: &std::sync::Arc<T>) -> std::sync::Weak<T> {
+        std::sync::Arc::downgrade(ptr)
+    }
+
+    #[inline]
+    fn upgrade<T>(weak: &std::sync::Weak<T>) -> Option<std::sync::Arc<T>> {
+        weak.upgrade()
+    }
+
+    #[inline]
+    fn dangling<T>() -> std::sync::Weak<T> {
+        std::sync::Weak::new()
+    }
+
+    #[inline]
+    fn addr<T>(ptr: &std::sync::Arc<T>) -> usize {
+        std::sync::Arc::as_ptr(ptr) as usize
+    }
+
+    #[inline]
+    fn new_lock<T>(val: T) -> Self::Lock<T> {
+        Self::Lock::new(val)
+    }
+
+    #[inline]
+    fn lock<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T> {
+        lock.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    #[inline]
+    fn lock_read<T>(lock: &Self::Lock<T>) -> Self::Guard<'_, T> {
+        Self::lock(lock)
+    }
+
+    /// a mutex can't be locked for reading only, so the value just stays locked
+    #[inline]
+    fn read<'a, T>(_: &'a Self::Lock<T>, guard: Self::Guard<'a, T>) -> Self::Guard<'a, T> {
+        guard
+    }
+
+    #[inline]
+    fn execute<T>(
+        executor: &Option<crate::executor::Executor<T>>,
+        f: Self::Observer<T>,
+    ) -> Self::Observer<T> {
+        match executor {
+            Some(executor) => executor.wrap(f),
+            None => f,
+        }
+    }
+
+    /// a thread safe reactive can be notified from any thread, so it has to be tracked per thread
+    #[inline]
+    fn enter(_: &(), addr: usize) -> NotifyingOnThread {
+        NotifyingOnThread::enter_addr(addr)
+    }
+
+    #[inline]
+    fn is_notifying(_: &(), addr: usize) -> bool {
+        NotifyingOnThread::contains_addr(addr)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, F: FnMut(&T) + Send + 'static> IntoObserver<T, ThreadSafe> for F {
+    fn into_observer(self) -> Box<dyn FnMut(&T) + Send> {
+        Box::new(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, F: FnMut(&T) -> bool + Send + 'static> IntoRetaining<T, ThreadSafe> for F {
+    fn into_retaining(
+        mut self,
+        expired: SharedIn<ThreadSafe, Vec<usize>>,
+        id: usize,
+    ) -> Box<dyn FnMut(&T) + Send> {
+        let mut alive = true;
+        Box::new(move |val: &T| {
+            if alive && !self(val) {
+                alive = false;
+                ThreadSafe::lock(&expired).push(id);
+            }
+        })
+    }
+}
@@ -0,0 +1,239 @@
+#[cfg(feature = "std")]
+use crate::backend::ThreadSafe;
+use crate::{
+    backend::{Backend, Local},
+    hash::DefaultHashBuilder,
+};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+};
+
+/// The value and observers of a reactive **without any interior mutability or shared ownership**.
+///
+/// [`crate::GenericReactive`] wraps its state in `Rc<RefCell<_>>` or `Arc<Mutex<_>>`.
+/// `GenericReactiveBase` is the bare building block: it is owned, changed through `&mut self`
+/// and can be embedded in your own struct behind your own lock (or no lock at all).
+///
+/// The observers are called right after every change with a reference to the new value,
+/// so nothing is cloned to notify them. They can't access the reactive itself
+/// (the borrow checker makes sure of that).
+///
+/// The backend only decides whether the observers have to be `Send`.
+/// Used through its aliases: [`LocalReactiveBase`], [`SyncReactiveBase`] and [`ReactiveBase`].
+#[derive(Default)]
+pub struct GenericReactiveBase<T, B: Backend> {
+    value: T,
+    observers: Vec<B::Observer<T>>,
+}
+
+/// [`GenericReactiveBase`] whose observers don't need to be `Send`, regardless of the `threadsafe` feature.
+///
+/// # Examples
+/// ```
+/// use reactivate::LocalReactiveBase;
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// let log = Rc::new(RefCell::new(vec![])); // not `Send`
+///
+/// let mut health = LocalReactiveBase::new(100);
+/// health.add_observer({
+///     let log = log.clone();
+///     move |hp| log.borrow_mut().push(*hp)
+/// });
+///
+/// health.update(|hp| hp - 30);
+///
+/// assert_eq!(vec![70], *log.borrow());
+/// ```
+pub type LocalReactiveBase<T> = GenericReactiveBase<T, Local>;
+
+/// [`GenericReactiveBase`] whose observers must be `Send`, so it can be sent to other threads
+/// regardless of the `threadsafe` feature.
+#[cfg(feature = "std")]
+pub type SyncReactiveBase<T> = GenericReactiveBase<T, ThreadSafe>;
+
+/// [`LocalReactiveBase`], or [`SyncReactiveBase`] with the `threadsafe` feature
+/// (the same backend as [`crate::Reactive`]).
+///
+/// # Examples
+/// ```
+/// use reactivate::ReactiveBase;
+/// use std::sync::{Arc, Mutex};
+///
+/// struct Player {
+///     name: String,
+///     health: ReactiveBase<u32>,
+/// }
+///
+/// let log = Arc::new(Mutex::new(vec![]));
+///
+/// let mut player = Player {
+///     name: "crab".to_string(),
+///     health: ReactiveBase::new(100),
+/// };
+/// player.health.add_observer({
+///     let log = log.clone();
+///     move |hp| log.lock().unwrap().push(*hp)
+/// });
+///
+/// player.health.update(|hp| hp - 30);
+/// player.health.update(|hp| hp - 0); // unchanged, not notified
+///
+/// assert_eq!("crab", player.name);
+/// assert_eq!(70, *player.health.borrowed());
+/// assert_eq!(vec![70], *log.lock().unwrap());
+/// ```
+#[cfg(not(feature = "threadsafe"))]
+pub type ReactiveBase<T> = LocalReactiveBase<T>;
+
+/// [`LocalReactiveBase`], or [`SyncReactiveBase`] with the `threadsafe` feature
+/// (the same backend as [`crate::Reactive`]).
+#[cfg(feature = "threadsafe")]
+pub type ReactiveBase<T> = SyncReactiveBase<T>;
+
+impl<T, B: Backend> GenericReactiveBase<T, B> {
+    /// Constructs a new reactive without any observers.
+    ///
+    /// It is `const`, so it can initialize a `static` (behind a lock, since it is changed through `&mut self`,
+    /// and with the `ThreadSafe` backend, so that the lock is `Sync`).
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "std")]
+    /// # {
+    /// use reactivate::SyncReactiveBase;
+    /// use std::sync::Mutex;
+    ///
+    /// static COUNTER: Mutex<SyncReactiveBase<u32>> = Mutex::new(SyncReactiveBase::new(0));
+    ///
+    /// COUNTER.lock().unwrap().update(|n| n + 1);
+    /// assert_eq!(1, COUNTER.lock().unwrap().value());
+    /// # }
+    /// ```
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            observers: Vec::new(),
+        }
+    }
+
+    /// Returns a clone/copy of the value
+    pub fn value(&self) -> T
+    where
+        T: Clone,
+    {
+        self.value.clone()
+    }
+
+    /// Returns a reference to the value
+    pub fn borrowed(&self) -> &T {
+        &self.value
+    }
+
+    /// Perform some action with the reference to the value.
+    pub fn with_value(&self, f: impl FnOnce(&T)) {
+        f(&self.value);
+    }
+
+    /// Perform some action with the mutable references to the value and the observers.
+    /// (the observers are **NOT** notified)
+    pub fn with(&mut self, f: impl FnOnce(&mut T, &mut [B::Observer<T>])) {
+        f(&mut self.value, &mut self.observers);
+    }
+
+    /// Consumes the reactive and returns the value. (the observers are dropped)
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns `true` if there is at least one observer.
+    pub fn has_observers(&self) -> bool {
+        !self.observers.is_empty()
+    }
+
+    /// Clears all observers.
+    pub fn clear_observers(&mut self) {
+        self.observers.clear();
+    }
+
+    /// Set the value to something new and notify all the observers.
+    /// (even if the provided value is the same as the current one)
+    pub fn set(&mut self, val: T) {
+        self.value = val;
+        self.notify();
+    }
+
+    /// Update the value and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function
+    pub fn update(&mut self, f: impl FnOnce(&T) -> T)
+    where
+        T: PartialEq,
+    {
+        let new_val = f(&self.value);
+        if new_val != self.value {
+            self.set(new_val);
+        }
+    }
+
+    /// Updates the value inplace and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function.
+    /// (changes are detected by comparing hashes, same as [`crate::Reactive::update_inplace`])
+    pub fn update_inplace(&mut self, f: impl FnOnce(&mut T))
+    where
+        T: Hash,
+    {
+        // a hasher can't be built in a `const fn`, so there is one per call instead of one per reactive
+        let hasher = DefaultHashBuilder::default();
+        let old_hash = hasher.hash_one(&self.value);
+        f(&mut self.value);
+        let new_hash = hasher.hash_one(&self.value);
+
+        if old_hash != new_hash {
+            self.notify();
+        }
+    }
+
+    /// Update the value and notify all the observers
+    /// without checking if the value is changed after applying the provided function
+    pub fn update_unchecked(&mut self, f: impl FnOnce(&T) -> T) {
+        self.set(f(&self.value));
+    }
+
+    /// Updates the value inplace and notify all the observers
+    /// without checking if the value is changed after applying the provided function.
+    pub fn update_inplace_unchecked(&mut self, f: impl FnOnce(&mut T)) {
+        f(&mut self.value);
+        self.notify();
+    }
+
+    /// Notify all the observers of the current value.
+    pub fn notify(&mut self) {
+        for obs in self.observers.iter_mut() {
+            obs(&self.value);
+        }
+    }
+}
+
+/// `add_observer` for a backend whose observers are boxed `FnMut(&T) + $send`
+macro_rules! impl_add_observer {
+    ($backend:ty, [$($send:tt)*]) => {
+        impl<T> GenericReactiveBase<T, $backend> {
+            /// Adds a new observer that is called every time the value changes.
+            pub fn add_observer(&mut self, f: impl FnMut(&T) + $($send)* 'static) {
+                self.observers.push(Box::new(f));
+            }
+        }
+    };
+}
+
+impl_add_observer!(Local, []);
+#[cfg(feature = "std")]
+impl_add_observer!(ThreadSafe, [Send+]);
+
+impl<T: Debug, B: Backend> Debug for GenericReactiveBase<T, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ReactiveBase").field(&self.value).finish()
+    }
+}
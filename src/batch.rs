@@ -0,0 +1,70 @@
+use crate::{observers::ObserverHandle, Reactive};
+use std::{
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Adds an observer that receives all the values that occurred within each `window`
+    /// as a single slice instead of being called once per change.
+    ///
+    /// The values are collected and handed to `f` on a background thread.
+    /// A window without any changes does not call `f`.
+    ///
+    /// When the observer goes away (removed through the returned handle or because
+    /// the reactive was dropped), the values collected so far are flushed right away
+    /// as a final batch and the background thread exits.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::time::Duration;
+    ///
+    /// let r = Reactive::new(0);
+    ///
+    /// r.add_batched_observer(Duration::from_millis(50), |vals| {
+    ///     println!("writing {} values to the database", vals.len());
+    /// });
+    ///
+    /// for n in 1..=10 {
+    ///     r.update(|_| n);
+    /// }
+    /// ```
+    pub fn add_batched_observer(
+        &self,
+        window: Duration,
+        mut f: impl FnMut(&[T]) + Send + 'static,
+    ) -> ObserverHandle<T> {
+        let (tx, rx) = mpsc::channel::<T>();
+
+        std::thread::spawn(move || {
+            let mut batch = vec![];
+            let mut deadline = Instant::now() + window;
+
+            loop {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+                match rx.recv_timeout(timeout) {
+                    Ok(val) => batch.push(val),
+                    Err(RecvTimeoutError::Timeout) => {
+                        if !batch.is_empty() {
+                            f(&batch);
+                            batch.clear();
+                        }
+                        deadline = Instant::now() + window;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        if !batch.is_empty() {
+                            f(&batch);
+                        }
+                        break;
+                    }
+                }
+            }
+        });
+
+        self.add_removable_observer(move |val| {
+            // the receiving thread only goes away after the sender is dropped
+            let _ = tx.send(val.clone());
+        })
+    }
+}
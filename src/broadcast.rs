@@ -0,0 +1,84 @@
+use crate::Reactive;
+use tokio::sync::broadcast;
+
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Returns a `tokio::sync::broadcast::Sender` that receives a clone of every
+    /// new value of the reactive. Call `subscribe()` on it to get as many receivers as needed.
+    ///
+    /// Values sent while there are no receivers are simply dropped.
+    ///
+    /// The lag behaviour is inherited from `tokio::sync::broadcast`: the channel holds at most
+    /// `capacity` values and a receiver that falls further behind loses the oldest ones
+    /// and gets a `RecvError::Lagged` on its next `recv`.
+    ///
+    /// The observer keeps its own clone of the sender, so receivers only get `RecvError::Closed`
+    /// once the observer is gone (the reactive was dropped or its observers cleared)
+    /// **and** the returned sender (along with its clones) was dropped too.
+    ///
+    /// # Panics
+    /// if `capacity` is zero (same as `tokio::sync::broadcast::channel`)
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(0);
+    /// let sender = r.broadcast(16);
+    ///
+    /// let mut rx = sender.subscribe();
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(1, rx.recv().await.unwrap());
+    /// # });
+    /// ```
+    pub fn broadcast(&self, capacity: usize) -> broadcast::Sender<T> {
+        let (sender, _) = broadcast::channel(capacity);
+
+        self.add_observer({
+            let sender = sender.clone();
+            move |val| {
+                // no receivers is not an error here
+                let _ = sender.send(val.clone());
+            }
+        });
+
+        sender
+    }
+
+    /// Same as [`Reactive::broadcast`] but returns a receiver instead of the sender,
+    /// for when the fan-out is only ever consumed. Call `resubscribe()` on it to get more receivers.
+    ///
+    /// Only the observer holds the sender, so the sender lives exactly as long as the observer:
+    /// receivers get `RecvError::Closed` once the reactive is dropped (or its observers cleared),
+    /// and the observer removes itself on the first change after every receiver was dropped.
+    ///
+    /// Lags and overflows the same way as [`Reactive::broadcast`]: a receiver that falls
+    /// more than `capacity` values behind loses the oldest ones and gets a `RecvError::Lagged`.
+    ///
+    /// # Panics
+    /// if `capacity` is zero (same as `tokio::sync::broadcast::channel`)
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(0);
+    /// let mut rx1 = r.broadcast_receiver(16);
+    /// let mut rx2 = rx1.resubscribe();
+    ///
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(1, rx1.recv().await.unwrap());
+    /// assert_eq!(1, rx2.recv().await.unwrap());
+    /// # });
+    /// ```
+    pub fn broadcast_receiver(&self, capacity: usize) -> broadcast::Receiver<T> {
+        let (sender, receiver) = broadcast::channel(capacity);
+
+        self.add_retaining_observer(move |val| sender.send(val.clone()).is_ok());
+
+        receiver
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    inbox::{Inbox, Registration},
+    sync::Ptr,
+    Reactive,
+};
+use std::{
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Error returned by [`Changed`] when every clone of the reactive
+/// was dropped before the next change could happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+impl Display for Closed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "reactive was dropped before the next change")
+    }
+}
+
+impl std::error::Error for Closed {}
+
+/// Future returned by [`Reactive::changed`]
+///
+/// Resolves with `Ok(value)` on the next change of the reactive
+/// or with `Err(Closed)` if the reactive is dropped before that.
+///
+/// Dropping this future unregisters its internal observer.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Changed<T> {
+    inbox: Ptr<Inbox<Option<T>>>,
+    registration: Registration<T>,
+}
+
+impl<T> Future for Changed<T> {
+    type Output = Result<T, Closed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut inbox = self.inbox.lock();
+
+        if let Some(value) = inbox.buf.take() {
+            drop(inbox);
+            self.registration.unregister();
+            return Poll::Ready(Ok(value));
+        }
+
+        if inbox.is_closed() {
+            return Poll::Ready(Err(Closed));
+        }
+
+        inbox.set_waker(cx.waker());
+        Poll::Pending
+    }
+}
+
+/// Future returned by [`Reactive::wait_until`]
+///
+/// Resolves with `Ok(value)` for the first value that satisfies the predicate
+/// or with `Err(Closed)` if the reactive is dropped before that.
+///
+/// Dropping this future unregisters its internal observer.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitUntil<T>(Changed<T>);
+
+impl<T> Future for WaitUntil<T> {
+    type Output = Result<T, Closed>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a future that resolves with the next value the reactive is updated to.
+    /// (only changes that happen after this method is called are considered)
+    ///
+    /// Any number of these futures can be waiting on the same reactive at once.
+    /// Dropping the future before it completes unregisters it from the reactive.
+    ///
+    /// If all clones of the reactive are dropped before the next change,
+    /// the future resolves with `Err(Closed)` instead of waiting forever.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let changed = r.changed();
+    ///
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(Ok(11), changed.await);
+    /// # });
+    /// ```
+    pub fn changed(&self) -> Changed<T> {
+        let inbox = Inbox::new(None);
+        let registration = self.add_inbox_observer(&inbox, |buf, val: &T| {
+            if buf.is_none() {
+                *buf = Some(val.clone());
+            }
+        });
+
+        Changed {
+            inbox,
+            registration,
+        }
+    }
+
+    /// Returns a future that resolves with the first value of the reactive that satisfies `pred`.
+    ///
+    /// If the current value already satisfies it, the future resolves immediately.
+    /// Otherwise it waits for the first update that does.
+    ///
+    /// Dropping the future before it completes unregisters it from the reactive.
+    /// If all clones of the reactive are dropped first, it resolves with `Err(Closed)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// #[derive(Clone, Copy, PartialEq, Debug)]
+    /// enum Connection {
+    ///     Connecting,
+    ///     Ready,
+    /// }
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(Connection::Connecting);
+    /// let ready = r.wait_until(|conn| *conn == Connection::Ready);
+    ///
+    /// r.update(|_| Connection::Ready);
+    ///
+    /// assert_eq!(Ok(Connection::Ready), ready.await);
+    /// # });
+    /// ```
+    pub fn wait_until(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] pred: impl Fn(&T) -> bool + 'static,
+        #[cfg(feature = "threadsafe")] pred: impl Fn(&T) -> bool + Send + 'static,
+    ) -> WaitUntil<T> {
+        let (inbox, registration) = self.observe_with_current(|current, observers| {
+            let inbox = Inbox::new(pred(current).then(|| current.clone()));
+            let registration = observers.add_inbox(&inbox, move |buf, val: &T| {
+                if buf.is_none() && pred(val) {
+                    *buf = Some(val.clone());
+                }
+            });
+            (inbox, registration)
+        });
+
+        WaitUntil(Changed {
+            inbox,
+            registration,
+        })
+    }
+}
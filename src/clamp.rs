@@ -0,0 +1,63 @@
+use crate::Reactive;
+
+/// raises to `min` first and then lowers to `max`, so `max` wins if the bounds cross
+fn clamp<T: PartialOrd + Clone>(val: &T, min: &T, max: &T) -> T {
+    let val = if val < min { min } else { val };
+    if val > max {
+        max.clone()
+    } else {
+        val.clone()
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + PartialOrd + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + PartialOrd + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive holding the value of this reactive clamped between
+    /// the values of `min` and `max`. It is recomputed whenever any of the three changes.
+    ///
+    /// If `min` is greater than `max`, `max` wins: the result never goes above `max`
+    /// and is simply `max` until the bounds are fixed. Nothing panics, so the bounds can be
+    /// moved past each other temporarily, e.g. while a user drags a slider.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let volume = Reactive::new(50);
+    /// let min = Reactive::new(0);
+    /// let max = Reactive::new(100);
+    ///
+    /// let clamped = volume.clamp_between(&min, &max);
+    /// assert_eq!(50, clamped.value());
+    ///
+    /// max.update(|_| 30);
+    /// assert_eq!(30, clamped.value());
+    ///
+    /// volume.update(|_| 10);
+    /// min.update(|_| 20);
+    /// assert_eq!(20, clamped.value());
+    /// ```
+    pub fn clamp_between(&self, min: &Reactive<T>, max: &Reactive<T>) -> Reactive<T> {
+        // every observer only writes its own slot so no observer ever locks
+        // another one of the three reactives. (same approach as `Merge`)
+        let inputs = Reactive::new((self.value(), min.value(), max.value()));
+
+        self.add_observer({
+            let inputs = inputs.clone();
+            move |val| inputs.with_mut(|i| i.0 = val.clone())
+        });
+        min.add_observer({
+            let inputs = inputs.clone();
+            move |val| inputs.with_mut(|i| i.1 = val.clone())
+        });
+        max.add_observer({
+            let inputs = inputs.clone();
+            move |val| inputs.with_mut(|i| i.2 = val.clone())
+        });
+
+        inputs.derive(|(val, min, max)| clamp(val, min, max))
+    }
+}
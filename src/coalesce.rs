@@ -0,0 +1,159 @@
+use crate::{
+    sync::{lock, shared, Shared},
+    Reactive,
+};
+use std::ops::DerefMut;
+
+#[derive(Default)]
+struct State {
+    /// some thread is currently calling the observers
+    notifying: bool,
+    /// the value changed after that thread read it
+    dirty: bool,
+}
+
+/// Wrapper returned by [`Reactive::coalescing`] whose updates don't wait for
+/// notifications that are already in progress on other threads.
+///
+/// The value is changed right away, but if another thread is busy calling the observers,
+/// the update only marks the reactive as dirty and returns.
+/// Once that thread is done, it calls the observers one more time with the latest value,
+/// no matter how many updates happened in the meantime.
+///
+/// **Observers may skip intermediate values**, they are only guaranteed to eventually see the latest one.
+/// The observers are called without holding the lock on the value.
+pub struct CoalescingReactive<T> {
+    reactive: Reactive<T>,
+    state: Shared<State>,
+}
+
+/// Clears `notifying` if an observer panics,
+/// otherwise every later update would only mark the reactive as dirty.
+struct ResetOnPanic<'a>(&'a Shared<State>);
+
+impl Drop for ResetOnPanic<'_> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            let mut state = lock(self.0);
+            state.notifying = false;
+            state.dirty = false;
+        }
+    }
+}
+
+impl<T> Clone for CoalescingReactive<T> {
+    fn clone(&self) -> Self {
+        Self {
+            reactive: self.reactive.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> Reactive<T> {
+    /// Returns a wrapper around this reactive that coalesces notifications
+    /// of contended updates. (see [`CoalescingReactive`])
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// r.add_observer(|n| std::thread::sleep(std::time::Duration::from_millis(*n)));
+    ///
+    /// let coalescing = r.coalescing();
+    /// let handles: Vec<_> = (1..=4)
+    ///     .map(|n| {
+    ///         let coalescing = coalescing.clone();
+    ///         std::thread::spawn(move || coalescing.update(|_| n))
+    ///     })
+    ///     .collect();
+    ///
+    /// for handle in handles {
+    ///     handle.join().unwrap();
+    /// }
+    /// ```
+    pub fn coalescing(&self) -> CoalescingReactive<T> {
+        CoalescingReactive {
+            reactive: self.clone(),
+            state: shared(State::default()),
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> CoalescingReactive<T> {
+    /// Same as [`Reactive::set`] but coalesced. (see [`CoalescingReactive`])
+    pub fn set(&self, val: T) {
+        if self.reactive.rejects_change() {
+            return;
+        }
+
+        let mut guard = self.reactive.acq_val();
+        *guard = val;
+        self.reactive.enforce_invariant(&mut guard);
+        drop(guard);
+        self.notify();
+    }
+
+    /// Same as [`Reactive::update`] but coalesced. (see [`CoalescingReactive`])
+    pub fn update(&self, f: impl FnOnce(&T) -> T)
+    where
+        T: PartialEq,
+    {
+        if self.reactive.rejects_change() {
+            return;
+        }
+
+        let mut guard = self.reactive.acq_val();
+        let val = guard.deref_mut();
+        let mut new_val = f(val);
+        self.reactive.enforce_invariant(&mut new_val);
+        if &new_val != val {
+            *val = new_val;
+            drop(guard);
+            self.notify();
+        }
+    }
+
+    /// Same as [`Reactive::update_inplace_unchecked`] but coalesced. (see [`CoalescingReactive`])
+    pub fn update_inplace_unchecked(&self, f: impl FnOnce(&mut T)) {
+        if self.reactive.rejects_change() {
+            return;
+        }
+
+        let mut guard = self.reactive.acq_val();
+        f(guard.deref_mut());
+        self.reactive.enforce_invariant(&mut guard);
+        drop(guard);
+        self.notify();
+    }
+
+    /// Returns the underlying (non coalesced) reactive
+    pub fn reactive(&self) -> &Reactive<T> {
+        &self.reactive
+    }
+
+    fn notify(&self) {
+        {
+            let mut state = lock(&self.state);
+            if state.notifying {
+                state.dirty = true;
+                return;
+            }
+            state.notifying = true;
+        }
+
+        let _reset = ResetOnPanic(&self.state);
+        loop {
+            let (observers, guard) = self.reactive.acq_update();
+            self.reactive.notify_unlocked(observers, guard);
+
+            let mut state = lock(&self.state);
+            if !state.dirty {
+                state.notifying = false;
+                return;
+            }
+            state.dirty = false;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{inbox::Registration, observers::ObserverHandle, Reactive};
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+use std::ops::Deref;
+
+/// `crossbeam_channel::Receiver` returned by [`Reactive::subscribe_crossbeam`].
+///
+/// Derefs to the receiver, so it can be used in `crossbeam_channel::select!` as is.
+/// Dropping it unregisters the observer feeding the channel,
+/// after which clones of the inner receiver only get the values that are already queued.
+pub struct CrossbeamReceiver<T> {
+    receiver: Receiver<T>,
+    _registration: Registration<T>,
+}
+
+impl<T> CrossbeamReceiver<T> {
+    /// Returns the inner receiver
+    pub fn receiver(&self) -> &Receiver<T> {
+        &self.receiver
+    }
+}
+
+impl<T> Deref for CrossbeamReceiver<T> {
+    type Target = Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.receiver
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Same as [`Reactive::observe_into`] but for a `crossbeam_channel::Sender`,
+    /// so the receiving side can take part in `crossbeam_channel::select!`.
+    ///
+    /// Once every receiver is dropped, the observer removes itself from the reactive.
+    ///
+    /// **When a bounded channel is full, the new value is dropped** instead of blocking.
+    /// (see [`Reactive::observe_into_sync`] for why)
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// let (tx, rx) = crossbeam_channel::unbounded();
+    ///
+    /// r.observe_into_crossbeam(tx);
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(Ok(1), rx.try_recv());
+    /// ```
+    pub fn observe_into_crossbeam(&self, sender: Sender<T>) -> ObserverHandle<T> {
+        self.add_retaining_observer(move |val| match sender.try_send(val.clone()) {
+            Ok(()) | Err(TrySendError::Full(_)) => true,
+            Err(TrySendError::Disconnected(_)) => false,
+        })
+    }
+
+    /// Returns a receiver (derefs to `crossbeam_channel::Receiver`) that receives a clone
+    /// of every value the reactive is updated to after this call.
+    ///
+    /// With `bound: None` the channel is unbounded.
+    /// With `bound: Some(capacity)` at most `capacity` values are queued and when the channel
+    /// is full the oldest value is discarded to make room for the new one (latest wins).
+    ///
+    /// In both modes the observer is removed once the returned [`CrossbeamReceiver`] is dropped.
+    /// (the bounded mode needs a receiver of its own to discard values,
+    /// so it couldn't rely on the channel getting disconnected)
+    ///
+    /// # Panics
+    /// if `bound` is `Some(0)`
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// let rx = r.subscribe_crossbeam(Some(1));
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(Ok(2), rx.try_recv());
+    /// ```
+    pub fn subscribe_crossbeam(&self, bound: Option<usize>) -> CrossbeamReceiver<T> {
+        let Some(capacity) = bound else {
+            let (sender, receiver) = crossbeam_channel::unbounded();
+            let handle = self.observe_into_crossbeam(sender);
+            return CrossbeamReceiver {
+                receiver,
+                _registration: Registration(handle),
+            };
+        };
+
+        assert!(capacity > 0, "channel capacity must be non-zero");
+
+        let (sender, receiver) = crossbeam_channel::bounded(capacity);
+        let handle = self.add_removable_observer({
+            let receiver = receiver.clone();
+            move |val| {
+                let mut val = val.clone();
+                // the consumer may empty the channel in between,
+                // so only discard while it is actually full
+                while let Err(TrySendError::Full(v)) = sender.try_send(val) {
+                    let _ = receiver.try_recv();
+                    val = v;
+                }
+            }
+        });
+
+        CrossbeamReceiver {
+            receiver,
+            _registration: Registration(handle),
+        }
+    }
+}
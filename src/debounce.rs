@@ -0,0 +1,210 @@
+#[cfg(feature = "threadsafe")]
+use crate::sync::Shared;
+use crate::{
+    sync::{lock, shared},
+    Reactive,
+};
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + PartialEq + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive that only picks up the latest value of this reactive
+    /// when the returned `flush` function is called.
+    ///
+    /// This gives debounce/throttle semantics driven by your own clock
+    /// (eg: once per render frame) without spawning any threads.
+    ///
+    /// Calling `flush` when this reactive hasn't changed since the last flush does nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let (debounced, flush) = r.manual_debounce();
+    ///
+    /// r.update(|_| 11);
+    /// r.update(|_| 12);
+    ///
+    /// assert_eq!(10, debounced.value());
+    ///
+    /// flush();
+    ///
+    /// assert_eq!(12, debounced.value());
+    /// ```
+    pub fn manual_debounce(&self) -> (Reactive<T>, impl Fn()) {
+        let pending = shared(None);
+        let debounced = self.observe_with_current(|val, observers| {
+            observers.add({
+                let pending = pending.clone();
+                move |val| *lock(&pending) = Some(val.clone())
+            });
+            Reactive::new(val.clone())
+        });
+
+        let flush = {
+            let debounced = debounced.clone();
+            move || {
+                let latest = lock(&pending).take();
+                if let Some(val) = latest {
+                    debounced.update(|_| val);
+                }
+            }
+        };
+
+        (debounced, flush)
+    }
+}
+
+#[cfg(feature = "threadsafe")]
+struct Debouncer<T> {
+    pending: Option<T>,
+    deadline: std::time::Instant,
+    timer_running: bool,
+}
+
+#[cfg(feature = "threadsafe")]
+impl<T: Send + 'static> Debouncer<T> {
+    fn new() -> Shared<Self> {
+        shared(Self {
+            pending: None,
+            deadline: std::time::Instant::now(),
+            timer_running: false,
+        })
+    }
+
+    /// Holds `val` back until `duration` passes without another call, then hands it to `emit`.
+    ///
+    /// A single timer thread runs while a value is pending. Every call replaces the pending value
+    /// and pushes the deadline back, so the timer goes back to sleep instead of emitting a stale value.
+    fn push(
+        state: &Shared<Self>,
+        val: T,
+        duration: std::time::Duration,
+        emit: &(impl Fn(T) + Clone + Send + 'static),
+    ) {
+        use std::time::Instant;
+
+        let mut debouncer = lock(state);
+        debouncer.pending = Some(val);
+        debouncer.deadline = Instant::now() + duration;
+
+        if debouncer.timer_running {
+            return;
+        }
+        debouncer.timer_running = true;
+
+        let state = state.clone();
+        let emit = emit.clone();
+        std::thread::spawn(move || loop {
+            let mut debouncer = lock(&state);
+            let now = Instant::now();
+
+            if now < debouncer.deadline {
+                let wait = debouncer.deadline - now;
+                drop(debouncer);
+                std::thread::sleep(wait);
+                continue;
+            }
+
+            debouncer.timer_running = false;
+            let latest = debouncer.pending.take();
+            drop(debouncer);
+
+            if let Some(val) = latest {
+                emit(val);
+            }
+            break;
+        });
+    }
+}
+
+#[cfg(feature = "threadsafe")]
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Returns a new reactive that only picks up the latest value of this reactive
+    /// once it has stopped changing for `duration`.
+    ///
+    /// Only the last value received is buffered (and dropped once it is handed over).
+    /// It is moved into the debounced reactive with `update_inplace_unchecked`,
+    /// so the value is cloned once per change of this reactive and never again,
+    /// which matters for large heap allocated values like `Vec<u8>`.
+    /// The debounced reactive is notified every time the quiet period ends,
+    /// even if the value ends up being the same.
+    ///
+    /// The quiet period is timed by a background thread that only lives while a value is pending.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::time::Duration;
+    ///
+    /// let r = Reactive::new(vec![0u8; 1024]);
+    /// let debounced = r.debounce_inplace(Duration::from_millis(20));
+    ///
+    /// r.update_inplace(|buf| buf[0] = 1);
+    /// r.update_inplace(|buf| buf[0] = 2);
+    /// assert_eq!(0, debounced.value()[0]);
+    ///
+    /// std::thread::sleep(Duration::from_millis(100));
+    /// assert_eq!(2, debounced.value()[0]);
+    /// ```
+    pub fn debounce_inplace(&self, duration: std::time::Duration) -> Reactive<T> {
+        self.observe_with_current(|val, observers| {
+            let debounced = Reactive::new(val.clone());
+            let state = Debouncer::new();
+
+            let emit = {
+                let debounced = debounced.clone();
+                move |val| debounced.update_inplace_unchecked(|curr| *curr = val)
+            };
+            observers.add(move |val| Debouncer::push(&state, val.clone(), duration, &emit));
+            debounced
+        })
+    }
+
+    /// Returns a new reactive that only picks up a value of this reactive
+    /// once it has stayed the same for `duration`.
+    ///
+    /// Every change restarts the timer, so nothing is emitted while the value keeps changing,
+    /// and the value emitted is always the one that stayed put.
+    /// The stable reactive only notifies its observers if that value differs from the last one it held
+    /// (unlike [`Reactive::debounce_inplace`], which notifies at the end of every quiet period).
+    ///
+    /// Just like `debounce_inplace`, the timer is a single background thread
+    /// that only lives while a value is pending, no matter how many changes arrive meanwhile.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::time::Duration;
+    ///
+    /// let query = Reactive::new(String::new());
+    /// let settled = query.when_stable_for(Duration::from_millis(20));
+    ///
+    /// query.update(|_| String::from("r"));
+    /// query.update(|_| String::from("ru"));
+    /// query.update(|_| String::from("rust"));
+    /// assert_eq!("", settled.value());
+    ///
+    /// std::thread::sleep(Duration::from_millis(100));
+    /// assert_eq!("rust", settled.value());
+    /// ```
+    pub fn when_stable_for(&self, duration: std::time::Duration) -> Reactive<T>
+    where
+        T: PartialEq,
+    {
+        self.observe_with_current(|val, observers| {
+            let stable = Reactive::new(val.clone());
+            let state = Debouncer::new();
+
+            let emit = {
+                let stable = stable.clone();
+                move |val| stable.update(|_| val)
+            };
+            observers.add(move |val| Debouncer::push(&state, val.clone(), duration, &emit));
+            stable
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    observers::ObserverHandle,
+    sync::{lock, shared},
+    Reactive,
+};
+use std::sync::mpsc;
+
+/// Sending side of a queue of jobs that is drained on one specific thread
+/// (typically the main/UI thread's event loop). See [`Reactive::observe_on`].
+pub type ThreadDispatcher = mpsc::Sender<Box<dyn FnOnce() + Send>>;
+
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Adds an observer that runs on whichever thread drains the receiving end of `dispatcher`
+    /// instead of inline on the thread that updated the reactive.
+    ///
+    /// Every change sends a job (holding a clone of the new value) through the `dispatcher`.
+    /// The jobs run in the order the changes happened, whenever the target thread gets to them.
+    /// Once the receiving end is dropped, new values are silently discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Reactive, ThreadDispatcher};
+    /// use std::sync::mpsc;
+    ///
+    /// let (dispatcher, jobs) = mpsc::channel();
+    /// let dispatcher: ThreadDispatcher = dispatcher;
+    ///
+    /// let r = Reactive::new(0);
+    /// r.observe_on(dispatcher, |val| println!("rendering {}", val));
+    ///
+    /// std::thread::spawn({
+    ///     let r = r.clone();
+    ///     move || r.update(|_| 10)
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// // event loop of the main thread
+    /// for job in jobs.try_iter() {
+    ///     job();
+    /// }
+    /// ```
+    pub fn observe_on(
+        &self,
+        dispatcher: ThreadDispatcher,
+        f: impl FnMut(&T) + Send + 'static,
+    ) -> ObserverHandle<T> {
+        let f = shared(f);
+
+        self.add_removable_observer(move |val| {
+            let f = f.clone();
+            let val = val.clone();
+            // the target thread is gone. nothing left to notify
+            let _ = dispatcher.send(Box::new(move || (lock(&f))(&val)));
+        })
+    }
+}
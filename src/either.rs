@@ -0,0 +1,61 @@
+use crate::Reactive;
+
+/// Value of the reactive returned by [`Reactive::either`],
+/// tagged with the source it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] A: Clone + 'static,
+        #[cfg(feature = "threadsafe")] A: Clone + Send + 'static,
+    > Reactive<A>
+{
+    /// Returns a new reactive holding the value of whichever of `self` (`Left`)
+    /// and `other` (`Right`) changed most recently.
+    ///
+    /// Unlike [`crate::Merge`], which keeps the values of both sources,
+    /// only the latest value is kept, tagged with its source.
+    ///
+    /// Neither source has changed yet when this is called,
+    /// so the initial value is `Left` with the current value of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Either, Reactive};
+    ///
+    /// let clicks = Reactive::new(0);
+    /// let keys = Reactive::new('a');
+    ///
+    /// let last_input = clicks.either(&keys);
+    /// assert_eq!(Either::Left(0), last_input.value());
+    ///
+    /// keys.update(|_| 'b');
+    /// assert_eq!(Either::Right('b'), last_input.value());
+    ///
+    /// clicks.update(|n| n + 1);
+    /// assert_eq!(Either::Left(1), last_input.value());
+    /// ```
+    pub fn either<
+        #[cfg(not(feature = "threadsafe"))] B: Clone + 'static,
+        #[cfg(feature = "threadsafe")] B: Clone + Send + 'static,
+    >(
+        &self,
+        other: &Reactive<B>,
+    ) -> Reactive<Either<A, B>> {
+        let either = Reactive::new(Either::Left(self.value()));
+
+        self.add_observer({
+            let either = either.clone();
+            move |val| either.set(Either::Left(val.clone()))
+        });
+        other.add_observer({
+            let either = either.clone();
+            move |val| either.set(Either::Right(val.clone()))
+        });
+
+        either
+    }
+}
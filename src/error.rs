@@ -0,0 +1,24 @@
+use core::fmt::{self, Display};
+
+/// Errors returned by the fallible (`try_*`, `*_timeout`) methods of [`crate::Reactive`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactiveError {
+    /// Something (usually an observer) panicked while holding a lock on the reactive,
+    /// so the value may be in an inconsistent state. See `Reactive::recover`.
+    Poisoned,
+
+    /// The lock on the reactive could not be acquired in time.
+    /// (usually because an observer is stuck)
+    Timeout,
+}
+
+impl Display for ReactiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReactiveError::Poisoned => write!(f, "reactive is poisoned by a panic"),
+            ReactiveError::Timeout => write!(f, "timed out acquiring a lock on the reactive"),
+        }
+    }
+}
+
+impl core::error::Error for ReactiveError {}
@@ -0,0 +1,86 @@
+use crate::{sync::lock_std, SyncReactive};
+use std::sync::{Arc, Mutex};
+
+/// A single observer call, ready to be run by an executor
+pub type Job = Box<dyn FnOnce() + Send>;
+
+type Submit = Arc<dyn Fn(Job) + Send + Sync>;
+
+type Observer<T> = Box<dyn FnMut(&T) + Send>;
+
+/// Executor installed on a reactive by [`SyncReactive::set_executor`].
+///
+/// `submit` is shared by every wrapped observer,
+/// so that replacing the executor doesn't need to re-wrap them.
+/// It is cloned out of its lock before being called, so jobs run inline by the executor
+/// can trigger other notifications and notifications don't serialize on the lock.
+pub struct Executor<T> {
+    submit: Arc<Mutex<Submit>>,
+    wrap: Box<dyn Fn(Observer<T>) -> Observer<T> + Send>,
+}
+
+impl<T> Executor<T> {
+    pub(crate) fn wrap(&self, f: Observer<T>) -> Observer<T> {
+        (self.wrap)(f)
+    }
+}
+
+impl<T: Clone + Send + 'static> SyncReactive<T> {
+    /// Runs every observer call through `executor` instead of inline on the thread doing the update.
+    ///
+    /// On each notification, every observer gets a job (holding its own clone of the new value)
+    /// submitted to `executor`, which can hand it to a thread pool (rayon, threadpool, ...).
+    /// This applies to the observers already added and to the ones added afterwards.
+    /// Calling it again replaces the executor.
+    ///
+    /// Things to keep in mind:
+    /// - `update` returns as soon as the jobs are submitted, so derived reactives
+    ///   and other observers catch up **eventually** instead of before `update` returns.
+    /// - Calls of the same observer never overlap, but with a multi-threaded executor
+    ///   they can run out of order. (an older value may be observed after a newer one)
+    ///   Different observers may run concurrently.
+    /// - This is why the value must be `Clone + Send` and the observers must be `Send`.
+    /// - `executor` may be called by several threads at once, so it must be `Sync` too.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::SyncReactive;
+    ///
+    /// let r = SyncReactive::new(0);
+    /// r.add_observer(|val| println!("{} on {:?}", val, std::thread::current().id()));
+    ///
+    /// r.set_executor(|job| {
+    ///     std::thread::spawn(job);
+    /// });
+    ///
+    /// r.update(|n| n + 1);
+    /// ```
+    pub fn set_executor(&self, executor: impl Fn(Job) + Send + Sync + 'static) {
+        let mut observers = self.acq_obs();
+
+        if let Some(installed) = observers.executor() {
+            *lock_std(&installed.submit) = Arc::new(executor);
+            return;
+        }
+
+        let submit: Arc<Mutex<Submit>> = Arc::new(Mutex::new(Arc::new(executor)));
+        let wrap = {
+            let submit = submit.clone();
+            move |f: Observer<T>| -> Observer<T> {
+                let f = Arc::new(Mutex::new(f));
+                let submit = submit.clone();
+                Box::new(move |val: &T| {
+                    let f = f.clone();
+                    let val = val.clone();
+                    let submit = lock_std(&submit).clone();
+                    submit(Box::new(move || (lock_std(&f))(&val)));
+                })
+            }
+        };
+
+        observers.set_executor(Executor {
+            submit,
+            wrap: Box::new(wrap),
+        });
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Ord + Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Ord + Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive holding the smallest value this reactive has ever had
+    /// (starting with the current value).
+    ///
+    /// It only changes when this reactive is updated to a value smaller than the minimum so far.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let min = r.min_ever();
+    ///
+    /// r.update(|_| 5);
+    /// r.update(|_| 7);
+    ///
+    /// assert_eq!(5, min.value());
+    /// ```
+    pub fn min_ever(&self) -> Reactive<T> {
+        self.extremum(|val, curr| val < curr)
+    }
+
+    /// Returns a new reactive holding the largest value this reactive has ever had
+    /// (starting with the current value).
+    ///
+    /// It only changes when this reactive is updated to a value larger than the maximum so far.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let max = r.max_ever();
+    ///
+    /// r.update(|_| 15);
+    /// r.update(|_| 12);
+    ///
+    /// assert_eq!(15, max.value());
+    /// ```
+    pub fn max_ever(&self) -> Reactive<T> {
+        self.extremum(|val, curr| val > curr)
+    }
+
+    /// Reactive following the values for which `replaces(new, current)` holds
+    fn extremum(&self, replaces: fn(&T, &T) -> bool) -> Reactive<T> {
+        self.observe_with_current(|val, observers| {
+            let extremum = Reactive::new(val.clone());
+            observers.add({
+                let extremum = extremum.clone();
+                move |val| {
+                    extremum.update_if(|curr| replaces(val, curr), |_| val.clone());
+                }
+            });
+            extremum
+        })
+    }
+}
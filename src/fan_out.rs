@@ -0,0 +1,38 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + PartialEq + Send + 'static,
+    > Reactive<T>
+{
+    /// Keeps every one of the `targets` in sync with this reactive.
+    ///
+    /// Whenever this reactive changes, each target is updated to the new value
+    /// (targets that already hold it are not notified again).
+    /// The targets are **NOT** touched until the first change.
+    ///
+    /// This does the same thing as calling `add_observer` once per target,
+    /// but spells out the one-to-many fan-out in one place.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let source = Reactive::new(0);
+    /// let targets = [Reactive::new(0), Reactive::new(0), Reactive::new(0)];
+    ///
+    /// source.broadcast_to(&targets);
+    /// source.update(|_| 10);
+    ///
+    /// assert!(targets.iter().all(|t| t.value() == 10));
+    /// ```
+    pub fn broadcast_to(&self, targets: &[Reactive<T>]) {
+        let targets = targets.to_vec();
+
+        self.add_observer(move |val| {
+            for target in &targets {
+                target.update(|_| val.clone());
+            }
+        });
+    }
+}
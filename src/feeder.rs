@@ -0,0 +1,80 @@
+use crate::Reactive;
+use futures_core::Stream;
+use std::{future::poll_fn, pin::pin};
+use tokio::{runtime::Handle, task::JoinHandle};
+
+/// Handle to the task spawned by [`Reactive::bind_stream`].
+///
+/// Dropping it cancels the task. (use [`Feeder::detach`] to keep it running)
+#[must_use = "dropping the feeder immediately cancels it"]
+pub struct Feeder {
+    task: Option<JoinHandle<()>>,
+}
+
+impl Feeder {
+    /// Returns `true` once the stream has ended (or the task was cancelled)
+    pub fn is_finished(&self) -> bool {
+        // not `Option::is_none_or`, which needs Rust 1.82
+        match &self.task {
+            Some(task) => task.is_finished(),
+            None => true,
+        }
+    }
+
+    /// Lets the task keep running until the stream ends, even after the feeder is dropped
+    pub fn detach(mut self) {
+        self.task.take();
+    }
+}
+
+impl Drop for Feeder {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + Send + 'static> Reactive<T> {
+    /// Spawns a task on the given runtime that feeds every item of the `stream`
+    /// into the reactive using `update`.
+    /// (so observers are only notified if the item differs from the current value)
+    ///
+    /// When the stream ends, the reactive simply keeps the last value it received.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(0);
+    /// let d = r.derive(|val| val * 10);
+    ///
+    /// let feeder = r.bind_stream(
+    ///     futures::stream::iter(vec![1, 2, 3]),
+    ///     &tokio::runtime::Handle::current(),
+    /// );
+    ///
+    /// while !feeder.is_finished() {
+    ///     tokio::task::yield_now().await;
+    /// }
+    ///
+    /// assert_eq!(3, r.value());
+    /// assert_eq!(30, d.value());
+    /// # });
+    /// ```
+    pub fn bind_stream<S>(&self, stream: S, handle: &Handle) -> Feeder
+    where
+        S: Stream<Item = T> + Send + 'static,
+    {
+        let reactive = self.clone();
+        let task = handle.spawn(async move {
+            let mut stream = pin!(stream);
+            while let Some(item) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+                reactive.update(|_| item);
+            }
+        });
+
+        Feeder { task: Some(task) }
+    }
+}
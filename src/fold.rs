@@ -0,0 +1,123 @@
+use crate::{
+    sync::{lock, shared},
+    Reactive,
+};
+use alloc::{vec, vec::Vec};
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive holding the current values of all the `reactives`
+    /// folded (in order) with `f`, starting from `init`. It is recomputed whenever any of them changes.
+    ///
+    /// The fold always starts over from (a clone of) `init`,
+    /// so values are only accumulated across the inputs, not over time.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let a = Reactive::new(1);
+    /// let b = Reactive::new(2);
+    /// let c = Reactive::new(3);
+    ///
+    /// let total = Reactive::zip_fold(&[&a, &b, &c], 0, |acc, x| acc + x);
+    /// assert_eq!(6, total.value());
+    ///
+    /// b.update(|_| 20);
+    /// assert_eq!(24, total.value());
+    /// ```
+    pub fn zip_fold<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+    >(
+        reactives: &[&Reactive<T>],
+        init: U,
+        #[cfg(not(feature = "threadsafe"))] f: impl Fn(U, &T) -> U + 'static,
+        #[cfg(feature = "threadsafe")] f: impl Fn(U, &T) -> U + Send + 'static,
+    ) -> Reactive<U> {
+        // every observer only writes its own slot so no observer ever locks
+        // another one of the inputs. (same approach as `Merge`)
+        let inputs = Reactive::new(reactives.iter().map(|r| r.value()).collect::<Vec<_>>());
+
+        for (i, r) in reactives.iter().enumerate() {
+            r.add_observer({
+                let inputs = inputs.clone();
+                move |val| inputs.update_inplace_unchecked(|vals| vals[i] = val.clone())
+            });
+        }
+
+        inputs.derive(move |vals| vals.iter().fold(init.clone(), &f))
+    }
+
+    /// Returns a new reactive holding the current values of all the `reactives` reduced with `f`.
+    /// It is kept up to date whenever any of them changes.
+    ///
+    /// Unlike [`Reactive::zip_fold`], `f` must be **associative** and `initial` must be its
+    /// **neutral element** (`0` for a sum, `1` for a product, `i32::MIN` for a max, ...).
+    /// That allows the values to be kept in a balanced tree of partial results,
+    /// so a change to one of the inputs only recomputes `O(log n)` of them instead of all `n`.
+    /// `f` doesn't need to be commutative, the values are always combined in order.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let scores = [Reactive::new(3), Reactive::new(7), Reactive::new(5)];
+    /// let inputs = scores.iter().collect::<Vec<_>>();
+    ///
+    /// let best = Reactive::combine_reduce(&inputs, i32::MIN, |a, b| a.max(*b));
+    /// assert_eq!(7, best.value());
+    ///
+    /// scores[2].update(|_| 10);
+    /// assert_eq!(10, best.value());
+    /// ```
+    pub fn combine_reduce(
+        reactives: &[&Reactive<T>],
+        initial: T,
+        #[cfg(not(feature = "threadsafe"))] f: impl Fn(T, &T) -> T + 'static,
+        #[cfg(feature = "threadsafe")] f: impl Fn(T, &T) -> T + Send + 'static,
+    ) -> Reactive<T>
+    where
+        T: PartialEq,
+    {
+        // segment tree: the leaves (the values of the inputs, padded with `initial`)
+        // are stored at `len..2 * len` and every other node `i` combines `2 * i` and `2 * i + 1`
+        let len = reactives.len().next_power_of_two();
+        let mut nodes = vec![initial; 2 * len];
+        for (i, r) in reactives.iter().enumerate() {
+            nodes[len + i] = r.value();
+        }
+        for i in (1..len).rev() {
+            nodes[i] = f(nodes[2 * i].clone(), &nodes[2 * i + 1]);
+        }
+
+        let reduced = Reactive::new(nodes[1].clone());
+        let tree = shared((nodes, f));
+
+        for (i, r) in reactives.iter().enumerate() {
+            r.add_observer({
+                let tree = tree.clone();
+                let reduced = reduced.clone();
+                move |val| {
+                    let mut tree = lock(&tree);
+                    let (nodes, f) = &mut *tree;
+
+                    let mut node = len + i;
+                    nodes[node] = val.clone();
+                    while node > 1 {
+                        node /= 2;
+                        nodes[node] = f(nodes[2 * node].clone(), &nodes[2 * node + 1]);
+                    }
+
+                    // still holding the tree so that concurrent changes are applied in order
+                    reduced.update(|_| nodes[1].clone());
+                }
+            });
+        }
+
+        reduced
+    }
+}
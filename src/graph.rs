@@ -0,0 +1,115 @@
+//! Tracking of the reactives derived from a reactive, used for introspection only.
+//! (see [`crate::GenericReactive::descendant_count`])
+
+use crate::backend::{Backend, SharedIn, WeakSharedIn};
+#[cfg(feature = "graph-introspection")]
+use crate::GenericReactive;
+use alloc::vec::Vec;
+#[cfg(feature = "graph-introspection")]
+use alloc::{format, string::String};
+#[cfg(feature = "graph-introspection")]
+use core::fmt::{Debug, Write};
+
+struct Child<B: Backend> {
+    children: WeakSharedIn<B, Children<B>>,
+    #[cfg(feature = "graph-introspection")]
+    type_name: &'static str,
+}
+
+/// Weak references to the children lists of the reactives derived from a reactive.
+///
+/// Only the children lists are kept (not the reactives themselves)
+/// so that reactives of different types can be tracked together.
+pub(crate) struct Children<B: Backend>(Vec<Child<B>>);
+
+impl<B: Backend> Default for Children<B> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<B: Backend> Children<B> {
+    /// Records the children list of a reactive of type `U` derived from this one
+    #[cfg_attr(
+        not(feature = "graph-introspection"),
+        allow(clippy::extra_unused_type_parameters)
+    )]
+    pub(crate) fn push<U>(&mut self, children: &SharedIn<B, Children<B>>) {
+        // forget the children that are gone while we are at it
+        self.0.retain(|child| B::upgrade(&child.children).is_some());
+        self.0.push(Child {
+            children: B::downgrade(children),
+            #[cfg(feature = "graph-introspection")]
+            type_name: core::any::type_name::<U>(),
+        });
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.0
+            .iter()
+            .filter_map(|child| B::upgrade(&child.children))
+            .map(|child| 1 + B::lock(&child).count())
+            .sum()
+    }
+
+    /// Writes a DOT node and edge for every (live) child, recursively
+    #[cfg(feature = "graph-introspection")]
+    fn write_dot(&self, parent: usize, out: &mut String) {
+        for child in &self.0 {
+            let Some(children) = B::upgrade(&child.children) else {
+                continue;
+            };
+            let id = node_id::<B>(&children);
+            let _ = writeln!(
+                out,
+                "    n{:x} [label=\"{}\"];",
+                id,
+                escape(child.type_name)
+            );
+            let _ = writeln!(out, "    n{:x} -> n{:x};", parent, id);
+            B::lock(&children).write_dot(id, out);
+        }
+    }
+}
+
+/// the address of the (shared) children list is the same for every clone of a reactive,
+/// so it identifies the reactive for as long as it is alive.
+#[cfg(feature = "graph-introspection")]
+fn node_id<B: Backend>(children: &SharedIn<B, Children<B>>) -> usize {
+    B::addr(children)
+}
+
+#[cfg(feature = "graph-introspection")]
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(feature = "graph-introspection")]
+impl<T: Debug, B: Backend> GenericReactive<T, B> {
+    /// Returns a Graphviz DOT description of this reactive and every reactive
+    /// (transitively) derived from it. (see [`GenericReactive::descendant_count`] for what is tracked)
+    ///
+    /// This reactive is labeled with its type and `Debug` value.
+    /// The derived ones are labeled with their type only, since they aren't required to be `Debug`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let _d = r.derive(|val| val.to_string());
+    ///
+    /// // paste into `dot -Tsvg` to render
+    /// println!("{}", r.to_dot());
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let id = node_id::<B>(self.children());
+        let label = format!("{}: {:?}", core::any::type_name::<T>(), &*self.acq_val());
+
+        let mut out = String::from("digraph reactivate {\n");
+        let _ = writeln!(out, "    n{:x} [label=\"{}\"];", id, escape(&label));
+        B::lock(self.children()).write_dot(id, &mut out);
+        out.push_str("}\n");
+        out
+    }
+}
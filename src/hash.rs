@@ -0,0 +1,68 @@
+//! Hashing used by `update_inplace` to detect changes.
+
+use core::hash::{BuildHasher, Hasher};
+
+/// Hash builder used by [`crate::Reactive::update_inplace`].
+///
+/// `FxHasher` with the `fast-hash` feature, SipHash (randomly keyed) with `std`
+/// and [`FnvBuildHasher`] otherwise.
+#[cfg(feature = "fast-hash")]
+pub(crate) type DefaultHashBuilder = rustc_hash::FxBuildHasher;
+
+#[cfg(all(not(feature = "fast-hash"), feature = "std"))]
+pub(crate) type DefaultHashBuilder = std::collections::hash_map::RandomState;
+
+#[cfg(all(not(feature = "fast-hash"), not(feature = "std")))]
+pub(crate) type DefaultHashBuilder = FnvBuildHasher;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hasher. Tiny and available without `std`, but not DoS resistant.
+///
+/// # Examples
+/// ```
+/// use reactivate::FnvHasher;
+/// use std::hash::Hasher;
+///
+/// let mut hasher = FnvHasher::default();
+/// hasher.write(b"crab");
+///
+/// assert_ne!(FnvHasher::default().finish(), hasher.finish());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// [`BuildHasher`] for [`FnvHasher`].
+///
+/// The default for `update_inplace` without `std`.
+/// Can also be passed to [`crate::Reactive::update_inplace_with_hasher`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FnvBuildHasher;
+
+impl BuildHasher for FnvBuildHasher {
+    type Hasher = FnvHasher;
+
+    fn build_hasher(&self) -> FnvHasher {
+        FnvHasher::default()
+    }
+}
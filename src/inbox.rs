@@ -0,0 +1,160 @@
+//! Plumbing shared by the receivers of a reactive ([`crate::Changed`], [`crate::ReactiveStream`],
+//! [`crate::Subscription`], ...).
+//!
+//! An observer registered on the reactive pushes values into an [`Inbox`]
+//! and wakes up whoever is waiting on it (a task through its `Waker`, a thread through the `Condvar`).
+//! The waiting side owns a [`Registration`] which removes that observer again when dropped.
+
+#[cfg(feature = "threadsafe")]
+use crate::sync::{self, Condvar};
+#[cfg(feature = "async")]
+use crate::Reactive;
+use crate::{
+    backend::DefaultBackend,
+    observers::ObserverHandle,
+    reactive::Registrar,
+    sync::{lock, Guard, Lock, Ptr},
+};
+use std::task::Waker;
+
+pub(crate) struct Inbox<B> {
+    contents: Lock<Contents<B>>,
+    /// blocked threads wait on this (only possible with the `threadsafe` feature)
+    #[cfg(feature = "threadsafe")]
+    ready: Condvar,
+}
+
+pub(crate) struct Contents<B> {
+    pub(crate) buf: B,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+impl<B> Inbox<B> {
+    pub(crate) fn new(buf: B) -> Ptr<Self> {
+        Ptr::new(Self {
+            contents: Lock::new(Contents {
+                buf,
+                waker: None,
+                closed: false,
+            }),
+            #[cfg(feature = "threadsafe")]
+            ready: Condvar::new(),
+        })
+    }
+
+    pub(crate) fn lock(&self) -> Guard<'_, Contents<B>> {
+        lock(&self.contents)
+    }
+
+    /// Blocks until the observer wakes up this inbox (or spuriously, see `Condvar::wait`)
+    #[cfg(feature = "threadsafe")]
+    pub(crate) fn wait<'a>(&'a self, guard: Guard<'a, Contents<B>>) -> Guard<'a, Contents<B>> {
+        sync::wait(&self.ready, guard)
+    }
+
+    /// Same as `wait` but gives up after `timeout`
+    #[cfg(feature = "threadsafe")]
+    pub(crate) fn wait_timeout<'a>(
+        &'a self,
+        guard: Guard<'a, Contents<B>>,
+        timeout: std::time::Duration,
+    ) -> Guard<'a, Contents<B>> {
+        sync::wait_timeout(&self.ready, guard, timeout)
+    }
+
+    /// Wakes up whoever is waiting, with `waker` taken out of the contents beforehand
+    fn wake(&self, waker: Option<Waker>) {
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        #[cfg(feature = "threadsafe")]
+        self.ready.notify_all();
+    }
+}
+
+impl<B> Contents<B> {
+    /// `true` once the observer feeding this inbox is gone
+    /// (either unregistered or the reactive itself was dropped)
+    #[cfg(any(feature = "async", feature = "threadsafe"))]
+    pub(crate) fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn set_waker(&mut self, waker: &Waker) {
+        match &self.waker {
+            Some(w) if w.will_wake(waker) => {}
+            _ => self.waker = Some(waker.clone()),
+        }
+    }
+}
+
+/// Marks the inbox as closed when the observer holding it is dropped
+struct CloseOnDrop<B>(Ptr<Inbox<B>>);
+
+impl<B> Drop for CloseOnDrop<B> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut contents = self.0.lock();
+            contents.closed = true;
+            contents.waker.take()
+        };
+        self.0.wake(waker);
+    }
+}
+
+/// Removes the observer from the reactive (if it is still alive) when dropped
+pub(crate) struct Registration<T>(pub(crate) ObserverHandle<T>);
+
+impl<T> Registration<T> {
+    pub(crate) fn unregister(&self) {
+        self.0.remove();
+    }
+}
+
+impl<T> Drop for Registration<T> {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> Reactive<T> {
+    /// Registers an observer that calls `f` with the inbox buffer and the new value
+    /// and then wakes up whoever is waiting on the inbox.
+    pub(crate) fn add_inbox_observer<
+        #[cfg(not(feature = "threadsafe"))] B: 'static,
+        #[cfg(feature = "threadsafe")] B: Send + 'static,
+    >(
+        &self,
+        inbox: &Ptr<Inbox<B>>,
+        #[cfg(not(feature = "threadsafe"))] f: impl FnMut(&mut B, &T) + 'static,
+        #[cfg(feature = "threadsafe")] f: impl FnMut(&mut B, &T) + Send + 'static,
+    ) -> Registration<T> {
+        self.observe_with_current(|_, observers| observers.add_inbox(inbox, f))
+    }
+}
+
+impl<T> Registrar<'_, T, DefaultBackend> {
+    /// Same as `Reactive::add_inbox_observer` (see `Reactive::observe_with_current`)
+    pub(crate) fn add_inbox<
+        #[cfg(not(feature = "threadsafe"))] B: 'static,
+        #[cfg(feature = "threadsafe")] B: Send + 'static,
+    >(
+        &mut self,
+        inbox: &Ptr<Inbox<B>>,
+        #[cfg(not(feature = "threadsafe"))] mut f: impl FnMut(&mut B, &T) + 'static,
+        #[cfg(feature = "threadsafe")] mut f: impl FnMut(&mut B, &T) + Send + 'static,
+    ) -> Registration<T> {
+        let guard = CloseOnDrop(inbox.clone());
+        Registration(self.add(move |val| {
+            let waker = {
+                let mut contents = guard.0.lock();
+                f(&mut contents.buf, val);
+                contents.waker.take()
+            };
+            guard.0.wake(waker);
+        }))
+    }
+}
@@ -37,11 +37,14 @@
 //! r.add_observer(|val| println!("{}", val));
 //!
 //! // non-threadsafe observer
+//! # #[cfg(not(feature = "threadsafe"))]
+//! # {
 //! let changes: Rc<RefCell<Vec<usize>>> = Default::default();
 //! r.add_observer({
 //!     let changes = changes.clone();
 //!     move |val| changes.borrow_mut().push(*val)
 //! });
+//! # }
 //!
 //! // threadsafe observer
 //! // must use Arc<Mutex<Vec<_>>> when threadsafety is enabled (features = ["threadsafe"])
@@ -114,20 +117,258 @@
 //! # }
 //! ```
 //!
+//! ## Async (features = ["async"])
+//!
+//! ```
+//! # #[cfg(feature = "async")]
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! use reactivate::Reactive;
+//!
+//! let r = Reactive::new(0);
+//! let next = r.changed();
+//!
+//! r.update(|_| 5);
+//!
+//! assert_eq!(Ok(5), next.await);
+//! # });
+//! ```
+//!
 //! ## Concurrency
 //!
 //! `Reactive` provides thread-safe implementations using `Arc` and `Mutex` for multi-threaded environments. Ensure to enable the `threadsafe` feature to use the thread-safe version.
 //!
+//! All the reactives are a [`GenericReactive`], whose [`Backend`] decides how its state is shared:
+//! [`LocalReactive`] is always `Rc` based and its observers don't need to be `Send`,
+//! and [`SyncReactive`] (with `std`) is always `Arc` based. The `threadsafe` feature only picks
+//! which one of them `Reactive` is, so single threaded parts of a program can keep using
+//! `LocalReactive` when it is enabled, and a program without it can still share some reactives
+//! with other threads. `SyncReactive`s are merged into another `SyncReactive` with `MergeSync`.
+//! Both can be merged like a `Reactive`, a `LocalReactive` can be derived into the others
+//! (`derive_reactive`, `derive_sync`) and a `Reactive` into a `LocalReactive` (`derive_local`)
+//! as far as `Send` allows.
+//!
+//! For read-heavy workloads, enable the `rwlock` feature and use `RwReactive`,
+//! which keeps the value in an `RwLock` so that readers don't block each other.
+//! For read-mostly values, the `arcswap` feature provides `ReactiveCell`,
+//! whose `value_arc()` doesn't take any lock at all.
+//! Without any feature, `Reactive::new_shared` keeps a large value in an `Arc`
+//! so that `value_arc()` hands out snapshots instead of deep copies.
+//!
+//! Observers are called with a clone of the new value after the value lock is released,
+//! so they can read (`value`, `with_value`, ...) the reactive they observe.
+//! `update_inplace`, `update_inplace_unchecked` and `notify_locked` are the exception:
+//! they avoid the clone by calling the observers while the value is still locked.
+//!
+//! Internally the observers are always locked before the value (and the value lock is never held
+//! while waiting for another one), so concurrent updates, observer registrations and observers
+//! reading their own reactive can't deadlock each other. This is model checked with
+//! [loom](https://docs.rs/loom) in `tests/loom_tests.rs`.
+//!
+//! `ReactiveBase::new` is `const`, so a `static` can hold one behind a `Mutex`.
+//! `Reactive::new` isn't, since a `Reactive` lives in its own `Arc`/`Rc` allocation,
+//! but `SyncReactive::const_new` (`Reactive::const_new` with the `threadsafe` feature) is:
+//! it returns a [`StaticReactive`], which allocates the reactive on first access.
+//!
+//! ```
+//! # #[cfg(feature = "threadsafe")]
+//! # {
+//! use reactivate::{Reactive, StaticReactive};
+//!
+//! static APP_STATE: StaticReactive<Vec<String>> = Reactive::const_new(Vec::new());
+//!
+//! APP_STATE.update_inplace(|logs| logs.push(String::from("started")));
+//! assert_eq!(1, APP_STATE.value().len());
+//! # }
+//! ```
+//!
+//! Observers can also update the reactive they observe (e.g. state machine auto-transitions),
+//! with any of the update methods, `with` and `notify_locked` included.
+//! The notification of such an update is deferred until the current one is done,
+//! and observers that keep doing that forever cause a panic instead of a hang.
+//! (observers called with the value still locked can only `notify_locked` it)
+//!
+//! A panicking observer doesn't make the reactive unusable: locks poisoned by the panic are taken
+//! regardless. Use `is_poisoned`, the `try_*` methods and `recover` to handle it explicitly.
+//! These three only exist on `SyncReactive` (`RefCell` has no notion of poisoning)
+//! and are left out of `loom` builds, whose mutexes can't report it either.
+//!
 //! ## Performance
 //!
 //! For performance-critical scenarios, `Reactive` provides methods like `update_unchecked` and `update_inplace_unchecked` for efficient updates without checking for value changes, optimizing performance especially in cases where frequent updates occur.
 //!
+//! `update_inplace` detects changes by hashing the value before and after the update.
+//! Enable the `fast-hash` feature to use `FxHasher` for this instead of the default SipHash.
+//!
+//! ## no_std
+//!
+//! Without the default `std` feature the crate is `no_std` (it still needs `alloc`).
+//! `Reactive` then only comes in its single threaded (`Rc` based) flavor, `update_inplace`
+//! hashes with FNV (see [`FnvBuildHasher`]) and the helpers that need threads, time or channels
+//! (`debounce`, `throttle`, `subscribe`, `AtomicReactive`, ...) are not available.
+//!
+//! ```toml
+//! reactivate = { version = "*", default-features = false }
+//! ```
+//!
 //! For more details and usage examples, refer to the individual method documentations.
 //!
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod arc_value;
+#[cfg(feature = "tokio")]
+mod async_reactive;
+#[cfg(feature = "threadsafe")]
+mod atomic;
+mod backend;
+mod base;
+#[cfg(feature = "threadsafe")]
+mod batch;
+#[cfg(feature = "tokio")]
+mod broadcast;
+#[cfg(feature = "async")]
+mod changed;
+mod clamp;
+#[cfg(feature = "threadsafe")]
+mod coalesce;
+#[cfg(feature = "crossbeam")]
+mod crossbeam;
+#[cfg(feature = "std")]
+mod debounce;
+#[cfg(feature = "threadsafe")]
+mod dispatch;
+mod either;
+mod error;
+#[cfg(feature = "std")]
+mod executor;
+mod extrema;
+mod fan_out;
+#[cfg(feature = "tokio")]
+mod feeder;
+mod fold;
+mod graph;
+mod hash;
+#[cfg(feature = "std")]
+mod inbox;
+mod local_reactive;
 mod macros;
+#[cfg(feature = "std")]
+mod map_ext;
 mod merge;
+mod merge_latest;
+#[cfg(feature = "std")]
+mod mpsc;
+mod numeric;
+mod observers;
+mod on_drop;
+mod option_ext;
+mod partition;
 mod reactive;
+#[cfg(feature = "arcswap")]
+mod reactive_cell;
+mod reactive_deque;
+mod reactive_sink;
+mod relay;
+mod result_ext;
+#[cfg(feature = "rwlock")]
+mod rw_reactive;
+#[cfg(feature = "futures-signals")]
+mod signals;
+#[cfg(feature = "async")]
+mod sink;
+#[cfg(feature = "tokio")]
+mod spawn;
+#[cfg(feature = "std")]
+mod static_reactive;
+#[cfg(feature = "async")]
+mod stream;
+mod string_ext;
+#[cfg(feature = "std")]
+mod subscription;
+mod sync;
+#[cfg(feature = "std")]
+mod sync_reactive;
+mod sync_to;
+#[cfg(feature = "threadsafe")]
+mod threaded;
+#[cfg(feature = "std")]
+mod throttle;
+#[cfg(feature = "std")]
+mod timed_history;
+#[cfg(all(feature = "threadsafe", not(loom)))]
+mod timeout;
+mod transform;
+mod transition;
+#[cfg(feature = "async")]
+mod update_async;
+mod vec_ext;
+#[cfg(feature = "threadsafe")]
+mod wait;
+#[cfg(feature = "tokio")]
+mod watch;
 
-pub use merge::Merge;
-pub use reactive::Reactive;
+#[cfg(feature = "tokio")]
+pub use async_reactive::{AsyncMerge, AsyncReactive};
+#[cfg(feature = "threadsafe")]
+pub use atomic::{AtomicInteger, AtomicReactive, AtomicRepr};
+#[cfg(feature = "std")]
+pub use backend::ThreadSafe;
+pub use backend::{Backend, Local};
+#[cfg(feature = "std")]
+pub use base::SyncReactiveBase;
+pub use base::{GenericReactiveBase, LocalReactiveBase, ReactiveBase};
+#[cfg(feature = "async")]
+pub use changed::{Changed, Closed, WaitUntil};
+#[cfg(feature = "threadsafe")]
+pub use coalesce::CoalescingReactive;
+#[cfg(feature = "crossbeam")]
+pub use crossbeam::CrossbeamReceiver;
+#[cfg(feature = "threadsafe")]
+pub use dispatch::ThreadDispatcher;
+pub use either::Either;
+pub use error::ReactiveError;
+#[cfg(feature = "std")]
+pub use executor::Job;
+#[cfg(feature = "tokio")]
+pub use feeder::Feeder;
+pub use hash::{FnvBuildHasher, FnvHasher};
+pub use local_reactive::LocalReactive;
+#[cfg(feature = "std")]
+pub use map_ext::ReactiveHashMapExt;
+#[cfg(feature = "std")]
+pub use merge::MergeSync;
+pub use merge::{Merge, MergeRef};
+pub use numeric::AsF64;
+pub use observers::{Observer, ObserverHandle, ObserverInfo};
+pub use reactive::{GenericReactive, Reactive};
+#[cfg(feature = "arcswap")]
+pub use reactive_cell::ReactiveCell;
+pub use reactive_deque::{DequeChange, ReactiveDeque};
+pub use reactive_sink::ReactiveSink;
+#[cfg(feature = "std")]
+pub use reactive_sink::WriteSink;
+pub use result_ext::ReactiveResultExt;
+#[cfg(feature = "rwlock")]
+pub use rw_reactive::RwReactive;
+#[cfg(feature = "async")]
+pub use sink::CheckedSink;
+#[cfg(feature = "std")]
+pub use static_reactive::StaticReactive;
+#[cfg(feature = "async")]
+pub use stream::ReactiveStream;
+pub use string_ext::ReactiveStringExt;
+#[cfg(feature = "std")]
+pub use subscription::Subscription;
+#[cfg(feature = "std")]
+pub use sync_reactive::SyncReactive;
+#[cfg(feature = "threadsafe")]
+pub use threaded::ObserverThread;
+#[cfg(feature = "std")]
+pub use throttle::ThrottledReactive;
+#[cfg(feature = "std")]
+pub use timed_history::TimedHistoryHandle;
+#[cfg(feature = "async")]
+pub use update_async::Conflict;
+pub use vec_ext::ReactiveVecExt;
@@ -0,0 +1,136 @@
+#[cfg(feature = "std")]
+use crate::SyncReactive;
+use crate::{
+    backend::{Backend, Local},
+    GenericReactive, Reactive,
+};
+
+/// Single threaded flavor of [`crate::Reactive`] that is always backed by `Rc<RefCell<_>>`,
+/// **regardless of the `threadsafe` feature**.
+///
+/// With the `threadsafe` feature enabled, `Reactive` is a [`crate::SyncReactive`] for the whole build
+/// (including every dependency using this crate). `LocalReactive` lets the single threaded parts
+/// of such a program (a UI layer, ...) keep using cheap, lock free reactives whose observers
+/// don't need to be `Send`. It is neither `Send` nor `Sync`. (`SyncReactive` is the always thread safe counterpart)
+///
+/// It is the same [`GenericReactive`] as the other flavors, so it has the same methods.
+/// The observers of `set`, `update`, `update_unchecked` and `notify` are called with a clone
+/// of the new value after the value is released, and the notifications of updates made by
+/// the observers to the reactive they observe are deferred until the current notification is done.
+/// The in-place updates don't clone the value, so their observers get it while it is still borrowed:
+/// they can read the reactive but updating it panics.
+///
+/// # Examples
+/// ```
+/// use reactivate::LocalReactive;
+/// use std::rc::Rc;
+///
+/// let label = Rc::new("count"); // not `Send`
+///
+/// let r = LocalReactive::new(10);
+/// let d = r.derive(move |val| format!("{}: {}", label, val));
+///
+/// r.update(|val| val * 2);
+///
+/// assert_eq!(20, r.value());
+/// assert_eq!("count: 20", d.value());
+/// ```
+pub type LocalReactive<T> = GenericReactive<T, Local>;
+
+impl<T> LocalReactive<T> {
+    /// Same as `derive` but the derived reactive uses another backend.
+    ///
+    /// The observer stays on this (local) reactive, so `f` doesn't need to be `Send`.
+    /// The derived reactive isn't recorded as a child (see `descendant_count`),
+    /// since the children of a reactive all share its backend.
+    fn derive_into<U: Clone + PartialEq + 'static, B: Backend>(
+        &self,
+        f: impl Fn(&T) -> U + 'static,
+    ) -> GenericReactive<U, B> {
+        self.observe_with_current(|val, observers| {
+            let derived = GenericReactive::new(f(val));
+            observers.add({
+                let derived = derived.clone();
+                move |val| derived.update(|_| f(val))
+            });
+            derived
+        })
+    }
+
+    /// Same as [`GenericReactive::derive`] but the derived reactive is a [`crate::Reactive`],
+    /// so it can be merged and combined with the other reactives of the program
+    /// (or handed to other threads with the `threadsafe` feature).
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{LocalReactive, Merge, Reactive};
+    ///
+    /// let width = LocalReactive::new(3);
+    /// let height = Reactive::new(2);
+    ///
+    /// let size = (&width.derive_reactive(|w| *w), &height).merge();
+    /// width.update(|w| w + 1);
+    ///
+    /// assert_eq!((4, 2), size.value());
+    /// ```
+    pub fn derive_reactive<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+    >(
+        &self,
+        f: impl Fn(&T) -> U + 'static,
+    ) -> Reactive<U> {
+        self.derive_into(f)
+    }
+
+    /// Same as [`GenericReactive::derive`] but the derived reactive is a [`SyncReactive`],
+    /// which can be handed to other threads even without the `threadsafe` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::LocalReactive;
+    ///
+    /// let r = LocalReactive::new(10);
+    /// let d = r.derive_sync(|val| val + 5);
+    ///
+    /// r.update(|val| val * 2);
+    ///
+    /// let seen = std::thread::spawn(move || d.value()).join().unwrap();
+    /// assert_eq!(25, seen);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn derive_sync<U: Clone + PartialEq + Send + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + 'static,
+    ) -> SyncReactive<U> {
+        self.derive_into(f)
+    }
+
+    /// Same as [`GenericReactive::derive`]. Lets `Reactive::derive_local` be written
+    /// the same way in the builds where `Reactive` is a `LocalReactive`.
+    ///
+    /// Only available without the `threadsafe` feature, since the observers of
+    /// a thread safe `Reactive` must be `Send` and a `LocalReactive` is not.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let d = r.derive_local(|val| val + 5);
+    ///
+    /// r.update(|val| val * 2);
+    ///
+    /// assert_eq!(25, d.value());
+    /// ```
+    #[cfg(not(feature = "threadsafe"))]
+    pub fn derive_local<U: Clone + PartialEq + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + 'static,
+    ) -> LocalReactive<U>
+    where
+        T: Clone,
+    {
+        self.derive(f)
+    }
+}
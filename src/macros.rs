@@ -1,9 +1,14 @@
-use crate::{Merge, Reactive};
+#[cfg(feature = "threadsafe")]
+use crate::LocalReactive;
+use crate::{Merge, MergeRef, Reactive};
+#[cfg(feature = "std")]
+use crate::{MergeSync, SyncReactive};
+use alloc::sync::Arc;
 use paste::paste;
 
 impl<
-        #[cfg(not(feature = "threadsafe"))] T: Clone + Default + 'static,
-        #[cfg(feature = "threadsafe")] T: Clone + Default + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
     > Merge for &Reactive<T>
 {
     type Output = T;
@@ -12,16 +17,51 @@ impl<
     }
 }
 
+/// merges through a `Reactive` that mirrors the local one (see [`LocalReactive::derive_reactive`]).
+/// Without the `threadsafe` feature a `LocalReactive` is a `Reactive` already.
+#[cfg(feature = "threadsafe")]
+impl<T: Clone + PartialEq + Send + 'static> Merge for &LocalReactive<T> {
+    type Output = T;
+    fn merge(self) -> Reactive<Self::Output> {
+        self.derive_reactive(T::clone)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone + Send + 'static> MergeSync for &SyncReactive<T> {
+    type Output = T;
+    fn merge_sync(self) -> SyncReactive<Self::Output> {
+        self.clone()
+    }
+}
+
 #[cfg(not(feature = "threadsafe"))]
 macro_rules! impl_merge_for_nested_tuple {
     ( $($i:literal),* ) => { paste!{
     impl < $( [<T $i>], )* > Merge for ( $( [<T $i>], )* )
     where
         $( [<T $i>]: Merge, ) *
-        $( [<T $i>]::Output: Clone + Default + 'static, ) *
+        $( [<T $i>]::Output: Clone + 'static, ) *
     {
         body!($($i),*);
     }
+
+    impl < $( [<T $i>], )* > MergeRef for ( $( [<T $i>], )* )
+    where
+        $( [<T $i>]: Merge, ) *
+        $( [<T $i>]::Output: Clone + 'static, ) *
+    {
+        body_ref!($($i),*);
+    }
+
+    #[cfg(feature = "std")]
+    impl < $( [<T $i>], )* > MergeSync for ( $( [<T $i>], )* )
+    where
+        $( [<T $i>]: MergeSync, ) *
+        $( [<T $i>]::Output: Clone + Send + 'static, ) *
+    {
+        body_sync!($($i),*);
+    }
     }};
 }
 
@@ -31,10 +71,28 @@ macro_rules! impl_merge_for_nested_tuple {
     impl < $( [<T $i>], )* > Merge for ( $( [<T $i>], )* )
     where
         $( [<T $i>]: Merge, ) *
-        $( [<T $i>]::Output: Clone + Default + Send + 'static, ) *
+        $( [<T $i>]::Output: Clone + Send + 'static, ) *
     {
         body!($($i),*);
     }
+
+    // the merged tuple is shared between threads through the `Arc`, hence `Sync`
+    impl < $( [<T $i>], )* > MergeRef for ( $( [<T $i>], )* )
+    where
+        $( [<T $i>]: Merge, ) *
+        $( [<T $i>]::Output: Clone + Send + Sync + 'static, ) *
+    {
+        body_ref!($($i),*);
+    }
+
+    #[cfg(feature = "std")]
+    impl < $( [<T $i>], )* > MergeSync for ( $( [<T $i>], )* )
+    where
+        $( [<T $i>]: MergeSync, ) *
+        $( [<T $i>]::Output: Clone + Send + 'static, ) *
+    {
+        body_sync!($($i),*);
+    }
     }};
 }
 
@@ -47,14 +105,70 @@ macro_rules! body {
             let values = ( $(reactives.$i.value(),)* );
             let combined = Reactive::new(values);
 
-            $( reactives.$i.add_observer({
-                let combined = combined.clone();
-                // we know for sure that the value inside 'combined' did change
-                // because 'combined' stores the reactive values as-is without any transformation
-                // eg: (&Reactive<String>, &Reactive<usize>, ...) -> Reactive<(String, usize, ...)>
-                // so if the parent reactive changes, the 'combined' will definitely change.
-                // Therefore 'unchecked' is fine.
-                move |val| combined.update_inplace_unchecked(|c| c.$i = val.clone())
+            $( reactives.$i.observe_with_current(|current, observers| {
+                // the source may have changed since `values` was read
+                combined.acq_val().$i = current.clone();
+                observers.add({
+                    let combined = combined.clone();
+                    // we know for sure that the value inside 'combined' did change
+                    // because 'combined' stores the reactive values as-is without any transformation
+                    // eg: (&Reactive<String>, &Reactive<usize>, ...) -> Reactive<(String, usize, ...)>
+                    // so if the parent reactive changes, the 'combined' will definitely change.
+                    // Therefore always notifying (like 'with_mut' does) is fine.
+                    // 'with_mut' also releases the lock before calling the observers,
+                    // so they can update the sources of 'combined' in turn.
+                    move |val| combined.with_mut(|c| c.$i = val.clone())
+                });
+            }); )*
+
+            combined
+        }
+    }};
+}
+
+macro_rules! body_ref {
+    ( $($i:literal),* ) => {paste!{
+        type Output = ( $([<T $i>]::Output,)* );
+
+        fn merge_ref(self) -> Reactive<Arc<Self::Output>> {
+            let reactives = ( $(self.$i.merge(),)* );
+            let values = ( $(reactives.$i.value(),)* );
+            let combined = Reactive::new(Arc::new(values));
+
+            $( reactives.$i.observe_with_current(|current, observers| {
+                // same as `body`, the source may have changed since `values` was read
+                Arc::make_mut(&mut combined.acq_val()).$i = current.clone();
+                observers.add({
+                    let combined = combined.clone();
+                    // same reasoning as `body`. the observers of 'combined' only get a clone of the `Arc`
+                    // and drop it once notified, so `make_mut` doesn't have to copy the tuple
+                    // unless someone else kept a snapshot of it.
+                    move |val| combined.with_mut(|c| Arc::make_mut(c).$i = val.clone())
+                });
+            }); )*
+
+            combined
+        }
+    }};
+}
+
+#[cfg(feature = "std")]
+macro_rules! body_sync {
+    ( $($i:literal),* ) => {paste!{
+        type Output = ( $([<T $i>]::Output,)* );
+
+        fn merge_sync(self) -> SyncReactive<Self::Output> {
+            let reactives = ( $(self.$i.merge_sync(),)* );
+            let values = ( $(reactives.$i.value(),)* );
+            let combined = SyncReactive::new(values);
+
+            $( reactives.$i.observe_with_current(|current, observers| {
+                // same reasoning as `body`
+                combined.acq_val().$i = current.clone();
+                observers.add({
+                    let combined = combined.clone();
+                    move |val| combined.with_mut(|c| c.$i = val.clone())
+                });
             }); )*
 
             combined
@@ -78,3 +192,76 @@ impl_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
 impl_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13);
 impl_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14);
 impl_merge_for_nested_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+
+/// Constructs a reactive and chains derives off of it in one expression.
+///
+/// # Grammar
+/// ```text
+/// reactive!( <initial value> [ => <derive fn> ]* )
+/// ```
+///
+/// expands to
+///
+/// ```text
+/// Reactive::new(<initial value>)[.derive(<derive fn>)]*
+/// ```
+///
+/// Only the last reactive of the chain is returned.
+/// Create the source yourself and call `derive` on it if it needs to be updated later.
+///
+/// # Examples
+/// ```
+/// use reactivate::reactive;
+///
+/// let r = reactive!(10);
+/// assert_eq!(10, r.value());
+///
+/// let d = reactive!(10 => |v| v + 1 => |v| v * 2);
+/// assert_eq!(22, d.value());
+///
+/// let len = reactive!(String::from("🦀") => |s: &String| s.len());
+/// assert_eq!(4, len.value());
+/// ```
+#[macro_export]
+macro_rules! reactive {
+    ( $init:expr $( => $f:expr )* $(,)? ) => {
+        $crate::Reactive::new($init) $( .derive($f) )*
+    };
+}
+
+/// Merges reactives and derives from them with the tuple already destructured.
+///
+/// # Grammar
+/// ```text
+/// combine!( <reactive>, <reactive>, ... => |<binding>, <binding>, ...| <body> )
+/// ```
+///
+/// expands to
+///
+/// ```text
+/// (&<reactive>, &<reactive>, ...).merge().derive(|(<binding>, <binding>, ...)| <body>)
+/// ```
+///
+/// There must be exactly one binding per reactive and each binding is a reference
+/// to the current value of its reactive. Works for as many reactives as `Merge` does (up to 16).
+///
+/// # Examples
+/// ```
+/// use reactivate::{combine, Reactive};
+///
+/// let a = Reactive::new(String::from("hazash"));
+/// let b = Reactive::new(1);
+/// let c = Reactive::new(2);
+///
+/// let d = combine!(a, b, c => |a, b, c| a.len() + b + c);
+/// assert_eq!(9, d.value());
+///
+/// b.update(|_| 10);
+/// assert_eq!(18, d.value());
+/// ```
+#[macro_export]
+macro_rules! combine {
+    ( $( $src:expr ),+ => | $( $arg:pat_param ),+ $(,)? | $body:expr ) => {
+        $crate::Merge::merge(( $( &$src, )+ )).derive(|( $( $arg, )+ )| $body)
+    };
+}
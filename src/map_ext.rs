@@ -0,0 +1,46 @@
+use crate::Reactive;
+use std::collections::HashMap;
+
+/// Shortcuts for the most common derives of a `Reactive<HashMap<K, V>>`.
+///
+/// Each method is exactly equivalent to the `derive` call mentioned in its docs.
+///
+/// # Examples
+/// ```
+/// use reactivate::{Reactive, ReactiveHashMapExt};
+/// use std::collections::HashMap;
+///
+/// let r = Reactive::new(HashMap::from([("a", 1)]));
+/// let len = r.len_reactive();
+///
+/// r.update_inplace_unchecked(|m| {
+///     m.insert("b", 2);
+/// });
+///
+/// assert_eq!(2, len.value());
+/// ```
+pub trait ReactiveHashMapExt {
+    /// `.derive(|m| m.len())`
+    fn len_reactive(&self) -> Reactive<usize>;
+
+    /// `.derive(|m| m.is_empty())`
+    fn is_empty_reactive(&self) -> Reactive<bool>;
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] K: Clone + 'static,
+        #[cfg(feature = "threadsafe")] K: Clone + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] V: Clone + 'static,
+        #[cfg(feature = "threadsafe")] V: Clone + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] S: Clone + 'static,
+        #[cfg(feature = "threadsafe")] S: Clone + Send + 'static,
+    > ReactiveHashMapExt for Reactive<HashMap<K, V, S>>
+{
+    fn len_reactive(&self) -> Reactive<usize> {
+        self.derive(|m| m.len())
+    }
+
+    fn is_empty_reactive(&self) -> Reactive<bool> {
+        self.derive(|m| m.is_empty())
+    }
+}
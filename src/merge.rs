@@ -1,4 +1,5 @@
 use crate::Reactive;
+use alloc::sync::Arc;
 
 /// This trait is used for implementing variadic generics.
 ///
@@ -20,7 +21,84 @@ use crate::Reactive;
 /// let r: Reactive<(usize, String, f64)> = (&r1, &r2, &r3).merge();
 ///
 /// ```
+///
+/// Every change of a source is cloned into its slot of the merged tuple,
+/// and the observers of the merged reactive get a clone of the whole tuple.
+/// For values that are expensive to clone, keep them in an `Arc` (see [`Reactive::new_shared`])
+/// so that the tuple holds `Arc`s and a change only copies a pointer,
+/// and use [`MergeRef`] to keep the tuple itself from being cloned for the observers.
+/// ```
+/// use reactivate::{Merge, Reactive};
+/// use std::sync::Arc;
+///
+/// let lines = Reactive::new_shared(vec![String::from("started")]);
+/// let level = Reactive::new(1);
+///
+/// let merged: Reactive<(Arc<Vec<String>>, i32)> = (&lines, &level).merge();
+///
+/// lines.update_cow(|lines| lines.push(String::from("running")));
+///
+/// assert!(Arc::ptr_eq(&lines.value_arc(), &merged.value().0));
+/// ```
 pub trait Merge {
     type Output;
     fn merge(self) -> Reactive<Self::Output>;
 }
+
+/// Same as [`Merge`] but the merged tuple is kept behind an `Arc`.
+///
+/// The observers of the merged reactive (derived reactives included) get a clone of the `Arc`
+/// instead of a deep copy of the tuple, and a change of a source replaces its slot in place
+/// (`Arc::make_mut`), which only copies the tuple while a snapshot of it is held somewhere.
+/// Combined with sources holding `Arc`s (see [`Reactive::new_shared`]),
+/// a change of a source doesn't deep copy anything.
+///
+/// Implemented for the same tuples as [`Merge`].
+/// ```
+/// use reactivate::{MergeRef, Reactive};
+/// use std::sync::Arc;
+///
+/// let lines = Reactive::new_shared(vec![String::from("started")]);
+/// let level = Reactive::new(1);
+///
+/// let merged: Reactive<Arc<(Arc<Vec<String>>, i32)>> = (&lines, &level).merge_ref();
+/// let summary = merged.derive(|merged| format!("{} lines at level {}", merged.0.len(), merged.1));
+///
+/// level.update(|n| n + 1);
+///
+/// assert_eq!("1 lines at level 2", summary.value());
+/// ```
+pub trait MergeRef {
+    type Output;
+    fn merge_ref(self) -> Reactive<Arc<Self::Output>>;
+}
+
+/// Same as [`Merge`] but for [`crate::SyncReactive`]s, merged into a `SyncReactive`.
+///
+/// Available in every build with `std`, unlike merging them with [`Merge`],
+/// which goes through a `Reactive` and so needs the `threadsafe` feature.
+/// The merged reactive can be handed to other threads just like its sources.
+///
+/// Implemented for `&SyncReactive` and for the same tuples as [`Merge`] (of `&SyncReactive`s).
+/// ```
+/// use reactivate::{MergeSync, SyncReactive};
+///
+/// let done = SyncReactive::new(0);
+/// let total = SyncReactive::new(10);
+///
+/// let progress = (&done, &total).merge_sync().derive(|(done, total)| done * 100 / total);
+///
+/// std::thread::spawn({
+///     let done = done.clone();
+///     move || done.update(|n| n + 5)
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(50, progress.value());
+/// ```
+#[cfg(feature = "std")]
+pub trait MergeSync {
+    type Output;
+    fn merge_sync(self) -> crate::SyncReactive<Self::Output>;
+}
@@ -0,0 +1,100 @@
+use crate::{Merge, Reactive};
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<Option<T>>
+{
+    /// Merges two reactives whose values may not be available yet (`None`)
+    /// into a reactive holding both of them.
+    ///
+    /// Unlike [`Reactive::merge_latest`], the merged reactive is updated on every change
+    /// of either side, even while the other side is still `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let name: Reactive<Option<String>> = Reactive::new(None);
+    /// let age = Reactive::new(Some(30));
+    ///
+    /// let merged = name.merge_with_defaults(&age);
+    /// assert_eq!((None, Some(30)), merged.value());
+    ///
+    /// name.update(|_| Some(String::from("john")));
+    /// assert_eq!((Some(String::from("john")), Some(30)), merged.value());
+    /// ```
+    pub fn merge_with_defaults<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + Send + 'static,
+    >(
+        &self,
+        other: &Reactive<Option<U>>,
+    ) -> Reactive<(Option<T>, Option<U>)> {
+        (self, other).merge()
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Merges two reactives like `combineLatest` from RxJS.
+    ///
+    /// The merged reactive holds `None` until **both** reactives have changed at least once
+    /// after this call (their current values don't count, use [`Merge`] for that).
+    /// From then on it holds the latest value of each side and is updated
+    /// whenever either of them changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let a = Reactive::new(0);
+    /// let b = Reactive::new("");
+    ///
+    /// let merged = a.merge_latest(&b);
+    /// assert_eq!(None, merged.value());
+    ///
+    /// a.update(|_| 1);
+    /// assert_eq!(None, merged.value());
+    ///
+    /// b.update(|_| "one");
+    /// assert_eq!(Some((1, "one")), merged.value());
+    ///
+    /// a.update(|_| 2);
+    /// assert_eq!(Some((2, "one")), merged.value());
+    /// ```
+    pub fn merge_latest<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + Send + 'static,
+    >(
+        &self,
+        other: &Reactive<U>,
+    ) -> Reactive<Option<(T, U)>> {
+        // every observer only writes its own slot so no observer ever locks
+        // the other source. (same approach as `Merge`)
+        let latest: Reactive<(Option<T>, Option<U>)> = Reactive::new((None, None));
+        let merged = Reactive::new(None);
+
+        self.add_observer({
+            let latest = latest.clone();
+            move |val| latest.update_inplace_unchecked(|l| l.0 = Some(val.clone()))
+        });
+        other.add_observer({
+            let latest = latest.clone();
+            move |val| latest.update_inplace_unchecked(|l| l.1 = Some(val.clone()))
+        });
+        latest.add_observer({
+            let merged = merged.clone();
+            move |(a, b)| {
+                if let (Some(a), Some(b)) = (a, b) {
+                    merged.set(Some((a.clone(), b.clone())));
+                }
+            }
+        });
+
+        merged
+    }
+}
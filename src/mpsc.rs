@@ -0,0 +1,62 @@
+use crate::{observers::ObserverHandle, Reactive};
+use std::sync::mpsc::{Sender, SyncSender, TrySendError};
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Sends a clone of every new value of the reactive into `sender`.
+    ///
+    /// Once the receiving end is dropped and sending fails,
+    /// the observer removes itself from the reactive.
+    ///
+    /// Returns a handle that can be used to stop forwarding earlier.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::sync::mpsc;
+    ///
+    /// let r = Reactive::new(0);
+    /// let (tx, rx) = mpsc::channel();
+    ///
+    /// r.observe_into(tx);
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(vec![1, 2], rx.try_iter().collect::<Vec<_>>());
+    /// ```
+    pub fn observe_into(&self, sender: Sender<T>) -> ObserverHandle<T> {
+        self.add_retaining_observer(move |val| sender.send(val.clone()).is_ok())
+    }
+
+    /// Same as [`Reactive::observe_into`] but for a bounded `SyncSender`.
+    ///
+    /// **When the channel is full, the new value is dropped** instead of blocking.
+    /// Observers run on the updating thread while the observers are locked, so blocking here
+    /// would stall it (and deadlock if the receiver tries to update the same reactive).
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::sync::mpsc;
+    ///
+    /// let r = Reactive::new(0);
+    /// let (tx, rx) = mpsc::sync_channel(1);
+    ///
+    /// r.observe_into_sync(tx);
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1); // channel is full, dropped
+    ///
+    /// assert_eq!(vec![1], rx.try_iter().collect::<Vec<_>>());
+    /// ```
+    pub fn observe_into_sync(&self, sender: SyncSender<T>) -> ObserverHandle<T> {
+        self.add_retaining_observer(move |val| match sender.try_send(val.clone()) {
+            Ok(()) | Err(TrySendError::Full(_)) => true,
+            Err(TrySendError::Disconnected(_)) => false,
+        })
+    }
+}
@@ -0,0 +1,189 @@
+use crate::Reactive;
+use alloc::collections::VecDeque;
+use core::ops::Sub;
+
+/// Lossy conversion of primitive numbers to `f64`,
+/// used by numeric helpers like [`Reactive::moving_average`].
+///
+/// Implemented for all the primitive integer and floating point types.
+pub trait AsF64: Copy {
+    /// Converts the number to `f64` like `self as f64` does.
+    /// (large integers may lose precision)
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ( $($t:ty),* ) => { $(
+        impl AsF64 for $t {
+            #[inline]
+            fn as_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )* };
+}
+
+impl_as_f64!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+fn mean(values: &VecDeque<f64>) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: AsF64 + 'static,
+        #[cfg(feature = "threadsafe")] T: AsF64 + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive holding the arithmetic mean of the last `window` values
+    /// of this reactive. (the current value counts as the first one)
+    ///
+    /// Until `window` values have been seen, the mean is taken over the values
+    /// available so far instead of waiting for a full window.
+    ///
+    /// # Panics
+    /// if `window` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(2.0);
+    /// let avg = r.moving_average(3);
+    ///
+    /// r.update(|_| 4.0);
+    /// assert_eq!(3.0, avg.value()); // (2 + 4) / 2
+    ///
+    /// r.update(|_| 6.0);
+    /// r.update(|_| 8.0);
+    /// assert_eq!(6.0, avg.value()); // (4 + 6 + 8) / 3
+    /// ```
+    pub fn moving_average(&self, window: usize) -> Reactive<f64> {
+        assert!(window > 0, "moving average window must be non-zero");
+
+        self.observe_with_current(|val, observers| {
+            let mut values = VecDeque::with_capacity(window);
+            values.push_back(val.as_f64());
+            let average = Reactive::new(mean(&values));
+
+            observers.add({
+                let average = average.clone();
+                move |val| {
+                    if values.len() == window {
+                        values.pop_front();
+                    }
+                    values.push_back(val.as_f64());
+                    average.update(|_| mean(&values));
+                }
+            });
+            average
+        })
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + Sub<Output = T> + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Sub<Output = T> + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive holding the difference between the last two values
+    /// of this reactive. (`new - old`)
+    ///
+    /// It starts with `current - current` (zero for numbers), so the first change
+    /// emits `new - initial` where `initial` is the value at the time of this call.
+    ///
+    /// Every change of this reactive is emitted (using `set`),
+    /// even when the difference is the same as the previous one.
+    ///
+    /// Handy for turning counters into rates. (position into velocity, bytes into bandwidth, ...)
+    ///
+    /// # Panics
+    /// `new - old` is computed with `T`'s `Sub`, so for unsigned integers a value that decreases
+    /// overflows: the update that decreased it panics in debug builds (and the difference wraps around
+    /// in release builds). Use a signed type (e.g. `i64` instead of `u64`) for values that can go down.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let bytes = Reactive::new(100);
+    /// let diff = bytes.derive_diff();
+    ///
+    /// assert_eq!(0, diff.value());
+    ///
+    /// bytes.update(|_| 150);
+    /// assert_eq!(50, diff.value());
+    ///
+    /// bytes.update(|_| 120);
+    /// assert_eq!(-30, diff.value());
+    /// ```
+    pub fn derive_diff(&self) -> Reactive<T> {
+        self.observe_with_current(|val, observers| {
+            let mut prev = val.clone();
+            let diff = Reactive::new(prev.clone() - prev.clone());
+
+            observers.add({
+                let diff = diff.clone();
+                move |val| {
+                    let old = core::mem::replace(&mut prev, val.clone());
+                    diff.set(val.clone() - old);
+                }
+            });
+            diff
+        })
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + Default + PartialEq + Sub<Output = T> + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Default + PartialEq + Sub<Output = T> + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive holding how much this reactive changed in its last notification.
+    /// (`new - old`)
+    ///
+    /// It starts at `T::default()` and goes back to `T::default()` when this reactive is
+    /// notified without actually changing. (e.g. `set` to the same value)
+    ///
+    /// Unlike [`Reactive::derive_diff`], the delta is applied using `update`,
+    /// so its observers are only notified when the delta itself changes.
+    ///
+    /// # Panics
+    /// same as [`Reactive::derive_diff`]: a decreasing unsigned value overflows `new - old`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let score: Reactive<i64> = Reactive::new(100);
+    /// let delta = score.delta_reactive();
+    /// let big_jump = delta.derive(|d| d.abs() > 10);
+    ///
+    /// assert_eq!(0, delta.value());
+    ///
+    /// score.update(|s| s + 25);
+    /// assert_eq!(25, delta.value());
+    /// assert!(big_jump.value());
+    ///
+    /// score.set(125);
+    /// assert_eq!(0, delta.value());
+    /// ```
+    pub fn delta_reactive(&self) -> Reactive<T> {
+        self.observe_with_current(|val, observers| {
+            let mut prev = val.clone();
+            let delta = Reactive::new(T::default());
+
+            observers.add({
+                let delta = delta.clone();
+                move |val| {
+                    let old = core::mem::replace(&mut prev, val.clone());
+                    if *val == old {
+                        delta.update(|_| T::default());
+                    } else {
+                        delta.update(|_| val.clone() - old);
+                    }
+                }
+            });
+            delta
+        })
+    }
+}
@@ -0,0 +1,245 @@
+#[cfg(feature = "std")]
+use crate::{backend::ThreadSafe, executor::Executor};
+use crate::{
+    backend::{Backend, DefaultBackend, IntoObserver},
+    reactive::Inner,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// Boxed observer function stored inside a [`crate::Reactive`]
+#[cfg(not(feature = "threadsafe"))]
+pub type Observer<T> = Box<dyn FnMut(&T)>;
+
+/// Boxed observer function stored inside a [`crate::Reactive`]
+#[cfg(feature = "threadsafe")]
+pub type Observer<T> = Box<dyn FnMut(&T) + Send>;
+
+/// Handle to an observer added to a reactive, used to remove it later on.
+///
+/// Dropping the handle does **not** remove the observer.
+/// The handle also doesn't keep the reactive alive.
+pub struct ObserverHandle<T, B: Backend = DefaultBackend> {
+    inner: B::WeakPtr<Inner<T, B>>,
+    id: usize,
+}
+
+impl<T, B: Backend> ObserverHandle<T, B> {
+    pub(crate) fn new(inner: B::WeakPtr<Inner<T, B>>, id: usize) -> Self {
+        Self { inner, id }
+    }
+
+    /// Id of the observer, unique among the observers of its reactive.
+    /// (matches [`ObserverInfo::id`])
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Handle to an observer that was never added. (`remove` always returns `false`)
+    pub(crate) fn dangling() -> Self {
+        Self::new(B::dangling(), 0)
+    }
+
+    /// Removes the observer from the reactive.
+    ///
+    /// Returns `false` if the observer was already removed (or the reactive was dropped)
+    ///
+    /// Can be called by the observers of the same reactive (an observer removing itself or a sibling).
+    /// The observers are busy being called at that point, so the observer is removed
+    /// once the notification in progress is done, and `true` is returned right away.
+    pub fn remove(&self) -> bool {
+        match B::upgrade(&self.inner) {
+            Some(inner) => Inner::<T, B>::remove_observer(&inner, self.id),
+            None => false,
+        }
+    }
+}
+
+/// What is known about an observer registered on a reactive, for introspection.
+/// (see [`crate::Reactive::has_observer_matching`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObserverInfo {
+    id: usize,
+    type_name: &'static str,
+}
+
+impl ObserverInfo {
+    /// Id of the observer, unique among the observers of its reactive.
+    /// (matches [`ObserverHandle::id`] of the handle returned when it was added, if any)
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Type name of the observer function when it was added (see [`core::any::type_name`]).
+    ///
+    /// The observers added by the crate itself (to feed derived reactives, channels, ...)
+    /// are named after the method that added them.
+    /// The name is meant for debugging only, its exact format isn't guaranteed.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// List of observers along with a unique id (and type name) for each one of them
+/// so that they can be removed individually later on.
+///
+/// `infos` and `list` are always kept the same length.
+pub(crate) struct Observers<T, B: Backend = DefaultBackend> {
+    infos: Slots<ObserverInfo>,
+    list: Slots<B::Observer<T>>,
+    next_id: usize,
+
+    /// set by `SyncReactive::set_executor`. every observer is wrapped by it when pushed
+    executor: B::Executor<T>,
+}
+
+impl<T, B: Backend> Observers<T, B> {
+    pub(crate) fn push<F: IntoObserver<T, B>>(&mut self, f: F) -> usize {
+        self.push_boxed(f.into_observer(), core::any::type_name::<F>())
+    }
+
+    /// Same as `push` but for an observer that is already boxed,
+    /// named `type_name` (see `ObserverInfo::type_name`)
+    pub(crate) fn push_boxed(&mut self, f: B::Observer<T>, type_name: &'static str) -> usize {
+        let f = B::execute(&self.executor, f);
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.infos.push(ObserverInfo { id, type_name });
+        self.list.push(f);
+        id
+    }
+
+    /// id the next pushed observer will get
+    pub(crate) fn next_id(&self) -> usize {
+        self.next_id
+    }
+
+    pub(crate) fn remove(&mut self, id: usize) -> Option<B::Observer<T>> {
+        let idx = self
+            .infos
+            .as_slice()
+            .iter()
+            .position(|info| info.id == id)?;
+        self.infos.remove(idx);
+        Some(self.list.remove(idx))
+    }
+
+    pub(crate) fn map(&mut self, f: impl Fn(B::Observer<T>) -> B::Observer<T>) {
+        self.list = core::mem::take(&mut self.list).map(f);
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.infos.clear();
+        self.list.clear();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.list.as_slice().is_empty()
+    }
+
+    pub(crate) fn infos(&self) -> &[ObserverInfo] {
+        self.infos.as_slice()
+    }
+
+    pub(crate) fn as_slice(&self) -> &[B::Observer<T>] {
+        self.list.as_slice()
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [B::Observer<T>] {
+        self.list.as_mut_slice()
+    }
+
+    pub(crate) fn call(&mut self, val: &T) {
+        for obs in self.list.as_mut_slice() {
+            obs(val);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Observers<T, ThreadSafe> {
+    pub(crate) fn executor(&self) -> Option<&Executor<T>> {
+        self.executor.as_ref()
+    }
+
+    /// Installs the executor and wraps every existing observer with it.
+    pub(crate) fn set_executor(&mut self, executor: Executor<T>) {
+        self.map(|f| executor.wrap(f));
+        self.executor = Some(executor);
+    }
+}
+
+impl<T, B: Backend> Default for Observers<T, B> {
+    fn default() -> Self {
+        Self {
+            infos: Slots::Empty,
+            list: Slots::Empty,
+            next_id: 0,
+            executor: Default::default(),
+        }
+    }
+}
+
+/// `Vec` that doesn't allocate until it holds more than one item.
+/// (most reactives only ever have one observer, if any)
+#[derive(Default)]
+enum Slots<T> {
+    #[default]
+    Empty,
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> Slots<T> {
+    fn push(&mut self, item: T) {
+        *self = match core::mem::take(self) {
+            Slots::Empty => Slots::One(item),
+            Slots::One(first) => Slots::Many(vec![first, item]),
+            Slots::Many(mut items) => {
+                items.push(item);
+                Slots::Many(items)
+            }
+        };
+    }
+
+    /// panics if `idx` is out of bounds. (same as `Vec::remove`)
+    fn remove(&mut self, idx: usize) -> T {
+        match core::mem::take(self) {
+            Slots::One(item) if idx == 0 => item,
+            Slots::Many(mut items) => {
+                let item = items.remove(idx);
+                *self = Slots::Many(items);
+                item
+            }
+            _ => panic!("removal index (is {idx}) should be < len"),
+        }
+    }
+
+    fn clear(&mut self) {
+        *self = Slots::Empty;
+    }
+
+    fn map(self, mut f: impl FnMut(T) -> T) -> Self {
+        match self {
+            Slots::Empty => Slots::Empty,
+            Slots::One(item) => Slots::One(f(item)),
+            Slots::Many(items) => Slots::Many(items.into_iter().map(f).collect()),
+        }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        match self {
+            Slots::Empty => &[],
+            Slots::One(item) => core::slice::from_ref(item),
+            Slots::Many(items) => items,
+        }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        match self {
+            Slots::Empty => &mut [],
+            Slots::One(item) => core::slice::from_mut(item),
+            Slots::Many(items) => items,
+        }
+    }
+}
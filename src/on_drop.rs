@@ -0,0 +1,56 @@
+#[cfg(feature = "std")]
+use crate::backend::ThreadSafe;
+use crate::backend::{Backend, Local};
+use crate::GenericReactive;
+use alloc::{boxed::Box, vec::Vec};
+
+/// Callbacks registered with [`GenericReactive::on_drop`].
+/// Shared by all the clones of a reactive and called when the last one of them is dropped.
+pub(crate) struct DropHooks<B: Backend>(Vec<B::Hook>);
+
+impl<B: Backend> Default for DropHooks<B> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<B: Backend> Drop for DropHooks<B> {
+    fn drop(&mut self) {
+        for hook in self.0.drain(..) {
+            hook();
+        }
+    }
+}
+
+/// `on_drop` for a backend whose hooks are boxed `FnOnce() + $send`
+macro_rules! impl_on_drop {
+    ($backend:ty, [$($send:tt)*]) => {
+        impl<T> GenericReactive<T, $backend> {
+            /// Registers `f` to be called once the last clone of this reactive is dropped.
+            /// Multiple callbacks are called in the order they were registered.
+            ///
+            /// Keep in mind that clones held by others also keep the reactive alive.
+            /// For example a derived reactive is owned by an observer of its parent,
+            /// so it lives (at least) as long as that observer does.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10);
+            /// r.on_drop(|| println!("closing the connection"));
+            ///
+            /// let r2 = r.clone();
+            /// drop(r); // nothing happens
+            /// drop(r2); // closing the connection
+            /// ```
+            pub fn on_drop(&self, f: impl FnOnce() + $($send)* 'static) {
+                <$backend>::lock(&self.inner.drop_hooks).0.push(Box::new(f));
+            }
+        }
+    };
+}
+
+impl_on_drop!(Local, []);
+#[cfg(feature = "std")]
+impl_on_drop!(ThreadSafe, [Send+]);
@@ -0,0 +1,29 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + Default + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Default + PartialEq + Send + 'static,
+    > Reactive<Option<T>>
+{
+    /// derive a new reactive holding the inner value, or `T::default()` while the value is `None`.
+    ///
+    /// Shorthand for `self.derive(|opt| opt.clone().unwrap_or_default())`
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let name: Reactive<Option<String>> = Reactive::new(None);
+    /// let shown = name.map_default();
+    /// assert_eq!("", shown.value());
+    ///
+    /// name.update(|_| Some(String::from("john")));
+    /// assert_eq!("john", shown.value());
+    ///
+    /// name.update(|_| None);
+    /// assert_eq!("", shown.value());
+    /// ```
+    pub fn map_default(&self) -> Reactive<T> {
+        self.derive(|opt| opt.clone().unwrap_or_default())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + PartialEq + Send + 'static,
+    > Reactive<T>
+{
+    /// Splits the reactive in two based on `pred`.
+    ///
+    /// The first returned reactive holds `Some(val)` while `pred(val)` is `true` (and `None` otherwise),
+    /// the second one holds `Some(val)` while it is `false` (and `None` otherwise).
+    /// `pred` is called once per change of this reactive.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(1);
+    /// let (even, odd) = r.partition(|n| n % 2 == 0);
+    ///
+    /// assert_eq!((None, Some(1)), (even.value(), odd.value()));
+    ///
+    /// r.update(|_| 4);
+    ///
+    /// assert_eq!((Some(4), None), (even.value(), odd.value()));
+    /// ```
+    pub fn partition(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] pred: impl Fn(&T) -> bool + 'static,
+        #[cfg(feature = "threadsafe")] pred: impl Fn(&T) -> bool + Send + 'static,
+    ) -> (Reactive<Option<T>>, Reactive<Option<T>>) {
+        let split = move |val: &T| match pred(val) {
+            true => (Some(val.clone()), None),
+            false => (None, Some(val.clone())),
+        };
+
+        let (matching, rest) = self.observe_with_current(|val, observers| {
+            let (matching, rest) = split(val);
+            let matching = Reactive::new(matching);
+            let rest = Reactive::new(rest);
+
+            observers.add({
+                let matching = matching.clone();
+                let rest = rest.clone();
+                move |val| {
+                    let (m, r) = split(val);
+                    matching.update(|_| m);
+                    rest.update(|_| r);
+                }
+            });
+            (matching, rest)
+        });
+        self.add_child(&matching);
+        self.add_child(&rest);
+
+        (matching, rest)
+    }
+}
@@ -1,31 +1,213 @@
-use std::{
-    collections::hash_map::RandomState,
+#[cfg(feature = "std")]
+use crate::backend::ThreadSafe;
+#[cfg(all(feature = "std", not(loom)))]
+use crate::ReactiveError;
+use crate::{
+    backend::{Backend, IntoObserver, IntoRetaining, Local, SharedIn},
+    graph::Children,
+    hash::DefaultHashBuilder,
+    observers::{ObserverHandle, ObserverInfo, Observers},
+    on_drop::DropHooks,
+};
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+use core::{
+    cell::Cell,
     fmt::Debug,
     hash::{BuildHasher, Hash},
     ops::{Deref, DerefMut},
 };
 
+/// Reactive Data Structure, generic over the way its state is shared (see [`Backend`]).
+///
+/// Used through its aliases: [`crate::LocalReactive`] (`Rc` based), [`crate::SyncReactive`]
+/// (`Arc` based, thread safe) and [`Reactive`] (one of them, depending on the `threadsafe` feature).
+///
+/// Cloning it is cheap and returns another handle to the **same** reactive
+/// (the value and observers are shared). Use [`GenericReactive::deep_copy`] to get an independent one.
+pub struct GenericReactive<T, B: Backend> {
+    /// state shared by all the clones, kept in a single allocation.
+    /// (the handle itself is a single pointer wide)
+    pub(crate) inner: B::Ptr<Inner<T, B>>,
+}
+
 /// Thread Safe Reactive Data Structure
+///
+/// [`crate::LocalReactive`] (`Rc` based), or [`crate::SyncReactive`] (`Arc` based) with the `threadsafe` feature.
+///
+/// Cloning a `Reactive` is cheap and returns another handle to the **same** reactive
+/// (the value and observers are shared). Use [`GenericReactive::deep_copy`] to get an independent one.
+///
 /// # Examples
 /// ```
 /// use reactivate::Reactive;
 ///
 /// let r = Reactive::new("🦀");
 /// ```
-#[derive(Clone, Default)]
-pub struct Reactive<T> {
-    #[cfg(not(feature = "threadsafe"))]
-    value: std::rc::Rc<std::cell::RefCell<T>>,
-    #[cfg(not(feature = "threadsafe"))]
-    observers: std::rc::Rc<std::cell::RefCell<Vec<Box<dyn FnMut(&T)>>>>,
+#[cfg(not(feature = "threadsafe"))]
+pub type Reactive<T> = crate::LocalReactive<T>;
 
-    #[cfg(feature = "threadsafe")]
-    value: std::sync::Arc<std::sync::Mutex<T>>,
-    #[cfg(feature = "threadsafe")]
-    observers: std::sync::Arc<std::sync::Mutex<Vec<Box<dyn FnMut(&T) + Send>>>>,
+/// Thread Safe Reactive Data Structure
+///
+/// [`crate::LocalReactive`] (`Rc` based), or [`crate::SyncReactive`] (`Arc` based) with the `threadsafe` feature.
+///
+/// Cloning a `Reactive` is cheap and returns another handle to the **same** reactive
+/// (the value and observers are shared). Use [`GenericReactive::deep_copy`] to get an independent one.
+///
+/// # Examples
+/// ```
+/// use reactivate::Reactive;
+///
+/// let r = Reactive::new("🦀");
+/// ```
+#[cfg(feature = "threadsafe")]
+pub type Reactive<T> = crate::SyncReactive<T>;
+
+/// Everything the clones of a [`GenericReactive`] share.
+pub(crate) struct Inner<T, B: Backend> {
+    value: B::Lock<T>,
+    pub(crate) observers: B::Lock<Observers<T, B>>,
+
+    /// ids of the observers removed while they were being called (by themselves or a sibling).
+    /// the observers are locked at that point, so they are removed once the notification pass is done.
+    /// only allocated once needed, since the observers that remove themselves keep a clone of it
+    expired: B::Lock<Option<SharedIn<B, Vec<usize>>>>,
+
+    /// reactives derived from this one. (only used for introspection, see `descendant_count`)
+    children: SharedIn<B, Children<B>>,
+
+    /// callbacks registered with `on_drop`
+    pub(crate) drop_hooks: B::Lock<DropHooks<B>>,
+
+    /// notifications of updates made by the observers of this reactive while they were being called,
+    /// waiting for the current notification pass to finish (see `notify_unlocked`)
+    deferred: B::Lock<VecDeque<Deferred<T>>>,
+
+    /// keys used to hash the value for change detection in `update_inplace`.
+    /// created once so that hashing stays consistent (and cheap) across updates
+    hasher: DefaultHashBuilder,
+
+    /// set by `with_invariant`, applied to every value before it is stored
+    invariant: B::Lock<Option<B::Invariant<T>>>,
+
+    /// set by `GenericReactive::constant`, never changes afterwards
+    constant: bool,
+
+    /// tells whether the observers are being called (see `Backend::enter`)
+    notifying: B::Flag,
+}
+
+/// Notification queued by an observer that updated its own reactive (see `notify_unlocked`)
+pub(crate) enum Deferred<T> {
+    /// clone of the value the update produced
+    Value(T),
+    /// the update didn't clone the value (`update_inplace`, `notify_locked`, ...),
+    /// so the observers get whatever the value is once this notification's turn comes
+    Current,
+}
+
+/// Upper bound on the deferred notifications handled by a single notification pass,
+/// to turn observers that keep updating their own reactive into a panic instead of a hang.
+pub(crate) const MAX_DEFERRED_NOTIFICATIONS: usize = 1024;
+
+/// The observers of a reactive, locked
+pub(crate) type ObserversGuard<'a, T, B> = <B as Backend>::Guard<'a, Observers<T, B>>;
+
+#[cfg(all(feature = "std", not(loom)))]
+std::thread_local! {
+    /// reactives (by address of their shared state) whose observers are being called on this thread
+    static NOTIFYING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+// loom runs all of its simulated threads on the same OS thread
+#[cfg(all(feature = "std", loom))]
+loom::thread_local! {
+    static NOTIFYING: RefCell<Vec<usize>> = RefCell::new(Vec::new());
+}
+
+/// Marks a reactive as notifying its observers on the current thread until dropped.
+///
+/// Reactives are identified by the address of their shared state, so every thread safe
+/// reactive type (`SyncReactive`, `AtomicReactive`, ...) can use it.
+#[cfg(feature = "std")]
+pub struct NotifyingOnThread(usize);
+
+#[cfg(feature = "std")]
+impl NotifyingOnThread {
+    pub(crate) fn enter_addr(addr: usize) -> Self {
+        NOTIFYING.with(|notifying| notifying.borrow_mut().push(addr));
+        Self(addr)
+    }
+
+    pub(crate) fn contains_addr(addr: usize) -> bool {
+        NOTIFYING.with(|notifying| notifying.borrow().contains(&addr))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for NotifyingOnThread {
+    fn drop(&mut self) {
+        NOTIFYING.with(|notifying| {
+            let mut notifying = notifying.borrow_mut();
+            if let Some(idx) = notifying.iter().rposition(|&addr| addr == self.0) {
+                notifying.remove(idx);
+            }
+        });
+    }
+}
+
+/// Sets the `notifying` flag of a `LocalReactive` until dropped (even if an observer panics)
+pub struct NotifyingFlag<'a>(&'a Cell<bool>);
+
+impl<'a> NotifyingFlag<'a> {
+    pub(crate) fn enter(flag: &'a Cell<bool>) -> Self {
+        flag.set(true);
+        Self(flag)
+    }
+}
+
+impl Drop for NotifyingFlag<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+impl<T, B: Backend> Inner<T, B> {
+    /// Removes the observer with the given id. (see `ObserverHandle::remove`)
+    pub(crate) fn remove_observer(this: &B::Ptr<Self>, id: usize) -> bool {
+        // called by one of the observers, which are locked by the notification in progress
+        if Self::is_notifying(this) {
+            B::lock(&this.expired()).push(id);
+            return true;
+        }
+
+        let removed = B::lock(&this.observers).remove(id);
+        removed.is_some()
+    }
+
+    /// The list of expired observer ids, allocated on first use
+    fn expired(&self) -> SharedIn<B, Vec<usize>> {
+        B::lock(&self.expired)
+            .get_or_insert_with(|| B::new_ptr(B::new_lock(Vec::new())))
+            .clone()
+    }
+
+    /// `true` while the observers of this reactive are being called (on this thread)
+    fn is_notifying(this: &B::Ptr<Self>) -> bool {
+        B::is_notifying(&this.notifying, B::addr(this))
+    }
+
+    /// Marks this reactive as notifying its observers until the returned guard is dropped
+    fn enter<'a>(this: &'a B::Ptr<Self>) -> B::Notifying<'a>
+    where
+        T: 'a,
+    {
+        B::enter(&this.notifying, B::addr(this))
+    }
 }
 
-impl<T> Reactive<T> {
+impl<T, B: Backend> GenericReactive<T, B> {
     /// Constructs a new `Reactive<T>`
     ///
     /// # Examples
@@ -35,15 +217,64 @@ impl<T> Reactive<T> {
     /// let r = Reactive::new("🦀");
     /// ```
     pub fn new(value: T) -> Self {
+        Self::with_constness(value, false)
+    }
+
+    fn with_constness(value: T, constant: bool) -> Self {
         Self {
-            #[cfg(feature = "threadsafe")]
-            value: std::sync::Arc::new(std::sync::Mutex::new(value)),
+            inner: B::new_ptr(Inner {
+                value: B::new_lock(value),
+                observers: B::new_lock(Observers::default()),
+                expired: B::new_lock(None),
+                children: B::new_ptr(B::new_lock(Children::default())),
+                drop_hooks: B::new_lock(DropHooks::default()),
+                deferred: B::new_lock(VecDeque::new()),
+                hasher: Default::default(),
+                invariant: B::new_lock(None),
+                constant,
+                notifying: Default::default(),
+            }),
+        }
+    }
 
-            #[cfg(not(feature = "threadsafe"))]
-            value: std::rc::Rc::new(std::cell::RefCell::new(value)),
+    /// Constructs a new `Reactive<T>` whose value never changes.
+    ///
+    /// Useful when an API expects a `Reactive<T>` but the value is static.
+    ///
+    /// All the methods that would change the value (`set`, `update`, `update_inplace`, ...)
+    /// are silently ignored and observers are never added (because they would never be called anyway).
+    /// With the `strict` feature enabled, attempting to change the value panics instead.
+    ///
+    /// `with` still gives raw access to the value and is not guarded.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::constant(10);
+    /// let d = r.derive(|val| val + 5);
+    ///
+    /// # #[cfg(not(feature = "strict"))]
+    /// r.update(|_| 20);
+    ///
+    /// assert_eq!(10, r.value());
+    /// assert_eq!(15, d.value());
+    /// ```
+    pub fn constant(value: T) -> Self {
+        Self::with_constness(value, true)
+    }
 
-            observers: Default::default(),
-        }
+    /// Returns `true` if the reactive was constructed using [`Reactive::constant`]
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// assert!(Reactive::constant(10).is_constant());
+    /// assert!(!Reactive::new(10).is_constant());
+    /// ```
+    pub fn is_constant(&self) -> bool {
+        self.inner.constant
     }
 
     /// Returns a clone/copy of the value inside the reactive
@@ -59,7 +290,59 @@ impl<T> Reactive<T> {
     where
         T: Clone,
     {
-        self.acq_val().clone()
+        self.acq_read().clone()
+    }
+
+    /// Returns a brand new reactive holding a clone of the current value, with **no observers**.
+    ///
+    /// Unlike `clone()`, which returns another handle to the same reactive (same value, same observers),
+    /// the copy is completely independent: updating one of them never affects the other.
+    /// (the copy is never constant, even if this reactive is)
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    ///
+    /// let handle = r.clone();
+    /// let copy = r.deep_copy();
+    ///
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(11, handle.value());
+    /// assert_eq!(10, copy.value());
+    /// ```
+    pub fn deep_copy(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(self.value())
+    }
+
+    /// Returns a read-only guard to the value inside the reactive, avoiding the clone done by `value()`.
+    ///
+    /// **The reactive stays locked for as long as the guard is alive.**
+    /// Any other access to the reactive (including updates from observers or other threads)
+    /// will block/panic until the guard is dropped, so keep it short-lived.
+    ///
+    /// The guard is not `Send` and cannot be moved to another thread.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(vec![1, 2, 3]);
+    ///
+    /// {
+    ///     let nums = r.borrowed();
+    ///     assert_eq!(3, nums.len());
+    /// } // lock is released here
+    ///
+    /// r.update_inplace(|nums| nums.push(4));
+    /// ```
+    pub fn borrowed(&self) -> impl Deref<Target = T> + '_ {
+        self.acq_read()
     }
 
     /// Perform some action with the reference to the inner value.
@@ -72,7 +355,7 @@ impl<T> Reactive<T> {
     /// r.with_value(|s| println!("{}", s));
     /// ```
     pub fn with_value(&self, f: impl FnOnce(&T)) {
-        f(self.acq_val().deref());
+        f(self.acq_read().deref());
     }
 
     /// All the Reactive methods acquire and release locks for each method call.
@@ -81,6 +364,11 @@ impl<T> Reactive<T> {
     /// to do as you please with them.
     ///
     /// Generally not recommended unless you know what you are doing.
+    /// Use [`Reactive::with_mut`] for mutable access to just the value.
+    ///
+    /// When called by one of the observers of this reactive, the observers are busy,
+    /// so `f` gets none of them and they are notified of the resulting value
+    /// once the current notification is done instead.
     ///
     /// # Examples
     ///
@@ -98,66 +386,243 @@ impl<T> Reactive<T> {
     /// assert_eq!(21, r.value());
     ///
     /// ```
-    pub fn with(
-        &self,
-        #[cfg(not(feature = "threadsafe"))] f: impl FnOnce(&mut T, &mut [Box<dyn FnMut(&T)>]),
-        #[cfg(feature = "threadsafe")] f: impl FnOnce(&mut T, &mut [Box<dyn FnMut(&T) + Send>]),
-    ) {
-        let mut val_guard = self.acq_val();
-        let mut obs_guard = self.acq_obs();
-        f(val_guard.deref_mut(), obs_guard.deref_mut());
+    pub fn with(&self, f: impl FnOnce(&mut T, &mut [B::Observer<T>])) {
+        let (observers, mut guard) = self.acq_update();
+        match observers {
+            Some(mut observers) => f(guard.deref_mut(), observers.as_mut_slice()),
+            None => {
+                f(guard.deref_mut(), &mut []);
+                drop(guard);
+                B::lock(&self.inner.deferred).push_back(Deferred::Current);
+            }
+        }
     }
 
-    /// derive a new child reactive that changes whenever the parent reactive changes.
-    /// (achieved by adding an observer function to the parent reactive behind the scenes)
+    /// Gives mutable access to the value and then notifies all the observers,
+    /// whether the value changed or not. Returns whatever `f` returns.
+    ///
+    /// This is the recommended way to mutate the value in place.
+    /// Compared to `with`, the observers are notified automatically.
+    /// Compared to `update_inplace_unchecked`, `f` can return a result and the observers
+    /// are called after the value lock is released (with a clone of the new value),
+    /// so they can read the reactive. Everything else happens under a single lock.
     ///
     /// # Examples
     /// ```
     /// use reactivate::Reactive;
     ///
-    /// let r = Reactive::new(10);
-    /// let d = r.derive(|val| val + 5);
+    /// let r = Reactive::new(vec![1, 2, 3]);
+    /// let sum = r.derive(|nums| nums.iter().sum::<i32>());
     ///
-    /// assert_eq!(15, d.value());
+    /// let popped = r.with_mut(|nums| {
+    ///     nums.push(4);
+    ///     nums.remove(0)
+    /// });
+    ///
+    /// assert_eq!(1, popped);
+    /// assert_eq!(9, sum.value());
     /// ```
-    pub fn derive<
-        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
-        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
-    >(
-        &self,
-        #[cfg(not(feature = "threadsafe"))] f: impl Fn(&T) -> U + 'static,
-        #[cfg(feature = "threadsafe")] f: impl Fn(&T) -> U + Send + 'static,
-    ) -> Reactive<U>
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R
     where
         T: Clone,
     {
-        let derived_val = f(self.acq_val().deref());
-        let derived: Reactive<U> = Reactive::new(derived_val);
+        if self.rejects_change() {
+            // `f` still gets to run (and return its result) but on a throwaway copy
+            return f(&mut self.value());
+        }
 
-        self.add_observer({
-            let derived = derived.clone();
-            move |value| derived.update(|_| f(value))
-        });
+        let (observers, mut guard) = self.acq_update();
+        let result = f(&mut guard);
+        self.enforce_invariant(&mut guard);
+        self.notify_unlocked(observers, guard);
+
+        result
+    }
 
-        derived
+    /// Same as `add_observer` but returns a handle that can later be used to remove the observer
+    pub(crate) fn add_removable_observer(
+        &self,
+        f: impl IntoObserver<T, B>,
+    ) -> ObserverHandle<T, B> {
+        self.push_observer(&mut self.acq_obs(), f)
+    }
+
+    /// Same as `add_removable_observer` but for when the observers are already locked
+    fn push_observer(
+        &self,
+        observers: &mut Observers<T, B>,
+        f: impl IntoObserver<T, B>,
+    ) -> ObserverHandle<T, B> {
+        if self.inner.constant {
+            return ObserverHandle::dangling();
+        }
+        let id = observers.push(f);
+        self.handle(id)
+    }
+
+    /// Same as `add_removable_observer` but the observer removes itself
+    /// the first time `f` returns `false`.
+    #[cfg(feature = "std")]
+    pub(crate) fn add_retaining_observer(
+        &self,
+        f: impl IntoRetaining<T, B>,
+    ) -> ObserverHandle<T, B> {
+        self.push_retaining(&mut self.acq_obs(), f)
     }
 
-    /// Adds a new observer to the reactive.
-    /// the observer functions are called whenever the value inside the Reactive is updated
+    /// Same as `add_retaining_observer` but for when the observers are already locked
+    fn push_retaining<F: IntoRetaining<T, B>>(
+        &self,
+        observers: &mut Observers<T, B>,
+        f: F,
+    ) -> ObserverHandle<T, B> {
+        if self.inner.constant {
+            return ObserverHandle::dangling();
+        }
+        let f = f.into_retaining(self.inner.expired(), observers.next_id());
+        let id = observers.push_boxed(f, core::any::type_name::<F>());
+        self.handle(id)
+    }
+
+    /// Calls `f` with the current value and a [`Registrar`] to add observers with,
+    /// while both the observers and the value are locked (in that order, see `acq_both`).
+    ///
+    /// Every combinator that starts from the current value subscribes through this.
+    /// Updates lock the observers before changing the value, so no change can slip in
+    /// between reading the current value and registering the observer,
+    /// and the observer never sees the value it was initialized from a second time.
+    ///
+    /// `f` runs with the value locked, so anything that may access this reactive
+    /// (like a user callback) has to happen after this returns.
+    pub(crate) fn observe_with_current<R>(
+        &self,
+        f: impl FnOnce(&T, &mut Registrar<'_, T, B>) -> R,
+    ) -> R {
+        let (mut observers, guard) = self.acq_both();
+        f(
+            &guard,
+            &mut Registrar {
+                reactive: self,
+                observers: &mut observers,
+            },
+        )
+    }
+
+    /// Applies the invariant set by `with_invariant` (if any) to a value about to be stored
+    #[inline]
+    pub(crate) fn enforce_invariant(&self, val: &mut T) {
+        if let Some(invariant) = B::lock(&self.inner.invariant).as_ref() {
+            invariant(val);
+        }
+    }
+
+    /// Replaces every existing observer with `wrapper(observer)`.
+    ///
+    /// The wrapper takes ownership of the original observer and is expected to call it.
+    /// Useful for adding cross-cutting concerns (logging, metrics, ...) to an
+    /// already constructed reactive graph. Observers added afterwards are not affected.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Observer, Reactive};
+    ///
+    /// let r = Reactive::new(10);
+    /// let d = r.derive(|val| val + 1);
+    ///
+    /// r.map_observers(|mut obs| -> Observer<i32> {
+    ///     Box::new(move |val| {
+    ///         println!("notifying {}", val);
+    ///         obs(val);
+    ///     })
+    /// });
+    ///
+    /// r.update(|n| n * 2);
+    ///
+    /// assert_eq!(21, d.value());
+    /// ```
+    pub fn map_observers(&self, wrapper: impl Fn(B::Observer<T>) -> B::Observer<T>) {
+        self.acq_obs().map(wrapper);
+    }
+
+    /// Gives read-only access to the observers currently registered on the reactive
+    /// (in the order they are called), for debugging and introspection.
+    ///
+    /// The observers are type erased, so there is no way to downcast them back to the
+    /// original closures. `std::any::type_name_of_val` only reports the boxed type,
+    /// use [`Reactive::has_observer_matching`] to look at their ids and type names instead.
+    ///
+    /// The observers are locked while `f` runs, so `f` must not touch the observers of
+    /// this reactive itself (updating it, adding observers, ...).
     ///
     /// # Examples
     /// ```
     /// use reactivate::Reactive;
     ///
-    /// let r = Reactive::new(String::from("🦀"));
+    /// let r = Reactive::new(10);
+    /// let _d = r.derive(|val| val + 1);
     /// r.add_observer(|val| println!("{}", val));
+    ///
+    /// assert_eq!(2, r.inspect_observers(|observers| observers.len()));
     /// ```
-    pub fn add_observer(
-        &self,
-        #[cfg(not(feature = "threadsafe"))] f: impl FnMut(&T) + 'static,
-        #[cfg(feature = "threadsafe")] f: impl FnMut(&T) + Send + 'static,
-    ) {
-        self.acq_obs().push(Box::new(f));
+    pub fn inspect_observers<R>(&self, f: impl FnOnce(&[B::Observer<T>]) -> R) -> R {
+        f(self.acq_obs().as_slice())
+    }
+
+    /// Returns `true` if `pred` returns `true` for at least one of the observers
+    /// currently registered on the reactive, for debugging and testing.
+    ///
+    /// The observers are type erased, so `pred` gets what is known about each of them instead:
+    /// its id (matching the one of the [`ObserverHandle`] returned when it was added)
+    /// and the type name of the function that was added.
+    ///
+    /// The observers are locked while `pred` runs, so it must not touch the observers of
+    /// this reactive itself.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// fn log(_: (), val: &i32) {
+    ///     println!("{}", val);
+    /// }
+    ///
+    /// let r = Reactive::new(10);
+    /// let handle = r.add_observer_with_context((), log);
+    /// let _d = r.derive(|val| val + 1);
+    ///
+    /// assert!(r.has_observer_matching(|info| info.id() == handle.id()));
+    /// assert!(r.has_observer_matching(|info| info.type_name().contains("derive")));
+    ///
+    /// handle.remove();
+    /// assert!(!r.has_observer_matching(|info| info.id() == handle.id()));
+    /// ```
+    pub fn has_observer_matching(&self, pred: impl Fn(ObserverInfo) -> bool) -> bool {
+        self.acq_obs().infos().iter().any(|&info| pred(info))
+    }
+
+    /// Returns `true` if at least one observer is registered on the reactive.
+    /// (reactives derived from this one count too, since they are fed by observers)
+    ///
+    /// Useful for skipping expensive work when nobody would see the result anyway.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let frames = Reactive::new(Vec::<u8>::new());
+    ///
+    /// let render = || vec![0u8; 1024]; // expensive
+    ///
+    /// if frames.has_observers() {
+    ///     frames.set(render());
+    /// }
+    /// assert!(frames.value().is_empty()); // nobody was listening
+    ///
+    /// let _len = frames.derive(|frame| frame.len());
+    /// assert!(frames.has_observers());
+    /// ```
+    pub fn has_observers(&self) -> bool {
+        !self.acq_obs().is_empty()
     }
 
     /// Clears all observers from the reactive.
@@ -195,14 +660,19 @@ impl<T> Reactive<T> {
     ///
     /// assert_eq!(25, d.value());
     /// ```
-    pub fn set(&self, val: T) {
-        let mut guard = self.acq_val();
-        let curr_val = guard.deref_mut();
-        *curr_val = val;
-
-        for obs in self.acq_obs().deref_mut() {
-            obs(curr_val);
+    pub fn set(&self, val: T)
+    where
+        T: Clone,
+    {
+        if self.rejects_change() {
+            return;
         }
+
+        let (observers, mut guard) = self.acq_update();
+        *guard = val;
+        self.enforce_invariant(&mut guard);
+
+        self.notify_unlocked(observers, guard);
     }
 
     /// Update the value inside the reactive and notify all the observers
@@ -222,26 +692,153 @@ impl<T> Reactive<T> {
     /// ```
     pub fn update(&self, f: impl FnOnce(&T) -> T)
     where
-        T: PartialEq,
+        T: Clone + PartialEq,
     {
-        let mut guard = self.acq_val();
-        let val = guard.deref_mut();
-        let new_val = f(val);
-        if &new_val != val {
-            *val = new_val;
+        if self.rejects_change() {
+            return;
+        }
 
-            for obs in self.acq_obs().deref_mut() {
-                obs(val);
-            }
+        let (observers, mut guard) = self.acq_update();
+        let mut new_val = f(&guard);
+        self.enforce_invariant(&mut new_val);
+        if new_val != *guard {
+            *guard = new_val;
+
+            self.notify_unlocked(observers, guard);
         }
     }
 
+    /// Same as `update` but `f` is only applied if `guard` returns `true` for the current value.
+    /// The check and the update happen under a single lock, so no other update
+    /// can sneak in between them.
+    ///
+    /// Returns whether `f` was applied.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    ///
+    /// assert!(r.update_if(|n| *n < 15, |n| n + 10));
+    /// assert!(!r.update_if(|n| *n < 15, |n| n + 10));
+    ///
+    /// assert_eq!(20, r.value());
+    /// ```
+    pub fn update_if(&self, guard: impl FnOnce(&T) -> bool, f: impl FnOnce(&T) -> T) -> bool
+    where
+        T: Clone + PartialEq,
+    {
+        if self.rejects_change() {
+            return false;
+        }
+
+        let (observers, mut val_guard) = self.acq_update();
+        if !guard(&val_guard) {
+            return false;
+        }
+
+        let mut new_val = f(&val_guard);
+        self.enforce_invariant(&mut new_val);
+        if new_val != *val_guard {
+            *val_guard = new_val;
+            self.notify_unlocked(observers, val_guard);
+        }
+
+        true
+    }
+
+    /// Same as `update` but also returns a clone of the resulting value.
+    ///
+    /// The clone is taken while the value is still locked by the update, right after `f` is applied.
+    /// The observers are only called afterwards, once the value lock is released
+    /// (with the observers still locked, like for `update`). So unlike calling `value()` afterwards,
+    /// the returned value never includes changes made in between by other threads
+    /// or by the observers themselves.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    ///
+    /// assert_eq!(20, r.update_and_get(|n| n * 2));
+    /// ```
+    pub fn update_and_get(&self, f: impl FnOnce(&T) -> T) -> T
+    where
+        T: Clone + PartialEq,
+    {
+        if self.rejects_change() {
+            return self.value();
+        }
+
+        let (observers, mut guard) = self.acq_update();
+        let mut new_val = f(&guard);
+        self.enforce_invariant(&mut new_val);
+        if new_val == *guard {
+            return guard.clone();
+        }
+
+        *guard = new_val;
+        let val = guard.clone();
+        self.notify_unlocked(observers, guard);
+
+        val
+    }
+
+    /// Sets the value to `val` and notifies the observers **ONLY** if the current value
+    /// is still `T::default()`. Useful for one-time-populated slots like config.
+    ///
+    /// The check and the set happen under a single lock, so when multiple threads race
+    /// to initialize the reactive, exactly one of them wins.
+    ///
+    /// Returns whether the value was initialized.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let config: Reactive<Option<String>> = Reactive::default();
+    ///
+    /// assert!(config.init_once(Some(String::from("prod"))));
+    /// assert!(!config.init_once(Some(String::from("dev"))));
+    ///
+    /// assert_eq!(Some(String::from("prod")), config.value());
+    /// ```
+    pub fn init_once(&self, val: T) -> bool
+    where
+        T: Clone + Default + PartialEq,
+    {
+        if self.rejects_change() {
+            return false;
+        }
+
+        let (observers, mut guard) = self.acq_update();
+        if *guard != T::default() {
+            return false;
+        }
+
+        *guard = val;
+        self.enforce_invariant(&mut guard);
+        self.notify_unlocked(observers, guard);
+
+        true
+    }
+
     /// Updates the value inside inplace without creating a new clone/copy and notify
     /// all the observers by calling the added observer functions in the sequence they were added
     /// **ONLY** if the value changes after applying the provided function.
     ///
     /// Prefer this when the datatype inside is expensive to clone, like a vector.
     ///
+    /// Changes are detected by comparing the hashes of the old and new values.
+    /// Enable the `fast-hash` feature to use the much faster (but not DoS resistant)
+    /// `FxHasher` instead of the default SipHash (FNV without `std`).
+    /// Use [`Reactive::update_inplace_with_hasher`] to pick the hasher per call.
+    ///
+    /// To avoid cloning, the observers are called while the value is still locked,
+    /// so they must not access the value of this reactive. (see [`Reactive::notify_locked`])
+    ///
     /// # Examples
     /// ```
     /// use reactivate::Reactive;
@@ -261,19 +858,83 @@ impl<T> Reactive<T> {
     where
         T: Hash,
     {
-        let random_state = RandomState::new();
+        self.update_inplace_with_hasher(&self.inner.hasher, f);
+    }
 
-        let mut guard = self.acq_val();
+    /// Same as `update_inplace` but the hashes used to detect the change
+    /// are computed with `hash_builder`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{FnvBuildHasher, Reactive};
+    ///
+    /// let r = Reactive::new(vec![1, 2, 3]);
+    /// let d = r.derive(|nums| nums.len());
+    ///
+    /// r.update_inplace_with_hasher(&FnvBuildHasher, |nums| nums.push(4));
+    ///
+    /// assert_eq!(4, d.value());
+    /// ```
+    pub fn update_inplace_with_hasher(
+        &self,
+        hash_builder: &impl BuildHasher,
+        f: impl FnOnce(&mut T),
+    ) where
+        T: Hash,
+    {
+        if self.rejects_change() {
+            return;
+        }
+
+        let (observers, mut guard) = self.acq_update();
         let val = guard.deref_mut();
 
-        let old_hash = random_state.hash_one(&val);
+        let old_hash = hash_builder.hash_one(&val);
         f(val);
-        let new_hash = random_state.hash_one(&val);
+        self.enforce_invariant(val);
+        let new_hash = hash_builder.hash_one(&val);
 
         if old_hash != new_hash {
-            for obs in self.acq_obs().deref_mut() {
-                obs(val);
-            }
+            self.notify_locked_with(observers, guard);
+        }
+    }
+
+    /// Same as `update_inplace` but the change is detected by cloning the old value
+    /// and comparing it with the new one using `==` instead of comparing hashes.
+    ///
+    /// `update_inplace` compares 64-bit hashes, so in the (very rare) case of a hash collision
+    /// between the old and the new value, the change goes unnoticed and observers are not notified.
+    /// This method never misses a change, at the cost of cloning the value on every call.
+    /// Prefer it when correctness matters more than the cost of the clone.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(vec![1, 2, 3]);
+    /// let d = r.derive(|nums| nums.iter().sum::<i32>());
+    ///
+    /// r.update_inplace_eq(|nums| nums.push(4));
+    ///
+    /// assert_eq!(10, d.value());
+    /// ```
+    pub fn update_inplace_eq(&self, f: impl FnOnce(&mut T))
+    where
+        T: Clone + PartialEq,
+    {
+        if self.rejects_change() {
+            return;
+        }
+
+        let (observers, mut guard) = self.acq_update();
+        let val = guard.deref_mut();
+
+        let old_val = val.clone();
+        f(val);
+        self.enforce_invariant(val);
+
+        if &old_val != val {
+            self.notify_unlocked(observers, guard);
         }
     }
 
@@ -304,14 +965,19 @@ impl<T> Reactive<T> {
     /// because the old value and the new value (after applying `f`) aren't compared.
     ///
     /// It is also faster than `update` for that reason
-    pub fn update_unchecked(&self, f: impl FnOnce(&T) -> T) {
-        let mut guard = self.acq_val();
-        let val = guard.deref_mut();
-        *val = f(val);
-
-        for obs in self.acq_obs().deref_mut() {
-            obs(val);
+    pub fn update_unchecked(&self, f: impl FnOnce(&T) -> T)
+    where
+        T: Clone,
+    {
+        if self.rejects_change() {
+            return;
         }
+
+        let (observers, mut guard) = self.acq_update();
+        *guard = f(&guard);
+        self.enforce_invariant(&mut guard);
+
+        self.notify_unlocked(observers, guard);
     }
 
     /// Updates the value inside inplace without creating a new clone/copy and notify
@@ -351,14 +1017,18 @@ impl<T> Reactive<T> {
     /// aren't calculated and compared.
     ///
     /// It is also faster than `update_inplace` for that reason
+    ///
+    /// Just like `update_inplace`, the observers are called while the value is still locked.
     pub fn update_inplace_unchecked(&self, f: impl FnOnce(&mut T)) {
-        let mut guard = self.acq_val();
-        let val = guard.deref_mut();
-        f(val);
-
-        for obs in self.acq_obs().deref_mut() {
-            obs(val);
+        if self.rejects_change() {
+            return;
         }
+
+        let (observers, mut guard) = self.acq_update();
+        f(guard.deref_mut());
+        self.enforce_invariant(&mut guard);
+
+        self.notify_locked_with(observers, guard);
     }
 
     /// Notify all the observers of the current value by calling the
@@ -373,45 +1043,906 @@ impl<T> Reactive<T> {
     /// r.add_observer(|val| println!("{}", val));
     /// r.notify();
     /// ```
-    pub fn notify(&self) {
-        let guard = self.acq_val();
-        let val = guard.deref();
-        for obs in self.acq_obs().deref_mut() {
-            obs(val);
+    pub fn notify(&self)
+    where
+        T: Clone,
+    {
+        let (observers, guard) = self.acq_update();
+        self.notify_unlocked(observers, guard);
+    }
+
+    /// Same as [`Reactive::notify`] but the observers are called with a reference to the value
+    /// while it is still locked, so `T` doesn't need to be `Clone`.
+    ///
+    /// Observers must not access the value of this reactive (`value`, `with_value`, ...)
+    /// because it is locked while they run. (this deadlocks a `SyncReactive`; a `LocalReactive`
+    /// only lends the value for reading to its observers, so they can read it but updating it panics)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// struct Connection(u32);
+    ///
+    /// let r = Reactive::new(Connection(1));
+    /// r.add_observer(|conn| println!("connection {}", conn.0));
+    ///
+    /// r.update_inplace_unchecked(|conn| conn.0 += 1);
+    /// r.notify_locked();
+    /// ```
+    pub fn notify_locked(&self) {
+        // called by one of the observers, the value may well be locked by the notification in progress
+        if Inner::<T, B>::is_notifying(&self.inner) {
+            B::lock(&self.inner.deferred).push_back(Deferred::Current);
+            return;
         }
+
+        let (observers, guard) = self.acq_both();
+        self.notify_locked_with(Some(observers), guard);
+    }
+
+    /// Calls the observers with a clone of the value held by `guard` **after** releasing it,
+    /// so that observers can read the reactive they are observing.
+    ///
+    /// `observers` (locked before the value, see `acq_update`) stays locked until every observer
+    /// was called, so notifications still arrive in the same order as the updates.
+    ///
+    /// Updates made by the observers to this same reactive (on this thread) can't notify right away
+    /// because the observers are locked, so they pass `None` as `observers`. Their notifications
+    /// are queued instead and each one gets its own full pass (in order) once the current pass is done.
+    ///
+    /// # Panics
+    /// if more than `MAX_DEFERRED_NOTIFICATIONS` notifications get deferred during a single pass,
+    /// which means the observers keep updating the reactive forever.
+    pub(crate) fn notify_unlocked(
+        &self,
+        observers: Option<ObserversGuard<'_, T, B>>,
+        guard: B::Guard<'_, T>,
+    ) where
+        T: Clone,
+    {
+        let Some(mut observers) = observers else {
+            let val = guard.clone();
+            drop(guard);
+            B::lock(&self.inner.deferred).push_back(Deferred::Value(val));
+            return;
+        };
+
+        if observers.is_empty() {
+            return;
+        }
+
+        let val = guard.clone();
+        drop(guard);
+
+        let _notifying = Inner::<T, B>::enter(&self.inner);
+        self.call_observers(&mut observers, &val);
+        self.notify_deferred(&mut observers, |observers| {
+            let val = self.acq_val().clone();
+            self.call_observers(observers, &val);
+        });
+    }
+
+    /// Same as `notify_unlocked` but the observers are called with the value **while it is still locked**,
+    /// so that `T` doesn't need to be `Clone`. (used by the in-place updates and `notify_locked`)
+    ///
+    /// Called by an observer (`observers` is `None`), nothing gets cloned either:
+    /// `Deferred::Current` is queued and the pass in progress notifies whatever the value is by then.
+    /// The observers of such a locked pass can't update the value, but can still `notify_locked`.
+    /// (and read it with the `Local` backend, see `Backend::read`)
+    ///
+    /// # Panics
+    /// same as `notify_unlocked`
+    fn notify_locked_with<'a>(
+        &'a self,
+        observers: Option<ObserversGuard<'a, T, B>>,
+        guard: B::Guard<'a, T>,
+    ) {
+        let Some(mut observers) = observers else {
+            drop(guard);
+            B::lock(&self.inner.deferred).push_back(Deferred::Current);
+            return;
+        };
+
+        if observers.is_empty() {
+            return;
+        }
+
+        let guard = B::read(&self.inner.value, guard);
+        let _notifying = Inner::<T, B>::enter(&self.inner);
+        self.call_observers(&mut observers, &guard);
+        self.notify_deferred(&mut observers, |observers| {
+            self.call_observers(observers, &guard)
+        });
+    }
+
+    /// Calls every observer with `val` and then removes the ones removed meanwhile (see `Inner::expired`)
+    fn call_observers(&self, observers: &mut Observers<T, B>, val: &T) {
+        observers.call(val);
+
+        let expired = match &*B::lock(&self.inner.expired) {
+            Some(expired) => core::mem::take(&mut *B::lock(expired)),
+            None => return,
+        };
+        for id in expired {
+            observers.remove(id);
+        }
+    }
+
+    /// Gives every notification deferred during the current pass its own full pass, in order.
+    /// `current` notifies the observers of the current value for `Deferred::Current`.
+    fn notify_deferred(
+        &self,
+        observers: &mut Observers<T, B>,
+        mut current: impl FnMut(&mut Observers<T, B>),
+    ) {
+        for _ in 0..MAX_DEFERRED_NOTIFICATIONS {
+            let deferred = B::lock(&self.inner.deferred).pop_front();
+            match deferred {
+                None => return,
+                Some(Deferred::Value(val)) => self.call_observers(observers, &val),
+                Some(Deferred::Current) => current(observers),
+            }
+        }
+
+        B::lock(&self.inner.deferred).clear();
+        panic!(
+            "observers updated their own reactive more than {} times in a row",
+            MAX_DEFERRED_NOTIFICATIONS
+        );
+    }
+
+    /// Returns the number of reactives that (transitively) depend on this one
+    /// through any of the derive-family combinators: `derive` (and its fused versions
+    /// `derive2`, `derive3`), `derive_with_initial`, `derive_with_context`, `multi_derive`,
+    /// `derive_shared`, `partition` and `relay`.
+    ///
+    /// Reactives that were dropped along with the observers feeding them are not counted.
+    /// Dependencies created in other ways (merges, custom observers, ...) are not tracked.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let a = r.derive(|val| val + 1);
+    /// let _b = a.derive(|val| val * 2);
+    /// let _c = r.derive(|val| val.to_string());
+    ///
+    /// assert_eq!(3, r.descendant_count());
+    /// assert_eq!(1, a.descendant_count());
+    /// ```
+    pub fn descendant_count(&self) -> usize {
+        B::lock(&self.inner.children).count()
+    }
+
+    /// Records `child` as derived from this reactive
+    pub(crate) fn add_child<U>(&self, child: &GenericReactive<U, B>) {
+        if self.inner.constant {
+            return;
+        }
+
+        B::lock(&self.inner.children).push::<U>(&child.inner.children);
     }
 
     #[inline]
-    #[cfg(not(feature = "threadsafe"))]
-    fn acq_val(&self) -> std::cell::RefMut<'_, T> {
-        self.value.borrow_mut()
+    #[cfg(feature = "graph-introspection")]
+    pub(crate) fn children(&self) -> &SharedIn<B, Children<B>> {
+        &self.inner.children
     }
 
+    /// `true` if the value must not be changed because the reactive is a constant.
+    /// panics instead when the `strict` feature is enabled.
     #[inline]
-    #[cfg(not(feature = "threadsafe"))]
-    fn acq_obs(&self) -> std::cell::RefMut<'_, Vec<Box<dyn FnMut(&T)>>> {
-        self.observers.borrow_mut()
+    pub(crate) fn rejects_change(&self) -> bool {
+        #[cfg(feature = "strict")]
+        if self.inner.constant {
+            panic!("attempted to change the value of a constant reactive");
+        }
+        self.inner.constant
     }
 
     #[inline]
-    #[cfg(feature = "threadsafe")]
-    fn acq_val(&self) -> std::sync::MutexGuard<'_, T> {
-        self.value.lock().expect("unable to acquire lock on value")
+    pub(crate) fn acq_val(&self) -> B::Guard<'_, T> {
+        B::lock(&self.inner.value)
     }
 
+    /// Same as `acq_val` but read-only, so that (with the `Local` backend) observers called
+    /// during an in-place update can still read the value
     #[inline]
-    #[cfg(feature = "threadsafe")]
-    fn acq_obs(&self) -> std::sync::MutexGuard<'_, Vec<Box<dyn FnMut(&T) + Send>>> {
-        self.observers
-            .lock()
-            .expect("unable to acquire lock on observers")
+    pub(crate) fn acq_read(&self) -> B::ReadGuard<'_, T> {
+        B::lock_read(&self.inner.value)
+    }
+
+    #[inline]
+    pub(crate) fn acq_obs(&self) -> ObserversGuard<'_, T, B> {
+        B::lock(&self.inner.observers)
+    }
+
+    // poisoning is ignored here (see `is_poisoned`)
+
+    /// Locks the observers and then the value.
+    ///
+    /// Whenever both are needed they are taken in this order, so that updaters and
+    /// registrations racing each other (or observers reading the value) can't deadlock.
+    /// The value lock is never held while waiting for another lock.
+    #[inline]
+    pub(crate) fn acq_both(&self) -> (ObserversGuard<'_, T, B>, B::Guard<'_, T>) {
+        let observers = self.acq_obs();
+        (observers, self.acq_val())
+    }
+
+    /// Same as `acq_both` but the observers are left alone (`None`) when called
+    /// by one of them, since this thread is already holding them. (see `notify_unlocked`)
+    #[inline]
+    pub(crate) fn acq_update(&self) -> (Option<ObserversGuard<'_, T, B>>, B::Guard<'_, T>) {
+        let observers = (!Inner::<T, B>::is_notifying(&self.inner)).then(|| self.acq_obs());
+        (observers, self.acq_val())
+    }
+
+    /// Handle for the observer with the given id
+    #[inline]
+    pub(crate) fn handle(&self, id: usize) -> ObserverHandle<T, B> {
+        ObserverHandle::new(B::downgrade(&self.inner), id)
+    }
+}
+
+/// Adds observers to a reactive while its observers are locked. (see [`GenericReactive::observe_with_current`])
+pub(crate) struct Registrar<'a, T, B: Backend> {
+    reactive: &'a GenericReactive<T, B>,
+    observers: &'a mut Observers<T, B>,
+}
+
+impl<T, B: Backend> Registrar<'_, T, B> {
+    /// Same as `GenericReactive::add_removable_observer`
+    pub(crate) fn add(&mut self, f: impl IntoObserver<T, B>) -> ObserverHandle<T, B> {
+        self.reactive.push_observer(self.observers, f)
+    }
+
+    /// Same as `GenericReactive::add_retaining_observer`
+    pub(crate) fn add_retaining(&mut self, f: impl IntoRetaining<T, B>) -> ObserverHandle<T, B> {
+        self.reactive.push_retaining(self.observers, f)
+    }
+}
+
+impl<T, B: Backend> Clone for GenericReactive<T, B> {
+    /// Returns a new handle to the same reactive. (the value and observers are shared, not copied)
+    ///
+    /// Use [`GenericReactive::deep_copy`] for an independent reactive.
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
     }
 }
 
-impl<T: Debug> Debug for Reactive<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: Default, B: Backend> Default for GenericReactive<T, B> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug, B: Backend> Debug for GenericReactive<T, B> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Reactive")
             .field(self.acq_val().deref())
             .finish()
     }
 }
+
+#[cfg(all(feature = "std", not(loom)))]
+impl<T> GenericReactive<T, ThreadSafe> {
+    /// Returns `true` if something (usually an observer) panicked while holding a lock on the reactive.
+    ///
+    /// The reactive keeps working after such a panic (the locks are taken regardless),
+    /// but the value may have been left half updated and the observers after the panicking one
+    /// were not called. Use the `try_*` methods to refuse working with such a value,
+    /// and [`GenericReactive::recover`] once it is dealt with.
+    ///
+    /// Only available for the thread safe backend (and not under `loom`), like the `try_*` methods
+    /// and `recover`, since a `RefCell` has no notion of poisoning.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::SyncReactive;
+    ///
+    /// let r = SyncReactive::new(0);
+    /// r.add_observer(|n| assert!(*n < 10));
+    ///
+    /// std::thread::spawn({
+    ///     let r = r.clone();
+    ///     move || r.update(|_| 10)
+    /// })
+    /// .join()
+    /// .unwrap_err();
+    ///
+    /// assert!(r.is_poisoned());
+    /// assert_eq!(10, r.value()); // still usable
+    ///
+    /// r.recover();
+    /// assert!(!r.is_poisoned());
+    /// ```
+    pub fn is_poisoned(&self) -> bool {
+        self.inner.value.is_poisoned() || self.inner.observers.is_poisoned()
+    }
+
+    /// Clears the poisoned state of the reactive. (see [`GenericReactive::is_poisoned`])
+    pub fn recover(&self) {
+        self.inner.value.clear_poison();
+        self.inner.observers.clear_poison();
+    }
+
+    /// Same as `value` but returns `Err(ReactiveError::Poisoned)` if the reactive is poisoned.
+    /// (see [`GenericReactive::is_poisoned`])
+    pub fn try_value(&self) -> Result<T, ReactiveError>
+    where
+        T: Clone,
+    {
+        self.ensure_not_poisoned()?;
+        Ok(self.value())
+    }
+
+    /// Same as `update` but returns `Err(ReactiveError::Poisoned)` without applying `f`
+    /// if the reactive is poisoned. (see [`GenericReactive::is_poisoned`])
+    pub fn try_update(&self, f: impl FnOnce(&T) -> T) -> Result<(), ReactiveError>
+    where
+        T: Clone + PartialEq,
+    {
+        self.ensure_not_poisoned()?;
+        self.update(f);
+        Ok(())
+    }
+
+    fn ensure_not_poisoned(&self) -> Result<(), ReactiveError> {
+        match self.is_poisoned() {
+            true => Err(ReactiveError::Poisoned),
+            false => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "threadsafe")]
+    pub(crate) fn acq_val_until(
+        &self,
+        deadline: std::time::Instant,
+    ) -> Option<std::sync::MutexGuard<'_, T>> {
+        crate::sync::lock_until(&self.inner.value, deadline)
+    }
+
+    #[cfg(feature = "threadsafe")]
+    pub(crate) fn acq_obs_until(
+        &self,
+        deadline: std::time::Instant,
+    ) -> Option<std::sync::MutexGuard<'_, Observers<T, ThreadSafe>>> {
+        crate::sync::lock_until(&self.inner.observers, deadline)
+    }
+}
+
+/// The methods storing closures in the reactive, whose bounds depend on the backend:
+/// `$($send)*` is `Send +` for the thread safe one and nothing for the local one.
+macro_rules! impl_storing_closures {
+    ($backend:ty, [$($send:tt)*]) => {
+        impl<T> GenericReactive<T, $backend> {
+            /// derive a new child reactive that changes whenever the parent reactive changes.
+            /// (achieved by adding an observer function to the parent reactive behind the scenes)
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10);
+            /// let d = r.derive(|val| val + 5);
+            ///
+            /// assert_eq!(15, d.value());
+            /// ```
+            pub fn derive<
+                U: Clone + PartialEq + $($send)* 'static,
+            >(
+                &self,
+                f: impl Fn(&T) -> U + $($send)* 'static,
+            ) -> GenericReactive<U, $backend>
+            where
+                T: Clone,
+            {
+                let derived = self.observe_with_current(|val, observers| {
+                    let derived: GenericReactive<U, $backend> = GenericReactive::new(f(val));
+                    observers.add({
+                        let derived = derived.clone();
+                        move |value| derived.update(|_| f(value))
+                    });
+                    derived
+                });
+                self.add_child(&derived);
+
+                derived
+            }
+
+            /// Same as `derive` but the derived reactive starts with `initial`
+            /// instead of `f` applied to the current value. `f` only runs on later changes of the parent.
+            ///
+            /// Useful for placeholder states like `None` or `Loading` until the first change arrives.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10);
+            /// let d = r.derive_with_initial(None, |val| Some(val + 5));
+            ///
+            /// assert_eq!(None, d.value());
+            ///
+            /// r.update(|_| 20);
+            ///
+            /// assert_eq!(Some(25), d.value());
+            /// ```
+            pub fn derive_with_initial<
+                U: Clone + PartialEq + $($send)* 'static,
+            >(
+                &self,
+                initial: U,
+                f: impl Fn(&T) -> U + $($send)* 'static,
+            ) -> GenericReactive<U, $backend> {
+                let derived: GenericReactive<U, $backend> = GenericReactive::new(initial);
+                self.observe_with_current(|_, observers| {
+                    observers.add({
+                        let derived = derived.clone();
+                        move |value| derived.update(|_| f(value))
+                    })
+                });
+                self.add_child(&derived);
+
+                derived
+            }
+
+            /// Same as `derive` but `f` also gets the current value of `ctx`.
+            ///
+            /// `ctx` is a read-only dependency: it is sampled whenever this reactive changes,
+            /// but changes to `ctx` alone don't recompute the derived value.
+            ///
+            /// `f` gets a clone of the value of `ctx`, so no lock of `ctx` is held while `f` runs
+            /// or while this reactive is locked. `ctx` may be this reactive itself or depend on it,
+            /// as long as it isn't updated in place (see [`GenericReactive::notify_locked`]).
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let price = Reactive::new(10);
+            /// let tax_rate = Reactive::new(20);
+            /// let total = price.derive_with_context(&tax_rate, |price, rate| price + price * rate / 100);
+            ///
+            /// assert_eq!(12, total.value());
+            ///
+            /// tax_rate.update(|_| 50);
+            /// assert_eq!(12, total.value()); // not recomputed
+            ///
+            /// price.update(|_| 20);
+            /// assert_eq!(30, total.value());
+            /// ```
+            pub fn derive_with_context<
+                U: Clone + PartialEq + $($send)* 'static,
+                C: Clone + $($send)* 'static,
+            >(
+                &self,
+                ctx: &GenericReactive<C, $backend>,
+                f: impl Fn(&T, &C) -> U + $($send)* 'static,
+            ) -> GenericReactive<U, $backend> {
+                // read before locking this reactive, which may be `ctx` itself
+                let initial_ctx = ctx.value();
+                let derived = self.observe_with_current(|val, observers| {
+                    let derived: GenericReactive<U, $backend> = GenericReactive::new(f(val, &initial_ctx));
+                    observers.add({
+                        let derived = derived.clone();
+                        let ctx = ctx.clone();
+                        move |value| {
+                            // the value of `self` is released while its observers are called
+                            // (except for in-place updates), so `ctx` can be read here even if it is `self`
+                            let ctx = ctx.value();
+                            derived.update(|_| f(value, &ctx))
+                        }
+                    });
+                    derived
+                });
+                self.add_child(&derived);
+
+                derived
+            }
+
+            /// Fused version of `self.derive(f).derive(g)`.
+            ///
+            /// The intermediate reactive is never created. Instead a single observer
+            /// remembers the last intermediate value and only calls `g` when it changes,
+            /// so the result is observationally identical to the chained version
+            /// with one less allocation, observer and lock per update.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10);
+            /// let d = r.derive2(|val| val + 5, |val| val * 2);
+            ///
+            /// assert_eq!(30, d.value());
+            ///
+            /// r.update(|_| 20);
+            ///
+            /// assert_eq!(50, d.value());
+            /// ```
+            pub fn derive2<
+                U: PartialEq + $($send)* 'static,
+                V: Clone + PartialEq + $($send)* 'static,
+            >(
+                &self,
+                f: impl Fn(&T) -> U + $($send)* 'static,
+                g: impl Fn(&U) -> V + $($send)* 'static,
+            ) -> GenericReactive<V, $backend> {
+                let derived = self.observe_with_current(|val, observers| {
+                    let mut last_u = f(val);
+                    let derived: GenericReactive<V, $backend> = GenericReactive::new(g(&last_u));
+                    observers.add({
+                        let derived = derived.clone();
+                        move |value| {
+                            let u = f(value);
+                            if u != last_u {
+                                derived.update(|_| g(&u));
+                                last_u = u;
+                            }
+                        }
+                    });
+                    derived
+                });
+                self.add_child(&derived);
+
+                derived
+            }
+
+            /// Fused version of `self.derive(f).derive(g).derive(h)`.
+            /// (see [`Reactive::derive2`])
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10);
+            /// let d = r.derive3(|val| val + 5, |val| val * 2, |val| val.to_string());
+            ///
+            /// assert_eq!("30", d.value());
+            /// ```
+            pub fn derive3<
+                U: PartialEq + $($send)* 'static,
+                V: PartialEq + $($send)* 'static,
+                W: Clone + PartialEq + $($send)* 'static,
+            >(
+                &self,
+                f: impl Fn(&T) -> U + $($send)* 'static,
+                g: impl Fn(&U) -> V + $($send)* 'static,
+                h: impl Fn(&V) -> W + $($send)* 'static,
+            ) -> GenericReactive<W, $backend> {
+                let derived = self.observe_with_current(|val, observers| {
+                    let mut last_u = f(val);
+                    let mut last_v = g(&last_u);
+                    let derived: GenericReactive<W, $backend> = GenericReactive::new(h(&last_v));
+                    observers.add({
+                        let derived = derived.clone();
+                        move |value| {
+                            let u = f(value);
+                            if u != last_u {
+                                let v = g(&u);
+                                if v != last_v {
+                                    derived.update(|_| h(&v));
+                                    last_v = v;
+                                }
+                                last_u = u;
+                            }
+                        }
+                    });
+                    derived
+                });
+                self.add_child(&derived);
+
+                derived
+            }
+
+            /// Same as `(self.derive(f1), self.derive(f2))` but both derived reactives
+            /// are updated by a single observer, so the fan-out costs one observer call per update.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(vec![3, 1, 2]);
+            /// let (len, max) = r.multi_derive(|nums| nums.len(), |nums| nums.iter().max().copied());
+            ///
+            /// r.update_inplace(|nums| nums.push(5));
+            ///
+            /// assert_eq!(4, len.value());
+            /// assert_eq!(Some(5), max.value());
+            /// ```
+            pub fn multi_derive<
+                U1: Clone + PartialEq + $($send)* 'static,
+                U2: Clone + PartialEq + $($send)* 'static,
+            >(
+                &self,
+                f1: impl Fn(&T) -> U1 + $($send)* 'static,
+                f2: impl Fn(&T) -> U2 + $($send)* 'static,
+            ) -> (GenericReactive<U1, $backend>, GenericReactive<U2, $backend>) {
+                let (d1, d2) = self.observe_with_current(|val, observers| {
+                    let d1: GenericReactive<U1, $backend> = GenericReactive::new(f1(val));
+                    let d2: GenericReactive<U2, $backend> = GenericReactive::new(f2(val));
+                    observers.add({
+                        let d1 = d1.clone();
+                        let d2 = d2.clone();
+                        move |value| {
+                            d1.update(|_| f1(value));
+                            d2.update(|_| f2(value));
+                        }
+                    });
+                    (d1, d2)
+                });
+                self.add_child(&d1);
+                self.add_child(&d2);
+
+                (d1, d2)
+            }
+
+            /// Adds a new observer to the reactive.
+            /// the observer functions are called whenever the value inside the Reactive is updated
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(String::from("🦀"));
+            /// r.add_observer(|val| println!("{}", val));
+            /// ```
+            pub fn add_observer(
+                &self,
+                f: impl FnMut(&T) + $($send)* 'static,
+            ) {
+                self.add_removable_observer(f);
+            }
+
+            /// Same as `add_observer` but takes and returns the reactive itself,
+            /// so logging, metrics or persistence can be attached while building it.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10)
+            ///     .side_effect(|val| println!("value: {}", val))
+            ///     .side_effect(|val| println!("doubled: {}", val * 2));
+            ///
+            /// r.update(|n| n + 1);
+            /// ```
+            pub fn side_effect(
+                self,
+                f: impl FnMut(&T) + $($send)* 'static,
+            ) -> Self {
+                self.add_observer(f);
+                self
+            }
+
+            /// Makes every later change of the value go through `f` before it is stored,
+            /// so the value always satisfies an invariant (clamping, normalization, ...).
+            /// `f` is applied to the current value right away too (without notifying the observers).
+            ///
+            /// Applies to every way of changing the value (`set`, `update`, `update_inplace`, `with_mut`, ...)
+            /// except `with`, which gives raw access. `f` gets a clone of the new value
+            /// and must not access this reactive. The invariant is shared by all the clones of the reactive
+            /// and replaces the previous one, if any.
+            ///
+            /// Change detection happens **after** `f` is applied, so an update that is
+            /// brought back to the current value by the invariant doesn't notify the observers.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let volume = Reactive::new(50).with_invariant(|v: i32| v.clamp(0, 100));
+            /// let changes = Reactive::new(0);
+            /// volume.add_observer({
+            ///     let changes = changes.clone();
+            ///     move |_| changes.update(|n| n + 1)
+            /// });
+            ///
+            /// volume.update(|v| v + 80);
+            /// assert_eq!(100, volume.value());
+            ///
+            /// volume.update(|v| v + 10); // clamped back to 100, so nothing changes
+            /// assert_eq!(1, changes.value());
+            /// ```
+            pub fn with_invariant(
+                self,
+                f: impl Fn(T) -> T + $($send)* 'static,
+            ) -> Self
+            where
+                T: Clone,
+            {
+                let invariant: <$backend as Backend>::Invariant<T> = Box::new(move |val: &mut T| *val = f(val.clone()));
+
+                {
+                    let (_observers, mut guard) = self.acq_both();
+                    invariant(&mut guard);
+                    *<$backend>::lock(&self.inner.invariant) = Some(invariant);
+                }
+
+                self
+            }
+
+            /// Adds a new observer that receives a clone of `context` along with the value on every call.
+            ///
+            /// Handy when a single observer function is shared between many reactives
+            /// and needs to know which one of them triggered it.
+            ///
+            /// Returns a handle that can be used to remove the observer later on.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let a = Reactive::new(10);
+            /// let b = Reactive::new(20);
+            ///
+            /// let log = |source: &'static str, val: &i32| println!("{} changed to {}", source, val);
+            ///
+            /// a.add_observer_with_context("a", log);
+            /// let handle = b.add_observer_with_context("b", log);
+            ///
+            /// a.update(|n| n + 1); // a changed to 11
+            /// b.update(|n| n + 1); // b changed to 21
+            ///
+            /// handle.remove();
+            /// b.update(|n| n + 1); // nothing is printed
+            /// ```
+            pub fn add_observer_with_context<
+                C: Clone + $($send)* 'static,
+            >(
+                &self,
+                context: C,
+                mut f: impl FnMut(C, &T) + $($send)* 'static,
+            ) -> ObserverHandle<T, $backend> {
+                self.add_removable_observer(move |val| f(context.clone(), val))
+            }
+
+            /// Adds a new observer that owns a context object `ctx`.
+            /// A mutable reference to the context is passed to the observer function on every call,
+            /// so stateful observers (counters, buffers, ...) don't need their own `Rc<RefCell<_>>`
+            /// or `Arc<Mutex<_>>`.
+            ///
+            /// The context lives as long as the observer does and is dropped along with it.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let r = Reactive::new(10);
+            /// r.add_observer_ctx(0, |count, val| {
+            ///     *count += 1;
+            ///     println!("change #{}: {}", count, val);
+            /// });
+            ///
+            /// r.update(|n| n + 1);
+            /// ```
+            pub fn add_observer_ctx<
+                C: $($send)* 'static,
+            >(
+                &self,
+                mut ctx: C,
+                mut f: impl FnMut(&mut C, &T) + $($send)* 'static,
+            ) {
+                self.add_observer(move |val| f(&mut ctx, val));
+            }
+
+            /// Adds a new observer that is only called when `key_fn` of the new value
+            /// differs from `key_fn` of the previous one.
+            ///
+            /// Useful when only a part of the value (a field, a length, ...) matters to the observer.
+            /// `T` itself doesn't need to implement `PartialEq`, only the key does.
+            ///
+            /// Returns a handle that can be used to remove the observer later on.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// #[derive(Clone)]
+            /// struct User {
+            ///     name: String,
+            ///     last_seen: u64,
+            /// }
+            ///
+            /// let r = Reactive::new(User { name: String::from("zahash"), last_seen: 0 });
+            ///
+            /// r.observe_distinct_by(
+            ///     |user| user.name.clone(),
+            ///     |user| println!("renamed to {}", user.name),
+            /// );
+            ///
+            /// r.update_unchecked(|user| User { name: user.name.clone(), last_seen: 1 }); // nothing is printed
+            /// r.update_unchecked(|user| User { name: String::from("hazash"), last_seen: user.last_seen }); // renamed to hazash
+            /// ```
+            pub fn observe_distinct_by<
+                K: PartialEq + $($send)* 'static,
+            >(
+                &self,
+                key_fn: impl Fn(&T) -> K + $($send)* 'static,
+                mut f: impl FnMut(&T) + $($send)* 'static,
+            ) -> ObserverHandle<T, $backend> {
+                self.observe_with_current(|val, observers| {
+                    let mut prev_key = key_fn(val);
+                    observers.add(move |val| {
+                        let key = key_fn(val);
+                        if key != prev_key {
+                            prev_key = key;
+                            f(val);
+                        }
+                    })
+                })
+            }
+
+            /// Calls `f` exactly once, with the first value of the reactive that satisfies `pred`.
+            ///
+            /// If the current value already satisfies it, `f` is called right away
+            /// (with a clone of the value, after releasing the lock, so it can access the reactive).
+            /// Otherwise it is called on the first notification whose value does
+            /// and the observer removes itself afterwards.
+            ///
+            /// Returns a handle that can be used to cancel the observer before it fires.
+            ///
+            /// # Examples
+            /// ```
+            /// use reactivate::Reactive;
+            ///
+            /// let progress = Reactive::new(0);
+            ///
+            /// progress.once_on_condition(|p| *p >= 100, |_| println!("download complete"));
+            ///
+            /// progress.update(|_| 50);
+            /// progress.update(|_| 100); // download complete
+            /// progress.update(|_| 0);
+            /// progress.update(|_| 100); // nothing is printed
+            /// ```
+            pub fn once_on_condition(
+                &self,
+                pred: impl Fn(&T) -> bool + $($send)* 'static,
+                f: impl FnOnce(&T) + $($send)* 'static,
+            ) -> ObserverHandle<T, $backend>
+            where
+                T: Clone,
+            {
+                let mut f = Some(f);
+                let registered = self.observe_with_current(|val, observers| {
+                    if pred(val) {
+                        return Err(val.clone());
+                    }
+
+                    let mut f = f.take();
+                    Ok(observers.add_retaining(move |val| {
+                        if !pred(val) {
+                            return true;
+                        }
+                        if let Some(f) = f.take() {
+                            f(val);
+                        }
+                        false
+                    }))
+                });
+
+                match registered {
+                    Ok(handle) => handle,
+                    Err(val) => {
+                        if let Some(f) = f {
+                            f(&val);
+                        }
+                        ObserverHandle::dangling()
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_storing_closures!(Local, []);
+#[cfg(feature = "std")]
+impl_storing_closures!(ThreadSafe, [Send+]);
@@ -0,0 +1,187 @@
+use arc_swap::ArcSwap;
+use std::{
+    fmt::Debug,
+    ops::Deref,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use crate::sync;
+
+type CellObserver<T> = Box<dyn FnMut(&T) + Send>;
+
+/// Twin of [`crate::Reactive`] for read-mostly values (configuration and the like)
+/// whose read path doesn't take any lock at all.
+///
+/// The value is kept in an `Arc<T>` that is swapped atomically on every update.
+/// Readers get a cheap `Arc<T>` clone from [`ReactiveCell::value_arc`] and keep
+/// seeing that snapshot for as long as they hold it, no matter how many updates happen meanwhile.
+///
+/// Updates are serialized (by the lock on the observers) and build a brand new `Arc<T>`,
+/// so they are more expensive than the ones of `Reactive`.
+/// Observers are called with the new value after it is swapped in.
+///
+/// # Examples
+/// ```
+/// use reactivate::ReactiveCell;
+///
+/// let config = ReactiveCell::new(String::from("v1"));
+/// let len = config.derive(|s| s.len());
+///
+/// let snapshot = config.value_arc();
+/// config.set(String::from("v2.0"));
+///
+/// assert_eq!("v1", *snapshot);
+/// assert_eq!("v2.0", *config.value_arc());
+/// assert_eq!(4, len.value());
+/// ```
+pub struct ReactiveCell<T> {
+    value: Arc<ArcSwap<T>>,
+    observers: Arc<Mutex<Vec<CellObserver<T>>>>,
+}
+
+impl<T> Clone for ReactiveCell<T> {
+    /// Returns a new handle to the same reactive. (the value and observers are shared, not copied)
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            observers: self.observers.clone(),
+        }
+    }
+}
+
+impl<T: Default> Default for ReactiveCell<T> {
+    fn default() -> Self {
+        Self {
+            value: Arc::new(ArcSwap::from_pointee(T::default())),
+            observers: Default::default(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> ReactiveCell<T> {
+    /// Constructs a new `ReactiveCell<T>`
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Arc::new(ArcSwap::from_pointee(value)),
+            observers: Default::default(),
+        }
+    }
+
+    /// Returns the current value without taking any lock.
+    pub fn value_arc(&self) -> Arc<T> {
+        self.value.load_full()
+    }
+
+    /// Returns a clone/copy of the value inside the reactive
+    pub fn value(&self) -> T
+    where
+        T: Clone,
+    {
+        self.value.load().deref().deref().clone()
+    }
+
+    /// Perform some action with the reference to the inner value.
+    pub fn with_value(&self, f: impl FnOnce(&T)) {
+        f(&self.value.load());
+    }
+
+    /// derive a new child reactive that changes whenever the parent reactive changes.
+    /// (same as [`crate::Reactive::derive`])
+    pub fn derive<U: PartialEq + Send + Sync + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + 'static,
+    ) -> ReactiveCell<U> {
+        // same invariant as `Reactive::observe_with_current`
+        let mut observers = self.acq_obs();
+        let derived = ReactiveCell::new(f(&self.value.load()));
+
+        observers.push(Box::new({
+            let derived = derived.clone();
+            move |val| derived.update(|_| f(val))
+        }));
+
+        derived
+    }
+
+    /// Adds a new observer to the reactive.
+    pub fn add_observer(&self, f: impl FnMut(&T) + Send + 'static) {
+        self.acq_obs().push(Box::new(f));
+    }
+
+    /// Clears all observers from the reactive.
+    pub fn clear_observers(&self) {
+        self.acq_obs().clear();
+    }
+
+    /// Set the value inside the reactive to something new and notify all the observers.
+    /// (even if the provided value is the same as the current one)
+    pub fn set(&self, val: T) {
+        let mut observers = self.acq_obs();
+        self.swap_and_call(&mut observers, val);
+    }
+
+    /// Update the value inside the reactive and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function
+    pub fn update(&self, f: impl FnOnce(&T) -> T)
+    where
+        T: PartialEq,
+    {
+        let mut observers = self.acq_obs();
+        let new_val = f(&self.value.load());
+        if new_val != **self.value.load() {
+            self.swap_and_call(&mut observers, new_val);
+        }
+    }
+
+    /// Applies `f` to a clone of the current value and swaps it in,
+    /// notifying all the observers **ONLY** if the value changes.
+    pub fn update_inplace(&self, f: impl FnOnce(&mut T))
+    where
+        T: Clone + PartialEq,
+    {
+        let mut observers = self.acq_obs();
+        let mut new_val = self.value.load().deref().deref().clone();
+        f(&mut new_val);
+        if new_val != **self.value.load() {
+            self.swap_and_call(&mut observers, new_val);
+        }
+    }
+
+    /// Update the value inside the reactive and notify all the observers
+    /// without checking if the value is changed after applying the provided function
+    pub fn update_unchecked(&self, f: impl FnOnce(&T) -> T) {
+        let mut observers = self.acq_obs();
+        let new_val = f(&self.value.load());
+        self.swap_and_call(&mut observers, new_val);
+    }
+
+    /// Notify all the observers of the current value.
+    pub fn notify(&self) {
+        let mut observers = self.acq_obs();
+        let val = self.value.load_full();
+        for obs in observers.iter_mut() {
+            obs(&val);
+        }
+    }
+
+    fn swap_and_call(&self, observers: &mut [CellObserver<T>], val: T) {
+        let val = Arc::new(val);
+        self.value.store(val.clone());
+        for obs in observers.iter_mut() {
+            obs(&val);
+        }
+    }
+
+    #[inline]
+    fn acq_obs(&self) -> MutexGuard<'_, Vec<CellObserver<T>>> {
+        sync::lock_std(&self.observers)
+    }
+}
+
+impl<T: Debug> Debug for ReactiveCell<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ReactiveCell")
+            .field(self.value.load().deref())
+            .finish()
+    }
+}
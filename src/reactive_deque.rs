@@ -0,0 +1,208 @@
+use crate::{
+    observers::Observers,
+    sync::{lock, shared, Shared},
+    Reactive,
+};
+use alloc::collections::VecDeque;
+use alloc::{vec, vec::Vec};
+
+/// Change made to a [`ReactiveDeque`], passed to its change observers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DequeChange<T> {
+    PushFront(T),
+    PushBack(T),
+    PopFront(T),
+    PopBack(T),
+    /// elements removed by `retain`, in their original order
+    Retain(Vec<T>),
+    /// elements removed by `clear`, in their original order
+    Clear(Vec<T>),
+}
+
+/// Double-ended queue whose observers are told exactly what changed
+/// instead of receiving the whole collection.
+///
+/// The elements live in a regular `Reactive<VecDeque<T>>` (see [`ReactiveDeque::reactive`])
+/// so everything that works with a reactive (derives, merges, ...) works here as well.
+/// Operations that don't change anything (popping an empty deque, ...) notify no one.
+///
+/// # Examples
+/// ```
+/// use reactivate::{DequeChange, ReactiveDeque};
+///
+/// let queue = ReactiveDeque::new();
+/// let len = queue.len_reactive();
+///
+/// queue.add_observer(|change| {
+///     if let DequeChange::PushBack(job) = change {
+///         println!("queued {}", job);
+///     }
+/// });
+///
+/// queue.push_back("build");
+/// queue.push_back("test");
+/// assert_eq!(2, len.value());
+///
+/// assert_eq!(Some("build"), queue.pop_front());
+/// assert_eq!(1, len.value());
+/// ```
+pub struct ReactiveDeque<T> {
+    deque: Reactive<VecDeque<T>>,
+    observers: Shared<Observers<DequeChange<T>>>,
+}
+
+impl<T> ReactiveDeque<T> {
+    /// Constructs a new, empty `ReactiveDeque<T>`
+    pub fn new() -> Self {
+        Self {
+            deque: Reactive::new(VecDeque::new()),
+            observers: shared(Observers::default()),
+        }
+    }
+
+    /// Adds a new observer that is called with every change made to the deque
+    pub fn add_observer(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] f: impl FnMut(&DequeChange<T>) + 'static,
+        #[cfg(feature = "threadsafe")] f: impl FnMut(&DequeChange<T>) + Send + 'static,
+    ) {
+        lock(&self.observers).push(f);
+    }
+
+    /// Returns the underlying reactive.
+    ///
+    /// Its observers are notified on every change as usual, but changes made
+    /// directly through it are **NOT** reported to the observers of the deque.
+    pub fn reactive(&self) -> &Reactive<VecDeque<T>> {
+        &self.deque
+    }
+
+    /// Number of elements in the deque
+    pub fn len(&self) -> usize {
+        self.deque.acq_val().len()
+    }
+
+    /// `true` if the deque has no elements
+    pub fn is_empty(&self) -> bool {
+        self.deque.acq_val().is_empty()
+    }
+
+    /// Applies `f` and notifies the observers of the underlying reactive
+    /// and then the change observers, all while the deque is locked
+    /// so observers see the changes in the order they were made.
+    fn apply<R>(
+        &self,
+        f: impl FnOnce(&mut VecDeque<T>) -> Option<(DequeChange<T>, R)>,
+    ) -> Option<R> {
+        let mut result = None;
+        self.deque.with(|deque, obs| {
+            if let Some((change, r)) = f(deque) {
+                for observer in obs {
+                    observer(deque);
+                }
+                lock(&self.observers).call(&change);
+                result = Some(r);
+            }
+        });
+        result
+    }
+}
+
+impl<T: Clone> ReactiveDeque<T> {
+    /// Returns a clone of the first element
+    pub fn front(&self) -> Option<T> {
+        self.deque.acq_val().front().cloned()
+    }
+
+    /// Returns a clone of the last element
+    pub fn back(&self) -> Option<T> {
+        self.deque.acq_val().back().cloned()
+    }
+
+    /// Prepends an element to the deque
+    pub fn push_front(&self, val: T) {
+        self.apply(|deque| {
+            deque.push_front(val.clone());
+            Some((DequeChange::PushFront(val), ()))
+        });
+    }
+
+    /// Appends an element to the deque
+    pub fn push_back(&self, val: T) {
+        self.apply(|deque| {
+            deque.push_back(val.clone());
+            Some((DequeChange::PushBack(val), ()))
+        });
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty
+    pub fn pop_front(&self) -> Option<T> {
+        self.apply(|deque| {
+            let val = deque.pop_front()?;
+            Some((DequeChange::PopFront(val.clone()), val))
+        })
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty
+    pub fn pop_back(&self) -> Option<T> {
+        self.apply(|deque| {
+            let val = deque.pop_back()?;
+            Some((DequeChange::PopBack(val.clone()), val))
+        })
+    }
+
+    /// Keeps only the elements for which `f` returns `true`
+    pub fn retain(&self, mut f: impl FnMut(&T) -> bool) {
+        self.apply(|deque| {
+            let mut removed = vec![];
+            deque.retain(|val| {
+                let keep = f(val);
+                if !keep {
+                    removed.push(val.clone());
+                }
+                keep
+            });
+            (!removed.is_empty()).then_some((DequeChange::Retain(removed), ()))
+        });
+    }
+
+    /// Removes all the elements
+    pub fn clear(&self) {
+        self.apply(|deque| {
+            let removed: Vec<T> = deque.drain(..).collect();
+            (!removed.is_empty()).then_some((DequeChange::Clear(removed), ()))
+        });
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > ReactiveDeque<T>
+{
+    /// Reactive length of the deque. (`.reactive().derive(|d| d.len())`)
+    pub fn len_reactive(&self) -> Reactive<usize> {
+        self.deque.derive(|d| d.len())
+    }
+
+    /// Reactive emptiness of the deque. (`.reactive().derive(|d| d.is_empty())`)
+    pub fn is_empty_reactive(&self) -> Reactive<bool> {
+        self.deque.derive(|d| d.is_empty())
+    }
+}
+
+impl<T> Default for ReactiveDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ReactiveDeque<T> {
+    /// Returns a new handle to the same deque. (the elements and observers are shared, not copied)
+    fn clone(&self) -> Self {
+        Self {
+            deque: self.deque.clone(),
+            observers: self.observers.clone(),
+        }
+    }
+}
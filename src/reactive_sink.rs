@@ -0,0 +1,138 @@
+use crate::{observers::ObserverHandle, Reactive};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::{
+    fmt::Display,
+    io::Write,
+    sync::{Arc, Mutex},
+};
+
+/// Anything that can accept the values of a reactive. (see [`Reactive::subscribe_to_sink`])
+///
+/// Implemented for `Vec<T>` (collects clones of the values) and for closures taking `&T`.
+/// With `std`, also for any `std::io::Write` wrapped in a [`WriteSink`]
+/// and for `Arc<Mutex<S>>` so that the sink can still be reached (to read a `Vec<T>`, ...) after subscribing.
+pub trait ReactiveSink<T> {
+    /// Accepts a new value of the reactive.
+    fn send(&mut self, value: &T);
+
+    /// Pushes out anything the sink buffered. Called once the sink is unsubscribed.
+    fn flush(&mut self) {}
+}
+
+impl<T: Clone> ReactiveSink<T> for Vec<T> {
+    fn send(&mut self, value: &T) {
+        self.push(value.clone());
+    }
+}
+
+impl<T, F: FnMut(&T)> ReactiveSink<T> for F {
+    fn send(&mut self, value: &T) {
+        self(value);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S: ReactiveSink<T>> ReactiveSink<T> for Arc<Mutex<S>> {
+    fn send(&mut self, value: &T) {
+        crate::sync::lock_std(self).send(value);
+    }
+
+    fn flush(&mut self) {
+        crate::sync::lock_std(self).flush();
+    }
+}
+
+/// [`ReactiveSink`] that writes every value on its own line (using `Display`) into a `std::io::Write`.
+///
+/// Write errors are ignored, the sink has nowhere to report them.
+///
+/// # Examples
+/// ```
+/// use reactivate::{Reactive, WriteSink};
+///
+/// let r = Reactive::new(0);
+/// r.subscribe_to_sink(WriteSink::new(std::io::stdout()));
+///
+/// r.update(|n| n + 1); // prints "1"
+/// ```
+#[cfg(feature = "std")]
+pub struct WriteSink<W>(W);
+
+#[cfg(feature = "std")]
+impl<W> WriteSink<W> {
+    /// Wraps the `writer`
+    pub fn new(writer: W) -> Self {
+        Self(writer)
+    }
+
+    /// Returns the wrapped writer
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Display, W: Write> ReactiveSink<T> for WriteSink<W> {
+    fn send(&mut self, value: &T) {
+        let _ = writeln!(self.0, "{}", value);
+    }
+
+    fn flush(&mut self) {
+        let _ = self.0.flush();
+    }
+}
+
+/// Flushes the sink once the observer holding it is dropped
+struct Subscribed<S: ReactiveSink<T>, T>(S, core::marker::PhantomData<fn(&T)>);
+
+impl<S: ReactiveSink<T>, T> Drop for Subscribed<S, T> {
+    fn drop(&mut self) {
+        self.0.flush();
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: 'static,
+        #[cfg(feature = "threadsafe")] T: Send + 'static,
+    > Reactive<T>
+{
+    /// Sends every new value of the reactive into `sink`.
+    ///
+    /// The sink is flushed once it is unsubscribed (the returned handle is used to remove it,
+    /// the observers are cleared or the reactive is dropped).
+    ///
+    /// Unlike `observe_into`, it isn't tied to a channel
+    /// and the values aren't cloned unless the sink needs to. (`Vec<T>` does)
+    ///
+    /// Returns a handle that can be used to unsubscribe the sink later on.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let r = Reactive::new(0);
+    /// let total = Arc::new(Mutex::new(0));
+    ///
+    /// r.subscribe_to_sink({
+    ///     let total = total.clone();
+    ///     move |n: &i32| *total.lock().unwrap() += n
+    /// });
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 2);
+    ///
+    /// assert_eq!(4, *total.lock().unwrap());
+    /// ```
+    pub fn subscribe_to_sink<
+        #[cfg(not(feature = "threadsafe"))] S: ReactiveSink<T> + 'static,
+        #[cfg(feature = "threadsafe")] S: ReactiveSink<T> + Send + 'static,
+    >(
+        &self,
+        sink: S,
+    ) -> ObserverHandle<T> {
+        let mut sink = Subscribed(sink, core::marker::PhantomData);
+        self.add_removable_observer(move |val| sink.0.send(val))
+    }
+}
@@ -0,0 +1,57 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + PartialEq + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a new reactive that always holds the same value as this one
+    /// and calls `f` whenever that value changes.
+    ///
+    /// Useful for inserting a monitoring point into an existing derive chain.
+    /// Unlike `self.derive(|val| { f(val); val.clone() })`, the value is compared
+    /// **before** cloning it, so nothing is cloned when it didn't change.
+    ///
+    /// `f` is called after the observers of the returned reactive.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let monitored = r.relay(|val| println!("passing through: {}", val));
+    /// let d = monitored.derive(|val| val * 2);
+    ///
+    /// r.update(|n| n + 1); // passing through: 11
+    ///
+    /// assert_eq!(11, monitored.value());
+    /// assert_eq!(22, d.value());
+    /// ```
+    pub fn relay(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] mut f: impl FnMut(&T) + 'static,
+        #[cfg(feature = "threadsafe")] mut f: impl FnMut(&T) + Send + 'static,
+    ) -> Reactive<T> {
+        let relay = self.observe_with_current(|val, observers| {
+            let relay = Reactive::new(val.clone());
+            observers.add({
+                let relay = relay.clone();
+                move |val| {
+                    let (observers, mut guard) = relay.acq_update();
+                    if *guard == *val {
+                        return;
+                    }
+
+                    *guard = val.clone();
+                    relay.enforce_invariant(&mut guard);
+                    relay.notify_unlocked(observers, guard);
+                    f(val);
+                }
+            });
+            relay
+        });
+        self.add_child(&relay);
+
+        relay
+    }
+}
@@ -0,0 +1,86 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Same as `derive` but for transform functions that can fail.
+    ///
+    /// The derived reactive holds the latest `Result`, so downstream observers
+    /// see the errors too. Use [`ReactiveResultExt::ok`] to only keep the successful values.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(String::from("10"));
+    /// let d = r.try_derive(|s| s.parse::<i32>().map_err(|e| e.to_string()));
+    ///
+    /// assert_eq!(Ok(10), d.value());
+    ///
+    /// r.update(|_| String::from("🦀"));
+    ///
+    /// assert!(d.value().is_err());
+    /// ```
+    pub fn try_derive<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] E: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] E: Clone + PartialEq + Send + 'static,
+    >(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] f: impl Fn(&T) -> Result<U, E> + 'static,
+        #[cfg(feature = "threadsafe")] f: impl Fn(&T) -> Result<U, E> + Send + 'static,
+    ) -> Reactive<Result<U, E>> {
+        self.derive(f)
+    }
+}
+
+/// Helpers for reactives holding a `Result`
+pub trait ReactiveResultExt<U> {
+    /// Returns a new reactive that only follows the successful values.
+    ///
+    /// It starts as `Some(value)` if the current value is `Ok(value)` and `None` otherwise.
+    /// Errors are skipped, so it keeps holding the last successful value.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Reactive, ReactiveResultExt};
+    ///
+    /// let r = Reactive::new(String::from("10"));
+    /// let ok = r
+    ///     .try_derive(|s| s.parse::<i32>().map_err(|e| e.to_string()))
+    ///     .ok();
+    ///
+    /// r.update(|_| String::from("🦀"));
+    /// assert_eq!(Some(10), ok.value());
+    ///
+    /// r.update(|_| String::from("20"));
+    /// assert_eq!(Some(20), ok.value());
+    /// ```
+    fn ok(&self) -> Reactive<Option<U>>;
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] E: 'static,
+        #[cfg(feature = "threadsafe")] E: Send + 'static,
+    > ReactiveResultExt<U> for Reactive<Result<U, E>>
+{
+    fn ok(&self) -> Reactive<Option<U>> {
+        self.observe_with_current(|val, observers| {
+            let ok = Reactive::new(val.as_ref().ok().cloned());
+            observers.add({
+                let ok = ok.clone();
+                move |val| {
+                    if let Ok(val) = val {
+                        ok.update(|_| Some(val.clone()));
+                    }
+                }
+            });
+            ok
+        })
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use crate::{hash::DefaultHashBuilder, sync};
+
+type RwObserver<T> = Box<dyn FnMut(&T) + Send>;
+
+/// Twin of [`crate::Reactive`] that keeps its value in an `RwLock` instead of a `Mutex`,
+/// for read-heavy workloads with many threads calling `value()` at the same time.
+///
+/// Reads (`value`, `with_value`, `borrowed`, `notify`) take a read lock and don't block each other.
+/// The update family takes the write lock only while changing the value, then the observers
+/// are called under a read lock, so they (and other threads) can keep reading the reactive.
+/// Observers are still called one after the other, never concurrently.
+///
+/// Since many threads may read the value at once, `T` must be `Sync` as well as `Send`.
+///
+/// # Writer starvation
+/// Whether a constant stream of readers can keep a writer waiting forever depends on
+/// the `RwLock` implementation of the platform (std makes no guarantee either way).
+/// Keep read locks short, especially the guards returned by [`RwReactive::borrowed`].
+///
+/// # Deadlocks
+/// Unlike [`crate::Reactive`], the observers are called while the observers lock and a read lock
+/// on the value are held, and updates made by the observers are not deferred.
+/// So an observer must not update the reactive it observes (`set`, `update`, ...),
+/// nor add or clear its observers: with std locks it deadlocks (or panics).
+/// Reading it (`value`, `with_value`, ...) is fine.
+///
+/// # Examples
+/// ```
+/// use reactivate::RwReactive;
+///
+/// let r = RwReactive::new(10);
+/// let d = r.derive(|val| val + 5);
+///
+/// r.update(|val| val * 2);
+///
+/// assert_eq!(20, r.value());
+/// assert_eq!(25, d.value());
+/// ```
+#[derive(Default)]
+pub struct RwReactive<T> {
+    value: Arc<RwLock<T>>,
+    observers: Arc<Mutex<Vec<RwObserver<T>>>>,
+    hasher: DefaultHashBuilder,
+}
+
+impl<T> Clone for RwReactive<T> {
+    /// Returns a new handle to the same reactive. (the value and observers are shared, not copied)
+    #[allow(clippy::clone_on_copy)] // the hasher is only `Copy` with some of the features
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            observers: self.observers.clone(),
+            hasher: self.hasher.clone(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> RwReactive<T> {
+    /// Constructs a new `RwReactive<T>`
+    pub fn new(value: T) -> Self {
+        Self {
+            value: Arc::new(RwLock::new(value)),
+            observers: Default::default(),
+            hasher: DefaultHashBuilder::default(),
+        }
+    }
+
+    /// Returns a clone/copy of the value inside the reactive
+    pub fn value(&self) -> T
+    where
+        T: Clone,
+    {
+        self.read().clone()
+    }
+
+    /// Perform some action with the reference to the inner value.
+    pub fn with_value(&self, f: impl FnOnce(&T)) {
+        f(self.read().deref());
+    }
+
+    /// Returns a read guard to the value inside the reactive, avoiding the clone done by `value()`.
+    ///
+    /// Other readers are not blocked by the guard, but **updates are**
+    /// until it is dropped, so keep it short-lived.
+    pub fn borrowed(&self) -> impl Deref<Target = T> + '_ {
+        self.read()
+    }
+
+    /// derive a new child reactive that changes whenever the parent reactive changes.
+    /// (same as [`crate::Reactive::derive`])
+    pub fn derive<U: Clone + PartialEq + Send + Sync + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + 'static,
+    ) -> RwReactive<U> {
+        // same invariant as `Reactive::observe_with_current`
+        let mut observers = self.acq_obs();
+        let derived = RwReactive::new(f(self.read().deref()));
+
+        observers.push(Box::new({
+            let derived = derived.clone();
+            move |val| derived.update(|_| f(val))
+        }));
+
+        derived
+    }
+
+    /// Adds a new observer to the reactive.
+    pub fn add_observer(&self, f: impl FnMut(&T) + Send + 'static) {
+        self.acq_obs().push(Box::new(f));
+    }
+
+    /// Clears all observers from the reactive.
+    pub fn clear_observers(&self) {
+        self.acq_obs().clear();
+    }
+
+    /// Set the value inside the reactive to something new and notify all the observers.
+    /// (even if the provided value is the same as the current one)
+    pub fn set(&self, val: T) {
+        let mut observers = self.acq_obs();
+        let mut guard = self.write();
+        *guard = val;
+        self.call_observers(&mut observers, guard);
+    }
+
+    /// Update the value inside the reactive and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function
+    pub fn update(&self, f: impl FnOnce(&T) -> T)
+    where
+        T: PartialEq,
+    {
+        let mut observers = self.acq_obs();
+        let mut guard = self.write();
+        let new_val = f(&guard);
+        if &new_val != guard.deref() {
+            *guard = new_val;
+            self.call_observers(&mut observers, guard);
+        }
+    }
+
+    /// Updates the value inside inplace and notify all the observers
+    /// **ONLY** if the value changes after applying the provided function.
+    /// (changes are detected by comparing hashes, same as [`crate::Reactive::update_inplace`])
+    pub fn update_inplace(&self, f: impl FnOnce(&mut T))
+    where
+        T: Hash,
+    {
+        let mut observers = self.acq_obs();
+        let mut guard = self.write();
+
+        let old_hash = self.hasher.hash_one(guard.deref());
+        f(guard.deref_mut());
+        let new_hash = self.hasher.hash_one(guard.deref());
+
+        if old_hash != new_hash {
+            self.call_observers(&mut observers, guard);
+        }
+    }
+
+    /// Update the value inside the reactive and notify all the observers
+    /// without checking if the value is changed after applying the provided function
+    pub fn update_unchecked(&self, f: impl FnOnce(&T) -> T) {
+        let mut observers = self.acq_obs();
+        let mut guard = self.write();
+        *guard = f(&guard);
+        self.call_observers(&mut observers, guard);
+    }
+
+    /// Updates the value inside inplace and notify all the observers
+    /// without checking if the value is changed after applying the provided function.
+    pub fn update_inplace_unchecked(&self, f: impl FnOnce(&mut T)) {
+        let mut observers = self.acq_obs();
+        let mut guard = self.write();
+        f(guard.deref_mut());
+        self.call_observers(&mut observers, guard);
+    }
+
+    /// Notify all the observers of the current value.
+    /// (only takes a read lock on the value)
+    pub fn notify(&self) {
+        let mut observers = self.acq_obs();
+        let guard = self.read();
+        for obs in observers.iter_mut() {
+            obs(guard.deref());
+        }
+    }
+
+    /// Swaps the write guard for a read guard before calling the observers,
+    /// so that they can read the reactive too.
+    ///
+    /// No update can sneak in between the two guards since the caller holds the observers lock,
+    /// which every update takes first.
+    fn call_observers(&self, observers: &mut [RwObserver<T>], guard: RwLockWriteGuard<'_, T>) {
+        drop(guard);
+        let guard = self.read();
+        for obs in observers.iter_mut() {
+            obs(guard.deref());
+        }
+    }
+
+    #[inline]
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        sync::read(&self.value)
+    }
+
+    #[inline]
+    fn write(&self) -> RwLockWriteGuard<'_, T> {
+        sync::write(&self.value)
+    }
+
+    #[inline]
+    fn acq_obs(&self) -> MutexGuard<'_, Vec<RwObserver<T>>> {
+        sync::lock_std(&self.observers)
+    }
+}
+
+impl<T: Debug> Debug for RwReactive<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RwReactive")
+            .field(sync::read(&self.value).deref())
+            .finish()
+    }
+}
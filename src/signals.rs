@@ -0,0 +1,87 @@
+use crate::Reactive;
+use futures_signals::signal::{Mutable, Signal};
+use std::{future::poll_fn, future::Future, pin::pin, pin::Pin};
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> Reactive<T> {
+    /// Returns a `futures_signals::signal::Mutable` that is kept in sync with the reactive.
+    ///
+    /// It starts with the current value and an observer copies every new value into it
+    /// using `set_neq`, so signal subscribers only wake up for real changes.
+    ///
+    /// Bridging the same pair in both directions
+    /// (`to_mutable` and then [`Reactive::from_signal`] on its signal) does not loop forever:
+    /// a value coming back from the other side is equal to the current one and is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let mutable = r.to_mutable();
+    ///
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(11, mutable.get());
+    /// ```
+    pub fn to_mutable(&self) -> Mutable<T> {
+        self.observe_with_current(|val, observers| {
+            let mutable = Mutable::new(val.clone());
+            observers.add({
+                let mutable = mutable.clone();
+                move |val| mutable.set_neq(val.clone())
+            });
+            mutable
+        })
+    }
+
+    /// Constructs a new reactive driven by a `futures_signals::signal::Signal`.
+    ///
+    /// The reactive starts with `T::default()` and a task handed to `spawn` feeds every
+    /// value of the signal into it using `update` (signals yield their current value first,
+    /// so the default is replaced as soon as the task first runs).
+    ///
+    /// `spawn` keeps this runtime agnostic: pass `|fut| { tokio::spawn(fut); }`,
+    /// `wasm_bindgen_futures::spawn_local` or whatever the UI layer uses.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures_signals::signal::Mutable;
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mutable = Mutable::new(5);
+    /// let r = Reactive::from_signal(mutable.signal(), |fut| {
+    ///     tokio::spawn(fut);
+    /// });
+    ///
+    /// tokio::task::yield_now().await;
+    /// assert_eq!(5, r.value());
+    ///
+    /// mutable.set(6);
+    /// tokio::task::yield_now().await;
+    /// assert_eq!(6, r.value());
+    /// # });
+    /// ```
+    pub fn from_signal<S>(
+        signal: S,
+        spawn: impl FnOnce(Pin<Box<dyn Future<Output = ()> + Send>>),
+    ) -> Self
+    where
+        S: Signal<Item = T> + Send + 'static,
+        T: Default,
+    {
+        let reactive = Reactive::default();
+
+        spawn(Box::pin({
+            let reactive = reactive.clone();
+            async move {
+                let mut signal = pin!(signal);
+                while let Some(val) = poll_fn(|cx| signal.as_mut().poll_change(cx)).await {
+                    reactive.update(|_| val);
+                }
+            }
+        }));
+
+        reactive
+    }
+}
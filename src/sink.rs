@@ -0,0 +1,112 @@
+use crate::Reactive;
+use futures_sink::Sink;
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Every item sent into the reactive is `set` as its new value,
+/// so observers are notified even if the value didn't change.
+/// (use [`Reactive::checked_sink`] to only notify on changes)
+///
+/// The sink is always ready and flushing/closing it does nothing.
+///
+/// # Examples
+/// ```
+/// use futures::{stream, StreamExt};
+/// use reactivate::Reactive;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let r = Reactive::new(0);
+/// let d = r.derive(|val| val * 10);
+///
+/// stream::iter(vec![1, 2, 3])
+///     .map(Ok)
+///     .forward(r.clone())
+///     .await
+///     .unwrap();
+///
+/// assert_eq!(3, r.value());
+/// assert_eq!(30, d.value());
+/// # });
+/// ```
+impl<T: Clone> Sink<T> for Reactive<T> {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        Reactive::set(&self, item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Sink returned by [`Reactive::checked_sink`]
+///
+/// Every item sent into it is applied using `update`,
+/// so observers are only notified if the value actually changed.
+pub struct CheckedSink<T>(Reactive<T>);
+
+impl<T> Clone for CheckedSink<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Clone + PartialEq> Sink<T> for CheckedSink<T> {
+    type Error = Infallible;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.0.update(|_| item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: PartialEq> Reactive<T> {
+    /// Returns a sink that feeds items into this reactive using `update`
+    /// instead of `set`, so observers are only notified when the value changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::{stream, StreamExt};
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(0);
+    ///
+    /// stream::iter(vec![1, 1, 2])
+    ///     .map(Ok)
+    ///     .forward(r.checked_sink())
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(2, r.value());
+    /// # });
+    /// ```
+    pub fn checked_sink(&self) -> CheckedSink<T> {
+        CheckedSink(self.clone())
+    }
+}
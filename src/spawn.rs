@@ -0,0 +1,239 @@
+use crate::{
+    observers::ObserverHandle,
+    sync::{lock, shared, Shared},
+    Reactive,
+};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tokio::{runtime::Handle, task::JoinHandle};
+
+/// Values waiting for a spawned observer, plus whether a blocking task is draining them.
+struct Queue<T> {
+    pending: VecDeque<T>,
+    draining: bool,
+}
+
+/// Drains a [`Queue`] on the blocking pool.
+///
+/// If the runtime is shutting down, the task is dropped without ever running.
+/// The drop then resets the queue so later updates can try again instead of piling up.
+struct Drain<T> {
+    queue: Shared<Queue<T>>,
+    done: bool,
+}
+
+impl<T> Drain<T> {
+    fn run(mut self, f: &mut impl FnMut(T)) {
+        loop {
+            let mut queue = lock(&self.queue);
+            let Some(val) = queue.pending.pop_front() else {
+                // checked and cleared under the same lock as the push in the observer,
+                // so no value can be left behind without a task to drain it.
+                queue.draining = false;
+                break;
+            };
+            drop(queue);
+            f(val);
+        }
+        self.done = true;
+    }
+}
+
+impl<T> Drop for Drain<T> {
+    fn drop(&mut self) {
+        if !self.done {
+            let mut queue = lock(&self.queue);
+            queue.draining = false;
+            queue.pending.clear();
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Adds an observer that clones the new value and spawns the future returned by `f`
+    /// on the given runtime, so the observer can do I/O without blocking the updating thread.
+    ///
+    /// Every notification spawns its own task. (see [`Reactive::add_async_observer_latest`]
+    /// to cancel the previous task instead)
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(0);
+    ///
+    /// r.add_async_observer(&tokio::runtime::Handle::current(), |val| async move {
+    ///     println!("saving {} to the database", val);
+    /// });
+    ///
+    /// r.update(|n| n + 1);
+    /// # });
+    /// ```
+    pub fn add_async_observer<F>(
+        &self,
+        handle: &Handle,
+        f: impl Fn(T) -> F + Send + 'static,
+    ) -> ObserverHandle<T>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = handle.clone();
+        self.add_removable_observer(move |val| {
+            handle.spawn(f(val.clone()));
+        })
+    }
+
+    /// Same as [`Reactive::add_async_observer`] but when a new value arrives,
+    /// the task spawned for the previous value is aborted if it is still running.
+    ///
+    /// Useful for observers that sync the latest state over the network,
+    /// where finishing work for an outdated value is pointless.
+    pub fn add_async_observer_latest<F>(
+        &self,
+        handle: &Handle,
+        f: impl Fn(T) -> F + Send + 'static,
+    ) -> ObserverHandle<T>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = handle.clone();
+        let mut running: Option<JoinHandle<()>> = None;
+        self.add_removable_observer(move |val| {
+            if let Some(task) = running.take() {
+                task.abort();
+            }
+            running = Some(handle.spawn(f(val.clone())));
+        })
+    }
+
+    /// Adds a (sync) observer that runs on the runtime's blocking pool (`spawn_blocking`)
+    /// instead of inline, for observers that are simply slow. (serialization, file writes, ...)
+    ///
+    /// Each call receives a clone of the new value. Calls of the same observer never overlap
+    /// and happen in the same order as the changes: values that arrive while a call is
+    /// running are queued and handled by the same blocking task afterwards.
+    ///
+    /// If the runtime has shut down, the values are dropped and `f` is not called.
+    /// The updating thread never panics because of it.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(0);
+    ///
+    /// r.add_observer_spawned(&tokio::runtime::Handle::current(), |val| {
+    ///     println!("writing {} to disk", val);
+    /// });
+    ///
+    /// r.update(|n| n + 1);
+    /// # });
+    /// ```
+    pub fn add_observer_spawned(
+        &self,
+        handle: &Handle,
+        f: impl FnMut(T) + Send + 'static,
+    ) -> ObserverHandle<T> {
+        let handle = handle.clone();
+        let f = shared(f);
+        let queue = shared(Queue {
+            pending: VecDeque::new(),
+            draining: false,
+        });
+
+        self.add_removable_observer(move |val| {
+            let mut guard = lock(&queue);
+            guard.pending.push_back(val.clone());
+            if guard.draining {
+                return;
+            }
+            guard.draining = true;
+            drop(guard);
+
+            let f = f.clone();
+            let drain = Drain {
+                queue: queue.clone(),
+                done: false,
+            };
+            handle.spawn_blocking(move || {
+                drain.run(&mut *lock(&f));
+            });
+        })
+    }
+
+    /// Async version of `derive`: every value of this reactive is passed to `f` and the
+    /// future it returns is spawned on the given runtime. Its output lands in the returned
+    /// reactive as `Some(output)`.
+    ///
+    /// The derived reactive holds `None` until the first computation finishes.
+    /// A new value aborts the computation still running for the previous one, and a result
+    /// that arrives for an outdated value is discarded, so the derived reactive never goes
+    /// back to a stale result.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let user_id = Reactive::new(1);
+    /// let profile = user_id.map_async(&tokio::runtime::Handle::current(), |id| async move {
+    ///     format!("profile of user {}", id)
+    /// });
+    ///
+    /// assert_eq!(None, profile.value());
+    ///
+    /// tokio::task::yield_now().await;
+    /// assert_eq!(Some(String::from("profile of user 1")), profile.value());
+    /// # });
+    /// ```
+    pub fn map_async<U, F>(
+        &self,
+        handle: &Handle,
+        f: impl Fn(T) -> F + Send + Sync + 'static,
+    ) -> Reactive<Option<U>>
+    where
+        U: Clone + PartialEq + Send + 'static,
+        F: Future<Output = U> + Send + 'static,
+    {
+        let derived = Reactive::new(None);
+        let generation = Arc::new(AtomicUsize::new(0));
+
+        let spawn = {
+            let handle = handle.clone();
+            let derived = derived.clone();
+            move |val: T| {
+                let current = generation.fetch_add(1, Ordering::SeqCst) + 1;
+                let generation = generation.clone();
+                let derived = derived.clone();
+                let fut = f(val);
+                handle.spawn(async move {
+                    let output = fut.await;
+                    // the generation is checked under the derived value's lock,
+                    // so an outdated result can't overwrite a newer one.
+                    derived.update_if(
+                        |_| generation.load(Ordering::SeqCst) == current,
+                        |_| Some(output),
+                    );
+                })
+            }
+        };
+
+        self.observe_with_current(|val, observers| {
+            let mut running = spawn(val.clone());
+            observers.add(move |val| {
+                running.abort();
+                running = spawn(val.clone());
+            });
+        });
+
+        derived
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{sync::lock_std, SyncReactive};
+use std::{
+    fmt::Debug,
+    ops::Deref,
+    sync::{Mutex, OnceLock},
+};
+
+/// A [`SyncReactive`] that can be built in a `const` context, so it can be a `static`
+/// without a lazy wrapper. Returned by [`SyncReactive::const_new`]
+/// (`Reactive::const_new` with the `threadsafe` feature).
+///
+/// A reactive lives in its own `Arc` allocation, which can't be made in a `const` context,
+/// so the initial value is kept aside and the reactive is allocated on first access.
+/// It derefs to that reactive, so it has the same methods.
+///
+/// There is no `Rc` based flavor: a `static` has to be `Sync`.
+///
+/// # Examples
+/// ```
+/// use reactivate::{StaticReactive, SyncReactive};
+///
+/// static APP_STATE: StaticReactive<Vec<String>> = SyncReactive::const_new(Vec::new());
+///
+/// let count = APP_STATE.derive(|logs| logs.len());
+/// APP_STATE.update_inplace(|logs| logs.push(String::from("started")));
+///
+/// assert_eq!(1, count.value());
+/// ```
+pub struct StaticReactive<T> {
+    /// taken out by the first access
+    initial: Mutex<Option<T>>,
+    reactive: OnceLock<SyncReactive<T>>,
+}
+
+impl<T> StaticReactive<T> {
+    /// Constructs a new `StaticReactive<T>`. (same as [`SyncReactive::const_new`])
+    pub const fn new(value: T) -> Self {
+        Self {
+            initial: Mutex::new(Some(value)),
+            reactive: OnceLock::new(),
+        }
+    }
+
+    /// Returns the reactive, allocating it on first access
+    pub fn get(&self) -> &SyncReactive<T> {
+        self.reactive.get_or_init(|| {
+            let value = lock_std(&self.initial)
+                .take()
+                .expect("the initial value is only taken once");
+            SyncReactive::new(value)
+        })
+    }
+}
+
+impl<T> Deref for StaticReactive<T> {
+    type Target = SyncReactive<T>;
+
+    fn deref(&self) -> &SyncReactive<T> {
+        self.get()
+    }
+}
+
+impl<T: Debug> Debug for StaticReactive<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+impl<T> SyncReactive<T> {
+    /// `const` constructor, for `static` reactives. (see [`StaticReactive`])
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{StaticReactive, SyncReactive};
+    ///
+    /// static COUNTER: StaticReactive<u32> = SyncReactive::const_new(0);
+    ///
+    /// std::thread::spawn(|| COUNTER.update(|n| n + 1)).join().unwrap();
+    /// assert_eq!(1, COUNTER.value());
+    /// ```
+    pub const fn const_new(value: T) -> StaticReactive<T> {
+        StaticReactive::new(value)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    inbox::{Inbox, Registration},
+    sync::Ptr,
+    Reactive,
+};
+use futures_core::Stream;
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Stream of values returned by [`Reactive::to_stream`] and friends.
+///
+/// Values that the consumer hasn't picked up yet are kept in a bounded buffer.
+/// When the buffer is full the oldest value is discarded to make room for the new one,
+/// so a slow consumer always ends up with the latest values instead of growing the queue.
+///
+/// The stream ends when every clone of the reactive is dropped.
+/// Dropping the stream unregisters its internal observer.
+#[must_use = "streams do nothing unless polled"]
+pub struct ReactiveStream<T> {
+    inbox: Ptr<Inbox<VecDeque<T>>>,
+    _registration: Registration<T>,
+}
+
+impl<T> Stream for ReactiveStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut inbox = self.inbox.lock();
+
+        if let Some(value) = inbox.buf.pop_front() {
+            return Poll::Ready(Some(value));
+        }
+
+        if inbox.is_closed() {
+            return Poll::Ready(None);
+        }
+
+        inbox.set_waker(cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a stream that yields a clone of every value the reactive
+    /// is updated to after this call.
+    ///
+    /// Only the latest value is kept if the consumer falls behind.
+    /// (use [`Reactive::to_stream_buffered`] to keep more)
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::StreamExt;
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let mut stream = r.to_stream();
+    ///
+    /// r.update(|n| n + 1);
+    /// assert_eq!(Some(11), stream.next().await);
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    /// assert_eq!(Some(13), stream.next().await);
+    /// # });
+    /// ```
+    pub fn to_stream(&self) -> ReactiveStream<T> {
+        self.stream(1, |_, _| ()).1
+    }
+
+    /// Same as [`Reactive::to_stream`] but the current value is yielded first.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::StreamExt;
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let mut stream = r.to_stream_with_current();
+    ///
+    /// assert_eq!(Some(10), stream.next().await);
+    /// # });
+    /// ```
+    pub fn to_stream_with_current(&self) -> ReactiveStream<T> {
+        self.stream(1, |current, buf| buf.push_back(current.clone()))
+            .1
+    }
+
+    /// Same as [`Reactive::to_stream`] but up to `capacity` values are buffered
+    /// for a slow consumer before the oldest ones start getting discarded.
+    ///
+    /// # Panics
+    /// if `capacity` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::StreamExt;
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let mut stream = r.to_stream_buffered(2);
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(Some(12), stream.next().await);
+    /// assert_eq!(Some(13), stream.next().await);
+    /// # });
+    /// ```
+    pub fn to_stream_buffered(&self, capacity: usize) -> ReactiveStream<T> {
+        assert!(capacity > 0, "stream buffer capacity must be non-zero");
+        self.stream(capacity, |_, _| ()).1
+    }
+
+    /// Same as [`Reactive::to_stream`] but also returns the current value,
+    /// captured under the same lock that registers the stream.
+    ///
+    /// No update can slip in between the snapshot and the first value of the stream,
+    /// so the snapshot can be used to render the initial state and the stream to follow it.
+    ///
+    /// # Examples
+    /// ```
+    /// use futures::StreamExt;
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let (snapshot, mut stream) = r.snapshot_stream();
+    ///
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(10, snapshot);
+    /// assert_eq!(Some(11), stream.next().await);
+    /// # });
+    /// ```
+    pub fn snapshot_stream(&self) -> (T, ReactiveStream<T>) {
+        self.stream(1, |current, _| current.clone())
+    }
+
+    /// Registers a stream with a buffer of `capacity` values.
+    /// `init` gets the current value and the empty buffer before anything else can change them.
+    fn stream<S>(
+        &self,
+        capacity: usize,
+        init: impl FnOnce(&T, &mut VecDeque<T>) -> S,
+    ) -> (S, ReactiveStream<T>) {
+        let (snapshot, inbox, registration) = self.observe_with_current(|current, observers| {
+            let mut buf = VecDeque::with_capacity(capacity);
+            let snapshot = init(current, &mut buf);
+
+            let inbox = Inbox::new(buf);
+            let registration = observers.add_inbox(&inbox, move |buf, val: &T| {
+                if buf.len() == capacity {
+                    buf.pop_front();
+                }
+                buf.push_back(val.clone());
+            });
+            (snapshot, inbox, registration)
+        });
+
+        let stream = ReactiveStream {
+            inbox,
+            _registration: registration,
+        };
+        (snapshot, stream)
+    }
+}
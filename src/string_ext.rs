@@ -0,0 +1,49 @@
+use crate::Reactive;
+use alloc::{string::String, vec::Vec};
+
+/// Shortcuts for the most common derives of a `Reactive<String>`.
+///
+/// Each method is exactly equivalent to the `derive` call mentioned in its docs.
+///
+/// # Examples
+/// ```
+/// use reactivate::{Reactive, ReactiveStringExt};
+///
+/// let r = Reactive::new(String::from("hello"));
+/// let len = r.len_reactive();
+///
+/// r.update_inplace(|s| s.push_str(" world"));
+///
+/// assert_eq!(11, len.value());
+/// ```
+pub trait ReactiveStringExt {
+    /// `.derive(|s| s.len())`
+    fn len_reactive(&self) -> Reactive<usize>;
+
+    /// `.derive(|s| s.is_empty())`
+    fn is_empty_reactive(&self) -> Reactive<bool>;
+
+    /// `.derive(|s| s.chars().count())`
+    fn chars_count_reactive(&self) -> Reactive<usize>;
+
+    /// `.derive(|s| s.lines().map(String::from).collect())`
+    fn lines_reactive(&self) -> Reactive<Vec<String>>;
+}
+
+impl ReactiveStringExt for Reactive<String> {
+    fn len_reactive(&self) -> Reactive<usize> {
+        self.derive(|s| s.len())
+    }
+
+    fn is_empty_reactive(&self) -> Reactive<bool> {
+        self.derive(|s| s.is_empty())
+    }
+
+    fn chars_count_reactive(&self) -> Reactive<usize> {
+        self.derive(|s| s.chars().count())
+    }
+
+    fn lines_reactive(&self) -> Reactive<Vec<String>> {
+        self.derive(|s| s.lines().map(String::from).collect())
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    inbox::{Inbox, Registration},
+    sync::Ptr,
+    Reactive,
+};
+use std::collections::VecDeque;
+
+/// Receiver of changes returned by [`Reactive::subscribe`] and friends.
+///
+/// Values are queued by an observer on the reactive until they are received.
+/// Iterating over the subscription blocks on `recv` for every item.
+///
+/// Once every clone of the reactive is dropped, the already queued values can still be received,
+/// after which `recv` returns `None` (and iteration ends).
+/// Dropping the subscription unregisters its internal observer.
+///
+/// Without the `threadsafe` feature, the subscription can't leave the thread that updates
+/// the reactive, so nothing could ever wake up a blocked `recv`.
+/// Only `try_recv` is available then (and the subscription is not an `Iterator`).
+pub struct Subscription<T> {
+    inbox: Ptr<Inbox<VecDeque<T>>>,
+    _registration: Registration<T>,
+}
+
+impl<T> Subscription<T> {
+    /// Blocks until the next value is available.
+    ///
+    /// Returns `None` once the reactive is dropped and no values are left.
+    ///
+    /// **Don't call this on the thread that updates the reactive**,
+    /// it would wait for a value that can never arrive.
+    #[cfg(feature = "threadsafe")]
+    pub fn recv(&self) -> Option<T> {
+        let mut inbox = self.inbox.lock();
+        loop {
+            if let Some(val) = inbox.buf.pop_front() {
+                return Some(val);
+            }
+            if inbox.is_closed() {
+                return None;
+            }
+            inbox = self.inbox.wait(inbox);
+        }
+    }
+
+    /// Returns the next value if one is already available, without blocking.
+    pub fn try_recv(&self) -> Option<T> {
+        self.inbox.lock().buf.pop_front()
+    }
+}
+
+#[cfg(feature = "threadsafe")]
+impl<T> Iterator for Subscription<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Returns a [`Subscription`] that receives a clone of every value
+    /// the reactive is updated to after this call.
+    ///
+    /// The queue is unbounded. If the consumer may fall behind,
+    /// use [`Reactive::subscribe_bounded`] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// let sub = r.subscribe();
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(Some(1), sub.try_recv());
+    /// assert_eq!(Some(2), sub.try_recv());
+    /// assert_eq!(None, sub.try_recv());
+    /// ```
+    pub fn subscribe(&self) -> Subscription<T> {
+        self.subscription(None, false)
+    }
+
+    /// Same as [`Reactive::subscribe`] but the current value is received first.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let sub = r.subscribe_with_current();
+    ///
+    /// assert_eq!(Some(10), sub.try_recv());
+    /// ```
+    pub fn subscribe_with_current(&self) -> Subscription<T> {
+        self.subscription(None, true)
+    }
+
+    /// Same as [`Reactive::subscribe`] but at most `capacity` values are queued.
+    /// When the queue is full the oldest value is discarded to make room for the new one,
+    /// so a slow consumer always ends up with the latest values.
+    ///
+    /// # Panics
+    /// if `capacity` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// let sub = r.subscribe_bounded(1);
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// assert_eq!(Some(2), sub.try_recv());
+    /// ```
+    pub fn subscribe_bounded(&self, capacity: usize) -> Subscription<T> {
+        assert!(capacity > 0, "subscription capacity must be non-zero");
+        self.subscription(Some(capacity), false)
+    }
+
+    fn subscription(&self, capacity: Option<usize>, with_current: bool) -> Subscription<T> {
+        let (inbox, registration) = self.observe_with_current(|current, observers| {
+            let inbox = Inbox::new(VecDeque::new());
+            if with_current {
+                inbox.lock().buf.push_back(current.clone());
+            }
+            let registration = observers.add_inbox(&inbox, move |buf, val: &T| {
+                if capacity.is_some_and(|cap| buf.len() == cap) {
+                    buf.pop_front();
+                }
+                buf.push_back(val.clone());
+            });
+            (inbox, registration)
+        });
+
+        Subscription {
+            inbox,
+            _registration: registration,
+        }
+    }
+}
+
+#[cfg(feature = "threadsafe")]
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Returns a blocking iterator over the values the reactive is updated to after this call.
+    ///
+    /// `next()` waits for the next change and ends once every clone of the reactive
+    /// has been dropped (the iterator itself doesn't keep the reactive alive).
+    /// Same as iterating over [`Reactive::subscribe`].
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// let changes = r.iter_changes();
+    ///
+    /// let consumer = std::thread::spawn(move || {
+    ///     for val in changes {
+    ///         println!("changed to {}", val);
+    ///     }
+    /// });
+    ///
+    /// r.update(|n| n + 1);
+    /// drop(r); // ends the loop
+    ///
+    /// consumer.join().unwrap();
+    /// ```
+    pub fn iter_changes(&self) -> impl Iterator<Item = T> {
+        self.subscribe()
+    }
+}
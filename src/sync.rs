@@ -0,0 +1,144 @@
+//! Shared-ownership primitives that switch between `Rc<RefCell<_>>`
+//! and `Arc<Mutex<_>>` depending on the `threadsafe` feature.
+
+#[cfg(not(feature = "threadsafe"))]
+pub(crate) type Ptr<T> = alloc::rc::Rc<T>;
+
+#[cfg(not(feature = "threadsafe"))]
+pub(crate) type Lock<T> = core::cell::RefCell<T>;
+
+#[cfg(feature = "threadsafe")]
+pub(crate) type Ptr<T> = std::sync::Arc<T>;
+
+#[cfg(feature = "threadsafe")]
+pub(crate) type WeakPtr<T> = std::sync::Weak<T>;
+
+#[cfg(all(feature = "threadsafe", not(loom)))]
+pub(crate) type Lock<T> = std::sync::Mutex<T>;
+
+// `loom` (`RUSTFLAGS="--cfg loom"`) swaps the mutexes for its own model checked ones.
+// its `Arc` doesn't support weak references, so `Ptr` stays the std one.
+#[cfg(all(feature = "threadsafe", loom))]
+pub(crate) type Lock<T> = loom::sync::Mutex<T>;
+
+#[cfg(not(feature = "threadsafe"))]
+pub(crate) type Guard<'a, T> = core::cell::RefMut<'a, T>;
+
+#[cfg(all(feature = "threadsafe", not(loom)))]
+pub(crate) type Guard<'a, T> = std::sync::MutexGuard<'a, T>;
+
+#[cfg(all(feature = "threadsafe", loom))]
+pub(crate) type Guard<'a, T> = loom::sync::MutexGuard<'a, T>;
+
+#[cfg(all(feature = "threadsafe", not(loom)))]
+pub(crate) type Condvar = std::sync::Condvar;
+
+#[cfg(all(feature = "threadsafe", loom))]
+pub(crate) type Condvar = loom::sync::Condvar;
+
+pub(crate) type Shared<T> = Ptr<Lock<T>>;
+
+#[inline]
+pub(crate) fn shared<T>(val: T) -> Shared<T> {
+    Shared::new(val.into())
+}
+
+#[inline]
+#[cfg(not(feature = "threadsafe"))]
+pub(crate) fn lock<T>(shared: &Lock<T>) -> Guard<'_, T> {
+    shared.borrow_mut()
+}
+
+#[inline]
+#[cfg(feature = "threadsafe")]
+pub(crate) fn lock<T>(shared: &Lock<T>) -> Guard<'_, T> {
+    shared
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Same as `Condvar::wait` but poisoning is ignored, just like in `lock`
+#[inline]
+#[cfg(feature = "threadsafe")]
+pub(crate) fn wait<'a, T>(condvar: &Condvar, guard: Guard<'a, T>) -> Guard<'a, T> {
+    condvar
+        .wait(guard)
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Same as `Condvar::wait_timeout` but poisoning is ignored, just like in `lock`.
+/// (whether it timed out is left to the caller to check)
+#[inline]
+#[cfg(feature = "threadsafe")]
+pub(crate) fn wait_timeout<'a, T>(
+    condvar: &Condvar,
+    guard: Guard<'a, T>,
+    timeout: std::time::Duration,
+) -> Guard<'a, T> {
+    condvar
+        .wait_timeout(guard, timeout)
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .0
+}
+
+/// Same as `lock` but gives up (returning `None`) once `deadline` has passed.
+///
+/// `std` mutexes can't wait with a timeout, so this polls `try_lock`,
+/// backing off from spinning to short sleeps while the lock stays contended.
+#[cfg(all(feature = "threadsafe", not(loom)))]
+pub(crate) fn lock_until<T>(
+    mutex: &std::sync::Mutex<T>,
+    deadline: std::time::Instant,
+) -> Option<std::sync::MutexGuard<'_, T>> {
+    use std::sync::TryLockError;
+
+    let mut attempts = 0u32;
+    loop {
+        match mutex.try_lock() {
+            Ok(guard) => return Some(guard),
+            Err(TryLockError::Poisoned(err)) => return Some(err.into_inner()),
+            Err(TryLockError::WouldBlock) => {}
+        }
+
+        let now = std::time::Instant::now();
+        if now >= deadline {
+            return None;
+        }
+
+        attempts += 1;
+        match attempts {
+            0..=16 => std::hint::spin_loop(),
+            17..=64 => std::thread::yield_now(),
+            _ => std::thread::sleep((deadline - now).min(std::time::Duration::from_micros(500))),
+        }
+    }
+}
+
+/// Same as `lock` but for the `std` mutexes that have to be thread safe in every build
+/// (the ones of `RwReactive`, `ReactiveCell`, user provided `Arc<Mutex<_>>` sinks, ...),
+/// which can't use `Lock`.
+#[inline]
+#[cfg(feature = "std")]
+pub(crate) fn lock_std<T>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Read lock on a `RwLock`, ignoring poisoning just like `lock`
+#[inline]
+#[cfg(feature = "rwlock")]
+pub(crate) fn read<T>(rwlock: &std::sync::RwLock<T>) -> std::sync::RwLockReadGuard<'_, T> {
+    rwlock
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Write lock on a `RwLock`, ignoring poisoning just like `lock`
+#[inline]
+#[cfg(feature = "rwlock")]
+pub(crate) fn write<T>(rwlock: &std::sync::RwLock<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+    rwlock
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
@@ -0,0 +1,97 @@
+use crate::{backend::ThreadSafe, GenericReactive};
+
+/// Thread safe flavor of [`crate::Reactive`] that is always backed by `Arc<Mutex<_>>`,
+/// **regardless of the `threadsafe` feature**. (the counterpart of [`crate::LocalReactive`])
+///
+/// Lets a program that doesn't enable `threadsafe` (so that its UI layer keeps cheap `Rc` based
+/// reactives) still share some reactives with its worker threads. Its observers must be `Send`.
+/// `SyncReactive`s are merged with [`crate::MergeSync`], in that build too.
+///
+/// It is the same [`GenericReactive`] as the other flavors, so it has the same methods,
+/// plus the ones handling poisoned locks (`is_poisoned`, `recover`, the `try_*` methods)
+/// and `set_executor`. With the `threadsafe` feature, `Reactive` is a `SyncReactive`.
+///
+/// The observers are locked before the value, and the observers of `set`, `update`,
+/// `update_unchecked` and `notify` are called with a clone of the new value after the value is released,
+/// so they can read the reactive they observe. The in-place updates call them while the value is still locked.
+///
+/// Observers can also update the reactive they observe: the notification of such an update
+/// is deferred until the current one is done.
+///
+/// # Panics
+/// if the observers keep updating the reactive they observe forever, instead of hanging.
+///
+/// # Examples
+/// ```
+/// use reactivate::SyncReactive;
+///
+/// let progress = SyncReactive::new(0);
+/// let percent = progress.derive(|done| done * 10);
+///
+/// std::thread::spawn({
+///     let progress = progress.clone();
+///     move || progress.update(|done| done + 1)
+/// })
+/// .join()
+/// .unwrap();
+///
+/// assert_eq!(1, progress.value());
+/// assert_eq!(10, percent.value());
+/// ```
+pub type SyncReactive<T> = GenericReactive<T, ThreadSafe>;
+
+impl<T> SyncReactive<T> {
+    /// Same as [`GenericReactive::derive`]. Lets `Reactive::derive_sync` be written
+    /// the same way in every `std` build.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(10);
+    /// let d = r.derive_sync(|val| val + 5);
+    ///
+    /// r.update(|val| val * 2);
+    ///
+    /// let seen = std::thread::spawn(move || d.value()).join().unwrap();
+    /// assert_eq!(25, seen);
+    /// ```
+    pub fn derive_sync<U: Clone + PartialEq + Send + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + 'static,
+    ) -> SyncReactive<U>
+    where
+        T: Clone,
+    {
+        self.derive(f)
+    }
+
+    /// Same as [`GenericReactive::derive`]. Lets `SyncReactive::derive_reactive` be written
+    /// the same way as `LocalReactive::derive_reactive`.
+    ///
+    /// Only available with the `threadsafe` feature, since the observers of
+    /// a `SyncReactive` must be `Send` and an `Rc` based `Reactive` is not.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Merge, Reactive, SyncReactive};
+    ///
+    /// let jobs = SyncReactive::new(3);
+    /// let failed = Reactive::new(1);
+    ///
+    /// let summary = (&jobs.derive_reactive(|n| *n), &failed).merge();
+    /// jobs.update(|n| n + 1);
+    ///
+    /// assert_eq!((4, 1), summary.value());
+    /// ```
+    #[cfg(feature = "threadsafe")]
+    pub fn derive_reactive<U: Clone + PartialEq + Send + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + 'static,
+    ) -> SyncReactive<U>
+    where
+        T: Clone,
+    {
+        self.derive(f)
+    }
+}
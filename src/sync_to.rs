@@ -0,0 +1,65 @@
+use crate::{observers::ObserverHandle, Reactive};
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: 'static,
+        #[cfg(feature = "threadsafe")] T: Send + 'static,
+    > Reactive<T>
+{
+    /// Pushes every future value of this reactive into `target` (one way, using `set`).
+    ///
+    /// The current value is **not** copied over, call `target.set(self.value())` first
+    /// if both should start out the same.
+    ///
+    /// Returns a handle that can be used to stop the syncing.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let source = Reactive::new(10);
+    /// let target = Reactive::new(0);
+    ///
+    /// let handle = source.sync_to(&target);
+    ///
+    /// source.update(|n| n + 1);
+    /// assert_eq!(11, target.value());
+    ///
+    /// handle.remove();
+    /// source.update(|n| n + 1);
+    /// assert_eq!(11, target.value());
+    /// ```
+    pub fn sync_to(&self, target: &Reactive<T>) -> ObserverHandle<T>
+    where
+        T: Clone,
+    {
+        let target = target.clone();
+        self.add_removable_observer(move |val| target.set(val.clone()))
+    }
+
+    /// Same as [`Reactive::sync_to`] but every value is mapped with `f` before being pushed into `target`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let celsius = Reactive::new(0.0);
+    /// let fahrenheit = Reactive::new(32.0);
+    ///
+    /// celsius.sync_derived_to(&fahrenheit, |c| c * 9.0 / 5.0 + 32.0);
+    ///
+    /// celsius.update(|_| 100.0);
+    /// assert_eq!(212.0, fahrenheit.value());
+    /// ```
+    pub fn sync_derived_to<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + Send + 'static,
+    >(
+        &self,
+        target: &Reactive<U>,
+        #[cfg(not(feature = "threadsafe"))] f: impl Fn(&T) -> U + 'static,
+        #[cfg(feature = "threadsafe")] f: impl Fn(&T) -> U + Send + 'static,
+    ) -> ObserverHandle<T> {
+        let target = target.clone();
+        self.add_removable_observer(move |val| target.set(f(val)))
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{observers::ObserverHandle, Reactive};
+use std::{
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+
+/// Handle to the worker thread spawned by [`Reactive::add_threaded_observer`].
+///
+/// Dropping it removes the observer: no new values are sent to the worker,
+/// the ones already sent are still handed to `f` and then the thread exits on its own.
+/// (use [`ObserverThread::join`] to wait for that, or [`ObserverThread::detach`]
+/// to keep the observer around)
+#[must_use = "dropping the handle immediately removes the observer"]
+pub struct ObserverThread<T> {
+    observer: Option<ObserverHandle<T>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl<T> ObserverThread<T> {
+    /// Removes the observer and blocks until the worker has handled every value
+    /// sent to it so far.
+    ///
+    /// Returns `Err` with the panic payload if the observer function panicked.
+    pub fn join(mut self) -> thread::Result<()> {
+        self.stop();
+        match self.worker.take() {
+            Some(worker) => worker.join(),
+            None => Ok(()),
+        }
+    }
+
+    /// Keeps the observer (and its worker) running for as long as the reactive lives,
+    /// even after this handle is dropped.
+    pub fn detach(mut self) {
+        self.observer.take();
+    }
+
+    fn stop(&mut self) {
+        if let Some(observer) = self.observer.take() {
+            observer.remove();
+        }
+    }
+}
+
+impl<T> Drop for ObserverThread<T> {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Adds an observer that runs on its own worker thread, so a slow observer
+    /// never holds up the thread doing the `update`.
+    ///
+    /// Every change sends a clone of the new value to the worker over a channel.
+    /// The values are handed to `f` one at a time, in the order the changes happened.
+    /// The channel is unbounded, so a worker that can't keep up makes the queue grow.
+    ///
+    /// If `f` panics, the worker is gone and the observer removes itself on the next change.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    ///
+    /// let worker = r.add_threaded_observer(|val| {
+    ///     println!("uploading {}", val);
+    /// });
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// // wait for both uploads to finish
+    /// worker.join().unwrap();
+    /// ```
+    pub fn add_threaded_observer(
+        &self,
+        mut f: impl FnMut(T) + Send + 'static,
+    ) -> ObserverThread<T> {
+        let (tx, rx) = mpsc::channel::<T>();
+
+        // ends once the sender (owned by the observer) is dropped and the queue is drained
+        let worker = thread::spawn(move || {
+            for val in rx {
+                f(val);
+            }
+        });
+
+        let observer = self.add_retaining_observer(move |val| tx.send(val.clone()).is_ok());
+
+        ObserverThread {
+            observer: Some(observer),
+            worker: Some(worker),
+        }
+    }
+}
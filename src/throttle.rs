@@ -0,0 +1,208 @@
+#[cfg(feature = "threadsafe")]
+use crate::sync::WeakPtr;
+use crate::{
+    sync::{lock, Guard, Lock, Ptr},
+    Reactive,
+};
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+#[cfg(not(feature = "threadsafe"))]
+type Mutation<T> = Box<dyn FnOnce(&mut T)>;
+
+#[cfg(feature = "threadsafe")]
+type Mutation<T> = Box<dyn FnOnce(&mut T) + Send>;
+
+struct State<T> {
+    last_applied: Option<Instant>,
+    pending: Option<Mutation<T>>,
+    /// deadlines for the timer thread, which is started the first time a mutation is held back
+    /// and exits once the wrapper is dropped (it only keeps a weak reference to it)
+    #[cfg(feature = "threadsafe")]
+    timer: Option<std::sync::mpsc::Sender<Instant>>,
+    #[cfg(feature = "threadsafe")]
+    timer_scheduled: bool,
+}
+
+/// Everything the clones of a [`ThrottledReactive`] share
+struct Throttle<T> {
+    reactive: Reactive<T>,
+    min_interval: Duration,
+    state: Lock<State<T>>,
+}
+
+/// Wrapper returned by [`Reactive::throttle_inplace`] that applies
+/// in-place mutations to the original reactive at most once per `min_interval`.
+///
+/// A mutation that arrives within the interval is held back and replaced by
+/// any mutation that arrives after it, so only the most recent one gets applied.
+///
+/// With the `threadsafe` feature, the held back mutation is applied by a timer thread
+/// as soon as the interval elapses.
+///
+/// **Without `threadsafe` there are no threads to rely on**: the held back mutation is only applied
+/// by [`ThrottledReactive::flush`] or by the first `update_inplace` call made after the interval
+/// (which then gets held back in its place). As long as neither happens, the reactive
+/// doesn't see the held back mutation, so call `flush` regularly (eg: once per frame).
+pub struct ThrottledReactive<T> {
+    inner: Ptr<Throttle<T>>,
+}
+
+impl<T> Clone for ThrottledReactive<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Reactive<T> {
+    /// Returns a wrapper around this reactive whose `update_inplace`
+    /// is rate-limited to at most once per `min_interval`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::time::Duration;
+    ///
+    /// let r = Reactive::new(vec![1, 2, 3]);
+    /// let throttled = r.throttle_inplace(Duration::from_secs(60));
+    ///
+    /// throttled.update_inplace(|v| v.push(4)); // applied right away
+    /// throttled.update_inplace(|v| v.push(5)); // held back
+    /// throttled.update_inplace(|v| v.push(6)); // replaces the held back one
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4], r.value());
+    ///
+    /// throttled.flush();
+    ///
+    /// assert_eq!(vec![1, 2, 3, 4, 6], r.value());
+    /// ```
+    pub fn throttle_inplace(&self, min_interval: Duration) -> ThrottledReactive<T> {
+        ThrottledReactive {
+            inner: Ptr::new(Throttle {
+                reactive: self.clone(),
+                min_interval,
+                state: Lock::new(State {
+                    last_applied: None,
+                    pending: None,
+                    #[cfg(feature = "threadsafe")]
+                    timer: None,
+                    #[cfg(feature = "threadsafe")]
+                    timer_scheduled: false,
+                }),
+            }),
+        }
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Hash + 'static,
+        #[cfg(feature = "threadsafe")] T: Hash + Send + 'static,
+    > ThrottledReactive<T>
+{
+    /// Same as [`Reactive::update_inplace`] but rate-limited.
+    /// (see [`ThrottledReactive`])
+    pub fn update_inplace(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] f: impl FnOnce(&mut T) + 'static,
+        #[cfg(feature = "threadsafe")] f: impl FnOnce(&mut T) + Send + 'static,
+    ) {
+        let now = Instant::now();
+        let throttle = &self.inner;
+        let mut state = lock(&throttle.state);
+
+        // the held back mutation is due (its timer is late, or there is no timer at all)
+        if throttle.is_due(&state, now) {
+            throttle.apply_pending(state);
+            state = lock(&throttle.state);
+        }
+
+        match state.last_applied {
+            Some(last) if now.duration_since(last) < throttle.min_interval => {
+                state.pending = Some(Box::new(f));
+
+                #[cfg(feature = "threadsafe")]
+                if !state.timer_scheduled {
+                    state.timer_scheduled = true;
+                    let deadline = last + throttle.min_interval;
+                    let timer = state
+                        .timer
+                        .get_or_insert_with(|| Throttle::start_timer(Ptr::downgrade(throttle)));
+                    // the timer thread only exits once the wrapper (and with it this sender) is dropped
+                    let _ = timer.send(deadline);
+                }
+            }
+            _ => {
+                state.last_applied = Some(now);
+                drop(state);
+                throttle.reactive.update_inplace(f);
+            }
+        }
+    }
+
+    /// Immediately applies the held back mutation (if any), ignoring the interval.
+    pub fn flush(&self) {
+        self.inner.apply_pending(lock(&self.inner.state));
+    }
+
+    /// Returns the underlying (non throttled) reactive
+    pub fn reactive(&self) -> &Reactive<T> {
+        &self.inner.reactive
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Hash + 'static,
+        #[cfg(feature = "threadsafe")] T: Hash + Send + 'static,
+    > Throttle<T>
+{
+    /// `true` if a mutation is held back and the interval since the last applied one has passed
+    fn is_due(&self, state: &State<T>, now: Instant) -> bool {
+        state.pending.is_some()
+            && state
+                .last_applied
+                .is_some_and(|last| now.duration_since(last) >= self.min_interval)
+    }
+
+    /// Applies the held back mutation (if any) and cancels its timer
+    fn apply_pending(&self, mut state: Guard<'_, State<T>>) {
+        #[cfg(feature = "threadsafe")]
+        {
+            state.timer_scheduled = false;
+        }
+
+        if let Some(f) = state.pending.take() {
+            state.last_applied = Some(Instant::now());
+            drop(state);
+            self.reactive.update_inplace(f);
+        }
+    }
+
+    /// Spawns the thread that applies held back mutations once their deadline passes.
+    ///
+    /// It sleeps until each deadline it receives and then applies the held back mutation
+    /// if it is still due (`flush` or a later `update_inplace` may have applied it already).
+    #[cfg(feature = "threadsafe")]
+    fn start_timer(throttle: WeakPtr<Self>) -> std::sync::mpsc::Sender<Instant> {
+        let (sender, deadlines) = std::sync::mpsc::channel::<Instant>();
+
+        std::thread::spawn(move || {
+            for deadline in deadlines {
+                std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+
+                let Some(throttle) = throttle.upgrade() else {
+                    break;
+                };
+                let state = lock(&throttle.state);
+                if throttle.is_due(&state, Instant::now()) {
+                    throttle.apply_pending(state);
+                }
+            }
+        });
+
+        sender
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    sync::{lock, shared, Ptr, Shared},
+    Reactive,
+};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// Handle returned by [`Reactive::record_timed_history`] to read the recorded changes.
+///
+/// Clones of the handle share the same history.
+/// Once every clone is dropped, the internal observer removes itself from the reactive.
+pub struct TimedHistoryHandle<T> {
+    entries: Shared<VecDeque<(Instant, T)>>,
+}
+
+impl<T> Clone for TimedHistoryHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<T: Clone> TimedHistoryHandle<T> {
+    /// Returns a copy of the recorded `(time of change, new value)` pairs, oldest first.
+    pub fn entries(&self) -> Vec<(Instant, T)> {
+        lock(&self.entries).iter().cloned().collect()
+    }
+}
+
+impl<T> TimedHistoryHandle<T> {
+    /// Returns the time elapsed between each pair of consecutive recorded changes, oldest first.
+    /// (one less than the number of entries)
+    pub fn intervals(&self) -> Vec<Duration> {
+        let entries = lock(&self.entries);
+        entries
+            .iter()
+            .zip(entries.iter().skip(1))
+            .map(|((prev, _), (next, _))| next.duration_since(*prev))
+            .collect()
+    }
+
+    /// Returns the number of recorded changes
+    pub fn len(&self) -> usize {
+        lock(&self.entries).len()
+    }
+
+    /// Returns `true` if no change was recorded (yet)
+    pub fn is_empty(&self) -> bool {
+        lock(&self.entries).is_empty()
+    }
+
+    /// Forgets every recorded change. (recording goes on)
+    pub fn clear(&self) {
+        lock(&self.entries).clear();
+    }
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Records the time and a clone of the new value of every change after this call,
+    /// keeping only the latest `capacity` of them. (the oldest ones are evicted)
+    ///
+    /// Meant for diagnostics, like finding out how often and when a reactive changes.
+    ///
+    /// # Panics
+    /// if `capacity` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    /// let history = r.record_timed_history(2);
+    ///
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    /// r.update(|n| n + 1);
+    ///
+    /// let values: Vec<_> = history.entries().into_iter().map(|(_, val)| val).collect();
+    /// assert_eq!(vec![2, 3], values);
+    /// assert_eq!(1, history.intervals().len());
+    /// ```
+    pub fn record_timed_history(&self, capacity: usize) -> TimedHistoryHandle<T> {
+        assert!(capacity > 0, "history capacity must be non-zero");
+
+        let entries = shared(VecDeque::with_capacity(capacity));
+        let weak = Ptr::downgrade(&entries);
+
+        self.add_retaining_observer(move |val| {
+            let Some(entries) = weak.upgrade() else {
+                return false;
+            };
+
+            let mut entries = lock(&entries);
+            if entries.len() == capacity {
+                entries.pop_front();
+            }
+            entries.push_back((Instant::now(), val.clone()));
+            true
+        });
+
+        TimedHistoryHandle { entries }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Reactive, ReactiveError};
+use std::time::{Duration, Instant};
+
+impl<T> Reactive<T> {
+    /// Same as `value` but returns `Err(ReactiveError::Timeout)` if the lock on the value
+    /// can't be acquired within `timeout` (for example because an observer of an in-place update is stuck).
+    ///
+    /// `std` mutexes can't wait with a timeout, so the lock is polled until it is free
+    /// or the time is up.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::time::Duration;
+    ///
+    /// let r = Reactive::new(10);
+    /// assert_eq!(Ok(10), r.value_timeout(Duration::from_millis(10)));
+    /// ```
+    pub fn value_timeout(&self, timeout: Duration) -> Result<T, ReactiveError>
+    where
+        T: Clone,
+    {
+        let deadline = Instant::now() + timeout;
+        let guard = self.acq_val_until(deadline).ok_or(ReactiveError::Timeout)?;
+        Ok(guard.clone())
+    }
+
+    /// Same as `set` but returns `Err(ReactiveError::Timeout)` (without changing the value)
+    /// if the locks on the observers and the value can't both be acquired within `timeout`.
+    pub fn set_timeout(&self, timeout: Duration, val: T) -> Result<(), ReactiveError>
+    where
+        T: Clone,
+    {
+        if self.rejects_change() {
+            return Ok(());
+        }
+
+        let deadline = Instant::now() + timeout;
+        let observers = self.acq_obs_until(deadline).ok_or(ReactiveError::Timeout)?;
+        let mut guard = self.acq_val_until(deadline).ok_or(ReactiveError::Timeout)?;
+
+        *guard = val;
+        self.enforce_invariant(&mut guard);
+        self.notify_unlocked(Some(observers), guard);
+
+        Ok(())
+    }
+
+    /// Same as `update` but returns `Err(ReactiveError::Timeout)` (without applying `f`)
+    /// if the locks on the observers and the value can't both be acquired within `timeout`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Reactive, ReactiveError};
+    /// use std::time::Duration;
+    ///
+    /// let r = Reactive::new(10);
+    /// let guard = r.borrowed();
+    ///
+    /// let result = std::thread::scope(|s| {
+    ///     s.spawn(|| r.update_timeout(Duration::from_millis(10), |n| n + 1))
+    ///         .join()
+    ///         .unwrap()
+    /// });
+    ///
+    /// assert_eq!(Err(ReactiveError::Timeout), result);
+    /// drop(guard);
+    /// ```
+    pub fn update_timeout(
+        &self,
+        timeout: Duration,
+        f: impl FnOnce(&T) -> T,
+    ) -> Result<(), ReactiveError>
+    where
+        T: Clone + PartialEq,
+    {
+        if self.rejects_change() {
+            return Ok(());
+        }
+
+        let deadline = Instant::now() + timeout;
+        let observers = self.acq_obs_until(deadline).ok_or(ReactiveError::Timeout)?;
+        let mut guard = self.acq_val_until(deadline).ok_or(ReactiveError::Timeout)?;
+
+        let mut new_val = f(&guard);
+        self.enforce_invariant(&mut new_val);
+        if new_val != *guard {
+            *guard = new_val;
+            self.notify_unlocked(Some(observers), guard);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::Reactive;
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Shorthand for `self.derive(|val| val.clone().into())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r: Reactive<u8> = Reactive::new(10);
+    /// let wide: Reactive<u64> = r.transform();
+    ///
+    /// r.update(|n| n * 2);
+    ///
+    /// assert_eq!(20, wide.value());
+    /// ```
+    pub fn transform<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+    >(
+        &self,
+    ) -> Reactive<U>
+    where
+        T: Into<U>,
+    {
+        self.derive(|val| val.clone().into())
+    }
+
+    /// Shorthand for `self.derive(|val| val.clone().try_into())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::num::TryFromIntError;
+    ///
+    /// let r: Reactive<i64> = Reactive::new(10);
+    /// let narrow: Reactive<Result<u8, TryFromIntError>> = r.try_transform();
+    ///
+    /// assert_eq!(Ok(10), narrow.value());
+    ///
+    /// r.update(|_| -1);
+    ///
+    /// assert!(narrow.value().is_err());
+    /// ```
+    pub fn try_transform<
+        #[cfg(not(feature = "threadsafe"))] U: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] U: Clone + PartialEq + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] E: Clone + PartialEq + 'static,
+        #[cfg(feature = "threadsafe")] E: Clone + PartialEq + Send + 'static,
+    >(
+        &self,
+    ) -> Reactive<Result<U, E>>
+    where
+        T: TryInto<U, Error = E>,
+    {
+        self.derive(|val| val.clone().try_into())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{observers::ObserverHandle, Reactive};
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > Reactive<T>
+{
+    /// Adds a new observer that is only called when `pred(old, new)` returns `true`
+    /// for the transition from the previous value to the new one.
+    ///
+    /// The canonical use is edge detection, e.g. only reacting to `false -> true`.
+    /// Every observer keeps its own clone of the previous value.
+    ///
+    /// Returns a handle that can be used to remove the observer later on.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let connected = Reactive::new(false);
+    ///
+    /// connected.add_observer_on(
+    ///     |old, new| !old && *new,
+    ///     |_| println!("connected!"),
+    /// );
+    ///
+    /// connected.update(|_| true); // connected!
+    /// connected.update(|_| false); // nothing is printed
+    /// ```
+    pub fn add_observer_on(
+        &self,
+        #[cfg(not(feature = "threadsafe"))] pred: impl Fn(&T, &T) -> bool + 'static,
+        #[cfg(feature = "threadsafe")] pred: impl Fn(&T, &T) -> bool + Send + 'static,
+        #[cfg(not(feature = "threadsafe"))] mut f: impl FnMut(&T) + 'static,
+        #[cfg(feature = "threadsafe")] mut f: impl FnMut(&T) + Send + 'static,
+    ) -> ObserverHandle<T> {
+        self.observe_with_current(|val, observers| {
+            let mut prev = val.clone();
+            observers.add(move |val| {
+                if pred(&prev, val) {
+                    f(val);
+                }
+                prev = val.clone();
+            })
+        })
+    }
+}
@@ -0,0 +1,101 @@
+use crate::Reactive;
+use std::{fmt::Display, future::Future};
+
+/// Error returned by [`Reactive::try_update_async`] when the value inside the reactive
+/// was changed by someone else while the async computation was running.
+///
+/// Holds the computed value that was **not** written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict<T>(pub T);
+
+impl<T> Display for Conflict<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value changed while the async update was in progress")
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for Conflict<T> {}
+
+impl<T: Clone + PartialEq> Reactive<T> {
+    /// Update the value inside the reactive using an async function.
+    ///
+    /// `f` receives a clone of the current value and the lock is **not** held
+    /// while its future runs. The result is then written back just like `update`
+    /// (observers are only notified if the value changes).
+    ///
+    /// If the value was changed by someone else in the meantime, it is overwritten
+    /// (last write wins). Use [`Reactive::try_update_async`] to detect that instead.
+    ///
+    /// # Locking
+    /// No lock is ever held across an `.await`. The value is cloned and the lock released
+    /// **before** `f` is called, and it is only taken again (by a regular `update`) once the
+    /// future has completed. So while the future is pending, other tasks and threads
+    /// (and the future itself) can freely read and update the reactive.
+    /// Holding the lock across the await instead would block (or, for the non threadsafe build,
+    /// panic) any access from the same thread until the future resolves, and could deadlock
+    /// an executor whose other tasks need the reactive to make progress.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let d = r.derive(|val| val + 5);
+    ///
+    /// r.update_async(|val| async move { val * 2 }).await;
+    ///
+    /// assert_eq!(25, d.value());
+    /// # });
+    /// ```
+    pub async fn update_async<F>(&self, f: impl FnOnce(T) -> F)
+    where
+        F: Future<Output = T>,
+    {
+        let new_val = f(self.value()).await;
+        self.update(|_| new_val);
+    }
+
+    /// Same as [`Reactive::update_async`] but the result is only written back
+    /// if the value inside the reactive is still equal to the one `f` started with.
+    /// Otherwise nothing is written and the computed value is returned in `Err(Conflict(..))`
+    ///
+    /// The comparison is done by value (`PartialEq`), so a value that was changed and
+    /// then changed back to the original is not considered a conflict.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Conflict, Reactive};
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    ///
+    /// let result = r
+    ///     .try_update_async(|val| {
+    ///         r.update(|_| 100); // someone else changes the value meanwhile
+    ///         async move { val * 2 }
+    ///     })
+    ///     .await;
+    ///
+    /// assert_eq!(Err(Conflict(20)), result);
+    /// assert_eq!(100, r.value());
+    /// # });
+    /// ```
+    pub async fn try_update_async<F>(&self, f: impl FnOnce(T) -> F) -> Result<(), Conflict<T>>
+    where
+        F: Future<Output = T>,
+    {
+        let snapshot = self.value();
+        let mut new_val = Some(f(snapshot.clone()).await);
+
+        let applied = self.update_if(
+            |curr| curr == &snapshot,
+            |_| new_val.take().expect("update is applied at most once"),
+        );
+
+        match new_val {
+            Some(new_val) if !applied => Err(Conflict(new_val)),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::Reactive;
+use alloc::vec::Vec;
+use core::iter::Sum;
+
+/// Shortcuts for the most common derives of a `Reactive<Vec<T>>`.
+///
+/// Each method is exactly equivalent to the `derive` call mentioned in its docs.
+///
+/// # Examples
+/// ```
+/// use reactivate::{Reactive, ReactiveVecExt};
+///
+/// let r = Reactive::new(vec![1, 2, 3]);
+/// let sum = r.sum_reactive();
+/// let last = r.last_reactive();
+///
+/// r.update_inplace(|nums| nums.push(4));
+///
+/// assert_eq!(10, sum.value());
+/// assert_eq!(Some(4), last.value());
+/// ```
+pub trait ReactiveVecExt<T> {
+    /// `.derive(|v| v.len())`
+    fn len_reactive(&self) -> Reactive<usize>;
+
+    /// `.derive(|v| v.is_empty())`
+    fn is_empty_reactive(&self) -> Reactive<bool>;
+
+    /// `.derive(|v| v.first().cloned())`
+    fn first_reactive(&self) -> Reactive<Option<T>>
+    where
+        T: PartialEq;
+
+    /// `.derive(|v| v.last().cloned())`
+    fn last_reactive(&self) -> Reactive<Option<T>>
+    where
+        T: PartialEq;
+
+    /// `.derive(move |v| v.get(index).cloned())`
+    ///
+    /// Tracks the element at `index`: it holds `Some(elem)` while the vec is long enough
+    /// and `None` whenever the vec is shorter than `index + 1`.
+    /// Observers are only notified when the selected element itself changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::{Reactive, ReactiveVecExt};
+    ///
+    /// let r = Reactive::new(vec![10, 20]);
+    /// let second = r.derive_index(1);
+    ///
+    /// assert_eq!(Some(20), second.value());
+    ///
+    /// r.update(|_| vec![10]);
+    /// assert_eq!(None, second.value());
+    /// ```
+    fn derive_index(&self, index: usize) -> Reactive<Option<T>>
+    where
+        T: PartialEq;
+
+    /// `.derive(|v| v.iter().cloned().sum())`
+    fn sum_reactive(&self) -> Reactive<T>
+    where
+        T: Sum + PartialEq;
+}
+
+impl<
+        #[cfg(not(feature = "threadsafe"))] T: Clone + 'static,
+        #[cfg(feature = "threadsafe")] T: Clone + Send + 'static,
+    > ReactiveVecExt<T> for Reactive<Vec<T>>
+{
+    fn len_reactive(&self) -> Reactive<usize> {
+        self.derive(|v| v.len())
+    }
+
+    fn is_empty_reactive(&self) -> Reactive<bool> {
+        self.derive(|v| v.is_empty())
+    }
+
+    fn first_reactive(&self) -> Reactive<Option<T>>
+    where
+        T: PartialEq,
+    {
+        self.derive(|v| v.first().cloned())
+    }
+
+    fn last_reactive(&self) -> Reactive<Option<T>>
+    where
+        T: PartialEq,
+    {
+        self.derive(|v| v.last().cloned())
+    }
+
+    fn derive_index(&self, index: usize) -> Reactive<Option<T>>
+    where
+        T: PartialEq,
+    {
+        self.derive(move |v| v.get(index).cloned())
+    }
+
+    fn sum_reactive(&self) -> Reactive<T>
+    where
+        T: Sum + PartialEq,
+    {
+        self.derive(|v| v.iter().cloned().sum())
+    }
+}
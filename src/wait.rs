@@ -0,0 +1,143 @@
+use crate::{inbox::Inbox, Reactive};
+use std::time::{Duration, Instant};
+
+impl<T: Clone + Send + 'static> Reactive<T> {
+    /// Blocks the calling thread until the value of the reactive satisfies `pred`
+    /// and returns a clone of that value.
+    ///
+    /// Returns right away if the current value already satisfies it.
+    /// Otherwise the thread sleeps on a `Condvar` that is signalled by an observer,
+    /// so there is no polling involved.
+    ///
+    /// **Don't call this on the thread that updates the reactive**,
+    /// it would wait for a change that can never happen.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    ///
+    /// std::thread::spawn({
+    ///     let r = r.clone();
+    ///     move || {
+    ///         for _ in 0..10 {
+    ///             r.update(|n| n + 1);
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert!(r.wait_for(|n| *n >= 5) >= 5);
+    /// ```
+    pub fn wait_for(&self, pred: impl Fn(&T) -> bool + Send + 'static) -> T {
+        self.wait(pred, true, None)
+            .expect("waiting without a timeout always ends with a value")
+    }
+
+    /// Same as [`Reactive::wait_for`] but gives up after `timeout`, returning `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    /// use std::time::Duration;
+    ///
+    /// let r = Reactive::new(0);
+    ///
+    /// assert_eq!(Some(0), r.wait_for_timeout(|n| *n == 0, Duration::from_millis(10)));
+    /// assert_eq!(None, r.wait_for_timeout(|n| *n == 1, Duration::from_millis(10)));
+    /// ```
+    pub fn wait_for_timeout(
+        &self,
+        pred: impl Fn(&T) -> bool + Send + 'static,
+        timeout: Duration,
+    ) -> Option<T> {
+        self.wait(pred, true, Some(timeout))
+    }
+
+    /// Blocks the calling thread until the next time the observers are notified
+    /// and returns a clone of the value they were notified with.
+    ///
+    /// The internal observer is registered before this method starts waiting
+    /// (under the same lock that updates take), so a notification that happens on another
+    /// thread right after the call is never missed. Notifications that happened
+    /// before the call are not considered.
+    ///
+    /// **Don't call this on the thread that updates the reactive**,
+    /// it would wait for a change that can never happen.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// let r = Reactive::new(0);
+    ///
+    /// let waiter = std::thread::spawn({
+    ///     let r = r.clone();
+    ///     move || r.wait_changed()
+    /// });
+    ///
+    /// while !waiter.is_finished() {
+    ///     r.update(|n| n + 1);
+    ///     std::thread::sleep(std::time::Duration::from_millis(1));
+    /// }
+    ///
+    /// println!("waiter saw {}", waiter.join().unwrap());
+    /// ```
+    pub fn wait_changed(&self) -> T {
+        self.wait(|_| true, false, None)
+            .expect("waiting without a timeout always ends with a value")
+    }
+
+    /// Same as [`Reactive::wait_changed`] but gives up after `timeout`, returning `None`.
+    pub fn wait_changed_timeout(&self, timeout: Duration) -> Option<T> {
+        self.wait(|_| true, false, Some(timeout))
+    }
+
+    /// Blocks until an observer call passes `pred` (or the current value does, if `check_current`)
+    /// or until the timeout elapses.
+    fn wait(
+        &self,
+        pred: impl Fn(&T) -> bool + Send + 'static,
+        check_current: bool,
+        timeout: Option<Duration>,
+    ) -> Option<T> {
+        let registered = self.observe_with_current(|current, observers| {
+            if check_current && pred(current) {
+                return Err(current.clone());
+            }
+
+            let inbox = Inbox::new(None);
+            let registration = observers.add_inbox(&inbox, move |buf, val: &T| {
+                if buf.is_none() && pred(val) {
+                    *buf = Some(val.clone());
+                }
+            });
+            Ok((inbox, registration))
+        });
+        let (inbox, _registration) = match registered {
+            Ok(registered) => registered,
+            Err(current) => return Some(current),
+        };
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut contents = inbox.lock();
+
+        // loop because `Condvar` is allowed to wake up spuriously
+        loop {
+            if let Some(val) = contents.buf.take() {
+                return Some(val);
+            }
+
+            contents = match deadline {
+                None => inbox.wait(contents),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return None;
+                    }
+                    inbox.wait_timeout(contents, deadline - now)
+                }
+            };
+        }
+    }
+}
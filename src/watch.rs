@@ -0,0 +1,75 @@
+use crate::Reactive;
+use tokio::{runtime::Handle, sync::watch};
+
+impl<T: Clone + Send + Sync + 'static> Reactive<T> {
+    /// Returns a `tokio::sync::watch::Receiver` that starts with the current value
+    /// and sees every new value of the reactive.
+    ///
+    /// Clone the receiver to get more of them.
+    /// Once every receiver is dropped, the internal observer removes itself from the reactive.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let r = Reactive::new(10);
+    /// let mut rx = r.into_tokio_watch();
+    ///
+    /// assert_eq!(10, *rx.borrow());
+    ///
+    /// r.update(|n| n + 1);
+    ///
+    /// rx.changed().await.unwrap();
+    /// assert_eq!(11, *rx.borrow_and_update());
+    /// # });
+    /// ```
+    pub fn into_tokio_watch(&self) -> watch::Receiver<T> {
+        self.observe_with_current(|val, observers| {
+            let (sender, receiver) = watch::channel(val.clone());
+            observers.add_retaining(move |val| sender.send(val.clone()).is_ok());
+            receiver
+        })
+    }
+
+    /// Constructs a new reactive that starts with the current value of the `receiver`
+    /// and spawns a task on the given runtime to feed every later value into it using `update`.
+    ///
+    /// When the sending side is dropped, the task ends and
+    /// the reactive simply keeps the last value it received.
+    ///
+    /// # Examples
+    /// ```
+    /// use reactivate::Reactive;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let (tx, rx) = tokio::sync::watch::channel(10);
+    /// let r = Reactive::from_tokio_watch(rx, &tokio::runtime::Handle::current());
+    ///
+    /// assert_eq!(10, r.value());
+    ///
+    /// tx.send(11).unwrap();
+    /// tokio::task::yield_now().await;
+    ///
+    /// assert_eq!(11, r.value());
+    /// # });
+    /// ```
+    pub fn from_tokio_watch(mut receiver: watch::Receiver<T>, handle: &Handle) -> Self
+    where
+        T: PartialEq,
+    {
+        let reactive = Reactive::new(receiver.borrow_and_update().clone());
+
+        handle.spawn({
+            let reactive = reactive.clone();
+            async move {
+                while receiver.changed().await.is_ok() {
+                    let val = receiver.borrow_and_update().clone();
+                    reactive.update(|_| val);
+                }
+            }
+        });
+
+        reactive
+    }
+}
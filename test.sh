@@ -1,2 +1,15 @@
 cargo test
+cargo test --no-default-features
+cargo clippy --no-default-features --all-targets -- -D warnings
 cargo test --features threadsafe
+cargo test --features async
+cargo test --features "async threadsafe"
+cargo test --features strict
+cargo test --features tokio
+cargo test --features fast-hash
+cargo test --features futures-signals
+cargo test --features crossbeam
+cargo test --features graph-introspection
+cargo test --features rwlock
+cargo test --features arcswap
+RUSTFLAGS="--cfg loom" cargo test --release --features threadsafe --test loom_tests
@@ -0,0 +1,56 @@
+//! Kept apart from `lib_tests.rs` because the counting global allocator
+//! would apply to every test in the binary.
+
+use reactivate::Reactive;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn single_observer_doesnt_allocate_observer_storage() {
+    // thread locals used while notifying are allocated on first use
+    let warm_up = Reactive::new(0);
+    warm_up.add_observer(|_| {});
+    warm_up.update(|n| n + 1);
+
+    let bare = allocations(|| {
+        let _r = Reactive::new(10);
+    });
+
+    // zero sized closures don't allocate when boxed, so any difference
+    // would come from the observer storage itself
+    let one_observer = allocations(|| {
+        let r = Reactive::new(10);
+        r.add_observer(|_| {});
+        r.update(|n| n + 1);
+    });
+
+    assert_eq!(bare, one_observer);
+}
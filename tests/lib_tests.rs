@@ -8,6 +8,21 @@ fn initial_derived_values_must_not_be_default() {
     assert_eq!(15, d.value());
 }
 
+#[test]
+fn derive_with_initial_starts_with_the_given_value() {
+    let r = Reactive::new(10);
+    let d = r.derive_with_initial(String::from("loading"), |val| val.to_string());
+
+    assert_eq!("loading", d.value());
+    assert_eq!(1, r.descendant_count());
+
+    r.update(|n| n + 1);
+    assert_eq!("11", d.value());
+
+    r.update(|_| 10);
+    assert_eq!("10", d.value());
+}
+
 #[test]
 fn can_set() {
     let r = Reactive::new(10);
@@ -347,3 +362,3619 @@ fn can_access_internals() {
 
     assert_eq!(21, r.value());
 }
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn changed_resolves_with_next_value() {
+    let r = Reactive::new(10);
+
+    let first = r.changed();
+    let second = r.changed();
+
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+
+    assert_eq!(Ok(11), first.await);
+    assert_eq!(Ok(11), second.await);
+
+    let mut num_observers = usize::MAX;
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(0, num_observers);
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn dropping_changed_future_unregisters_it() {
+    let r = Reactive::new(10);
+
+    let mut num_observers = usize::MAX;
+
+    let changed = r.changed();
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(1, num_observers);
+
+    drop(changed);
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(0, num_observers);
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn changed_errors_when_reactive_is_dropped() {
+    let r = Reactive::new(10);
+    let changed = r.changed();
+
+    drop(r);
+
+    assert_eq!(Err(reactivate::Closed), changed.await);
+}
+
+#[test]
+fn can_map_observers() {
+    let r = Reactive::new(10);
+    let d = r.derive(|val| val + 1);
+
+    #[cfg(not(feature = "threadsafe"))]
+    let calls: std::rc::Rc<std::cell::Cell<usize>> = Default::default();
+
+    #[cfg(not(feature = "threadsafe"))]
+    r.map_observers(|mut obs| {
+        let calls = calls.clone();
+        Box::new(move |val| {
+            calls.set(calls.get() + 1);
+            obs(val);
+        })
+    });
+
+    #[cfg(feature = "threadsafe")]
+    let calls: std::sync::Arc<std::sync::atomic::AtomicUsize> = Default::default();
+
+    #[cfg(feature = "threadsafe")]
+    r.map_observers(|mut obs| {
+        let calls = calls.clone();
+        Box::new(move |val| {
+            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            obs(val);
+        })
+    });
+
+    r.update(|n| n * 2);
+    r.update(|n| n + 1);
+
+    assert_eq!(22, d.value());
+
+    #[cfg(not(feature = "threadsafe"))]
+    assert_eq!(2, calls.get());
+
+    #[cfg(feature = "threadsafe")]
+    assert_eq!(2, calls.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[test]
+fn can_add_observer_with_ctx() {
+    let r = Reactive::new(10);
+
+    struct DropFlag(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    let dropped: std::sync::Arc<std::sync::atomic::AtomicBool> = Default::default();
+    let sum: std::sync::Arc<std::sync::Mutex<i32>> = Default::default();
+
+    r.add_observer_ctx((0, DropFlag(dropped.clone())), {
+        let sum = sum.clone();
+        move |(count, _), val| {
+            *count += 1;
+            *sum.lock().unwrap() = *count * 100 + val;
+        }
+    });
+
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+
+    assert_eq!(212, *sum.lock().unwrap());
+    assert!(!dropped.load(std::sync::atomic::Ordering::SeqCst));
+
+    r.clear_observers();
+
+    assert!(dropped.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn can_stream_values() {
+    use futures::StreamExt;
+
+    let r = Reactive::new(0);
+    let mut latest = r.to_stream();
+    let mut buffered = r.to_stream_buffered(3);
+    let mut with_current = r.to_stream_with_current();
+
+    assert_eq!(Some(0), with_current.next().await);
+
+    for _ in 0..4 {
+        r.update(|n| n + 1);
+    }
+
+    assert_eq!(Some(4), latest.next().await);
+    assert_eq!(
+        vec![2, 3, 4],
+        buffered.by_ref().take(3).collect::<Vec<_>>().await
+    );
+    assert_eq!(Some(4), with_current.next().await);
+
+    drop(latest);
+    drop(buffered);
+    drop(with_current);
+
+    let mut num_observers = usize::MAX;
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(0, num_observers);
+}
+
+#[tokio::test]
+#[cfg(all(feature = "async", feature = "threadsafe"))]
+async fn snapshot_stream_has_no_gap_after_the_snapshot() {
+    use futures::StreamExt;
+
+    let r = Reactive::new(0);
+    let updater = std::thread::spawn({
+        let r = r.clone();
+        move || {
+            for _ in 0..100 {
+                r.update(|n| n + 1);
+            }
+        }
+    });
+
+    let (snapshot, stream) = r.snapshot_stream();
+    updater.join().unwrap();
+    drop(r);
+
+    let rest: Vec<i32> = stream.collect().await;
+    assert!(rest.iter().all(|&n| n > snapshot));
+    if snapshot < 100 {
+        assert_eq!(Some(&100), rest.last());
+    }
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn stream_ends_when_reactive_is_dropped() {
+    use futures::StreamExt;
+
+    let r = Reactive::new(0);
+    let mut stream = r.to_stream();
+
+    r.update(|n| n + 1);
+    drop(r);
+
+    assert_eq!(Some(1), stream.next().await);
+    assert_eq!(None, stream.next().await);
+}
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn constant_ignores_changes() {
+    let r = Reactive::constant(10);
+    let d = r.derive(|val| val + 5);
+
+    r.set(11);
+    r.update(|n| n + 1);
+    r.update_unchecked(|n| n + 1);
+    r.update_inplace(|n| *n += 1);
+    r.update_inplace_unchecked(|n| *n += 1);
+
+    assert_eq!(10, r.value());
+    assert_eq!(15, d.value());
+}
+
+#[test]
+#[cfg(feature = "strict")]
+#[should_panic]
+fn constant_panics_on_change_when_strict() {
+    let r = Reactive::constant(10);
+    r.update(|n| n + 1);
+}
+
+#[test]
+fn update_if_only_applies_when_guard_holds() {
+    let r = Reactive::new(10);
+    let d = r.derive(|val| val + 5);
+
+    assert!(!r.update_if(|n| *n > 10, |n| n * 2));
+    assert_eq!(15, d.value());
+
+    assert!(r.update_if(|n| *n == 10, |n| n * 2));
+    assert_eq!(25, d.value());
+}
+
+#[test]
+fn update_and_get_returns_the_new_value() {
+    let r = Reactive::new(10);
+    let d = r.derive(|val| val + 5);
+
+    assert_eq!(20, r.update_and_get(|n| n * 2));
+    assert_eq!(20, r.value());
+    assert_eq!(25, d.value());
+
+    assert_eq!(20, r.update_and_get(|n| *n));
+    assert_eq!(20, r.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn update_if_is_atomic() {
+    let r = Reactive::new(0);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let r = r.clone();
+            std::thread::spawn(move || {
+                let mut applied = 0;
+                for _ in 0..1000 {
+                    if r.update_if(|n| *n < 1000, |n| n + 1) {
+                        applied += 1;
+                    }
+                }
+                applied
+            })
+        })
+        .collect();
+
+    let applied: i32 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+    assert_eq!(1000, applied);
+    assert_eq!(1000, r.value());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn can_bind_stream() {
+    let r = Reactive::new(0);
+    let d = r.derive(|val| val * 10);
+
+    let (mut tx, rx) = futures::channel::mpsc::unbounded();
+    let feeder = r.bind_stream(rx, &tokio::runtime::Handle::current());
+
+    let next = r.changed();
+    tx.start_send(1).unwrap();
+    assert_eq!(Ok(1), next.await);
+    assert_eq!(10, d.value());
+
+    let next = r.changed();
+    tx.start_send(2).unwrap();
+    assert_eq!(Ok(2), next.await);
+
+    drop(tx);
+    while !feeder.is_finished() {
+        tokio::task::yield_now().await;
+    }
+
+    // last value is kept after the stream ends
+    assert_eq!(2, r.value());
+    assert_eq!(20, d.value());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn dropping_feeder_cancels_it() {
+    let r = Reactive::new(0);
+
+    let (mut tx, rx) = futures::channel::mpsc::unbounded();
+    let feeder = r.bind_stream(rx, &tokio::runtime::Handle::current());
+
+    drop(feeder);
+    tokio::task::yield_now().await;
+
+    let _ = tx.start_send(1);
+    tokio::task::yield_now().await;
+
+    assert_eq!(0, r.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn throttle_inplace_applies_latest_mutation_once_per_interval() {
+    let r = Reactive::new(vec![1, 2, 3]);
+    let d = r.derive(|nums| nums.len());
+
+    let throttled = r.throttle_inplace(std::time::Duration::from_millis(50));
+
+    throttled.update_inplace(|v| v.push(4));
+    throttled.update_inplace(|v| v.push(5));
+    throttled.update_inplace(|v| v.push(6));
+
+    assert_eq!(vec![1, 2, 3, 4], r.value());
+    assert_eq!(4, d.value());
+
+    #[cfg(not(feature = "threadsafe"))]
+    throttled.flush();
+
+    #[cfg(feature = "threadsafe")]
+    std::thread::sleep(std::time::Duration::from_millis(150));
+
+    assert_eq!(vec![1, 2, 3, 4, 6], r.value());
+    assert_eq!(5, d.value());
+}
+
+#[test]
+#[cfg(all(feature = "std", not(feature = "threadsafe")))]
+fn throttle_inplace_applies_due_mutations_on_the_next_call() {
+    use std::time::Duration;
+
+    let r = Reactive::new(vec![1]);
+    let throttled = r.throttle_inplace(Duration::from_millis(20));
+
+    throttled.update_inplace(|v| v.push(2));
+    throttled.update_inplace(|v| v.push(3));
+    std::thread::sleep(Duration::from_millis(60));
+
+    // the held back mutation goes first, the new one waits for the next interval
+    throttled.update_inplace(|v| v.push(4));
+    assert_eq!(vec![1, 2, 3], r.value());
+
+    throttled.flush();
+    assert_eq!(vec![1, 2, 3, 4], r.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn throttle_inplace_timers_are_cancelled_by_flush() {
+    use std::time::Duration;
+
+    let r = Reactive::new(vec![1]);
+    let throttled = r.throttle_inplace(Duration::from_millis(200));
+
+    throttled.update_inplace(|v| v.push(2));
+    throttled.update_inplace(|v| v.push(3)); // timer due in 200ms
+    std::thread::sleep(Duration::from_millis(100));
+
+    throttled.flush();
+    throttled.update_inplace(|v| v.push(4)); // due 200ms after the flush
+    assert_eq!(vec![1, 2, 3], r.value());
+
+    // the first timer woke up in between and must have left `4` alone
+    std::thread::sleep(Duration::from_millis(150));
+    assert_eq!(vec![1, 2, 3], r.value());
+
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(vec![1, 2, 3, 4], r.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn throttle_inplace_timers_dont_keep_the_reactive_alive() {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+    use std::time::Duration;
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let r = Reactive::new(vec![1]);
+    r.on_drop({
+        let dropped = dropped.clone();
+        move || dropped.store(true, Ordering::SeqCst)
+    });
+
+    let throttled = r.throttle_inplace(Duration::from_secs(60));
+    throttled.update_inplace(|v| v.push(2));
+    throttled.update_inplace(|v| v.push(3)); // timer due in a minute
+
+    drop(throttled);
+    drop(r);
+
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn manual_debounce_only_emits_on_flush() {
+    let r = Reactive::new(10);
+    let (debounced, flush) = r.manual_debounce();
+    let d = debounced.derive(|val| val * 2);
+
+    r.update(|_| 11);
+    r.update(|_| 12);
+
+    assert_eq!(10, debounced.value());
+    assert_eq!(20, d.value());
+
+    flush();
+
+    assert_eq!(12, debounced.value());
+    assert_eq!(24, d.value());
+
+    debounced.update(|_| 0);
+    flush();
+
+    // nothing changed since the last flush
+    assert_eq!(0, debounced.value());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn can_forward_stream_into_sink() {
+    use futures::{stream, StreamExt};
+
+    let r = Reactive::new(0);
+    let checked = Reactive::new(0);
+
+    #[cfg(not(feature = "threadsafe"))]
+    let changes: std::rc::Rc<std::cell::RefCell<Vec<i32>>> = Default::default();
+
+    #[cfg(not(feature = "threadsafe"))]
+    checked.add_observer({
+        let changes = changes.clone();
+        move |val| changes.borrow_mut().push(*val)
+    });
+
+    #[cfg(feature = "threadsafe")]
+    let changes: std::sync::Arc<std::sync::Mutex<Vec<i32>>> = Default::default();
+
+    #[cfg(feature = "threadsafe")]
+    checked.add_observer({
+        let changes = changes.clone();
+        move |val| changes.lock().unwrap().push(*val)
+    });
+
+    stream::iter(vec![1, 1, 2])
+        .map(Ok)
+        .forward(r.clone())
+        .await
+        .unwrap();
+
+    stream::iter(vec![1, 1, 2])
+        .map(Ok)
+        .forward(checked.checked_sink())
+        .await
+        .unwrap();
+
+    assert_eq!(2, r.value());
+    assert_eq!(2, checked.value());
+
+    #[cfg(not(feature = "threadsafe"))]
+    assert_eq!(vec![1, 2], changes.borrow().clone());
+
+    #[cfg(feature = "threadsafe")]
+    assert_eq!(vec![1, 2], changes.lock().unwrap().clone());
+}
+
+#[test]
+fn can_add_observer_with_context() {
+    let a = Reactive::new(10);
+    let b = Reactive::new(20);
+
+    let changes: std::sync::Arc<std::sync::Mutex<Vec<(&'static str, i32)>>> = Default::default();
+
+    let observe = {
+        let changes = changes.clone();
+        move |source, val: &i32| changes.lock().unwrap().push((source, *val))
+    };
+
+    a.add_observer_with_context("a", observe.clone());
+    let handle = b.add_observer_with_context("b", observe);
+
+    a.update(|n| n + 1);
+    b.update(|n| n + 1);
+
+    assert!(handle.remove());
+    assert!(!handle.remove());
+
+    b.update(|n| n + 1);
+
+    assert_eq!(vec![("a", 11), ("b", 21)], changes.lock().unwrap().clone());
+}
+
+#[test]
+fn observers_can_remove_themselves() {
+    use reactivate::ObserverHandle;
+    use std::sync::{Arc, Mutex};
+
+    let r = Reactive::new(0);
+    let calls: Arc<Mutex<Vec<i32>>> = Default::default();
+    let own: Arc<Mutex<Option<ObserverHandle<i32>>>> = Default::default();
+
+    let handle = r.add_observer_with_context(own.clone(), {
+        let calls = calls.clone();
+        move |own, val| {
+            calls.lock().unwrap().push(*val);
+            // unsubscribe once
+            if let Some(handle) = own.lock().unwrap().take() {
+                assert!(handle.remove());
+            }
+        }
+    });
+    *own.lock().unwrap() = Some(handle);
+    let d = r.derive(|n| n * 2);
+
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+
+    assert_eq!(vec![1], *calls.lock().unwrap());
+    assert_eq!(1, r.inspect_observers(|observers| observers.len()));
+    assert_eq!(4, d.value());
+}
+
+#[test]
+fn observers_can_remove_their_siblings() {
+    use reactivate::ObserverHandle;
+    use std::sync::{Arc, Mutex};
+
+    let r = Reactive::new(0);
+    let calls: Arc<Mutex<Vec<(&'static str, i32)>>> = Default::default();
+    let sibling: Arc<Mutex<Option<ObserverHandle<i32>>>> = Default::default();
+
+    r.add_observer_with_context(sibling.clone(), {
+        let calls = calls.clone();
+        let r = r.clone();
+        move |sibling, val| {
+            calls.lock().unwrap().push(("first", *val));
+            if *val == 2 {
+                let handle = sibling.lock().unwrap().take().unwrap();
+                assert!(handle.remove());
+                // updates made afterwards by the observers don't reach the removed one
+                r.update(|n| n + 1);
+            }
+        }
+    });
+    let handle = r.add_observer_with_context("second", {
+        let calls = calls.clone();
+        move |name, val| calls.lock().unwrap().push((name, *val))
+    });
+    *sibling.lock().unwrap() = Some(handle);
+
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+
+    assert_eq!(
+        vec![
+            ("first", 1),
+            ("second", 1),
+            ("first", 2),
+            // removed once the pass in progress is done
+            ("second", 2),
+            ("first", 3),
+            ("first", 4),
+        ],
+        *calls.lock().unwrap()
+    );
+}
+
+#[test]
+fn fused_derives_match_chained_derives() {
+    let r = Reactive::new(10);
+
+    let chained2 = r.derive(|val| val / 10).derive(|val| val * 3);
+    let fused2 = r.derive2(|val| val / 10, |val| val * 3);
+
+    let chained3 = r
+        .derive(|val| val / 10)
+        .derive(|val| val % 3)
+        .derive(|val| val.to_string());
+    let fused3 = r.derive3(|val| val / 10, |val| val % 3, |val| val.to_string());
+
+    for n in [11, 25, 30, 40, 70, 7] {
+        r.update(|_| n);
+        assert_eq!(chained2.value(), fused2.value());
+        assert_eq!(chained3.value(), fused3.value());
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(feature = "tokio")]
+async fn async_observers_run_on_the_runtime() {
+    use std::sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc,
+    };
+
+    let r = Reactive::new(0);
+    let handle = tokio::runtime::Handle::current();
+
+    let sum: Arc<AtomicI32> = Default::default();
+    r.add_async_observer(&handle, {
+        let sum = sum.clone();
+        move |val| {
+            let sum = sum.clone();
+            async move {
+                sum.fetch_add(val, Ordering::SeqCst);
+            }
+        }
+    });
+
+    struct CountDrops(Arc<AtomicI32>);
+
+    impl Drop for CountDrops {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let completed: Arc<AtomicI32> = Default::default();
+    let dropped: Arc<AtomicI32> = Default::default();
+    r.add_async_observer_latest(&handle, {
+        let completed = completed.clone();
+        let dropped = dropped.clone();
+        move |val| {
+            let completed = completed.clone();
+            let guard = CountDrops(dropped.clone());
+            let tx = tx.clone();
+            async move {
+                let _guard = guard;
+                if val < 3 {
+                    std::future::pending::<()>().await;
+                }
+                completed.store(val, Ordering::SeqCst);
+                tx.send(()).unwrap();
+            }
+        }
+    });
+
+    for _ in 0..3 {
+        r.update(|n| n + 1);
+    }
+
+    rx.recv().await.unwrap();
+    while sum.load(Ordering::SeqCst) != 6 {
+        tokio::task::yield_now().await;
+    }
+
+    assert_eq!(3, completed.load(Ordering::SeqCst));
+    // the tasks spawned for 1 and 2 were aborted and the one for 3 completed
+    while dropped.load(Ordering::SeqCst) != 3 {
+        tokio::task::yield_now().await;
+    }
+}
+
+#[test]
+fn can_derive_string_properties() {
+    use reactivate::ReactiveStringExt;
+
+    let r: Reactive<String> = Reactive::default();
+
+    let len = r.len_reactive();
+    let is_empty = r.is_empty_reactive();
+    let chars_count = r.chars_count_reactive();
+    let lines = r.lines_reactive();
+
+    assert_eq!(0, len.value());
+    assert!(is_empty.value());
+
+    r.update(|_| String::from("🦀\nrust"));
+
+    assert_eq!(9, len.value());
+    assert!(!is_empty.value());
+    assert_eq!(6, chars_count.value());
+    assert_eq!(
+        vec![String::from("🦀"), String::from("rust")],
+        lines.value()
+    );
+}
+
+#[test]
+fn can_borrow_value_without_cloning() {
+    let r = Reactive::new(vec![1, 2, 3]);
+
+    {
+        let nums = r.borrowed();
+        assert_eq!(&[1, 2, 3], nums.as_slice());
+    }
+
+    r.update_inplace(|nums| nums.push(4));
+
+    assert_eq!(4, r.borrowed().len());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn try_update_async_detects_conflicts() {
+    let r = Reactive::new(0);
+    let (tx, rx) = futures::channel::oneshot::channel::<()>();
+
+    let slow = r.try_update_async(|val| async move {
+        rx.await.unwrap();
+        val + 1
+    });
+
+    let fast = async {
+        let result = r.try_update_async(|val| async move { val + 10 }).await;
+        tx.send(()).unwrap();
+        result
+    };
+
+    let (slow, fast) = futures::join!(slow, fast);
+
+    assert_eq!(Ok(()), fast);
+    assert_eq!(Err(reactivate::Conflict(1)), slow);
+    assert_eq!(10, r.value());
+
+    r.update_async(|val| async move { val * 2 }).await;
+    assert_eq!(20, r.value());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn update_async_doesnt_lock_while_pending() {
+    let r = Reactive::new(0);
+    let (tx, rx) = futures::channel::oneshot::channel::<()>();
+
+    let slow = r.update_async(|val| async move {
+        rx.await.unwrap();
+        val + 1
+    });
+
+    let meanwhile = async {
+        // would deadlock (or panic) if the lock was held across the await
+        assert_eq!(0, r.value());
+        r.update(|_| 10);
+        tx.send(()).unwrap();
+    };
+
+    futures::join!(slow, meanwhile);
+
+    // last write wins
+    assert_eq!(1, r.value());
+}
+
+#[test]
+fn can_derive_vec_properties() {
+    use reactivate::ReactiveVecExt;
+
+    let r: Reactive<Vec<i32>> = Reactive::default();
+
+    let len = r.len_reactive();
+    let is_empty = r.is_empty_reactive();
+    let first = r.first_reactive();
+    let last = r.last_reactive();
+    let sum = r.sum_reactive();
+
+    assert_eq!(0, len.value());
+    assert!(is_empty.value());
+    assert_eq!(None, first.value());
+    assert_eq!(None, last.value());
+    assert_eq!(0, sum.value());
+
+    r.update_inplace(|nums| nums.extend([1, 2, 3]));
+
+    assert_eq!(3, len.value());
+    assert!(!is_empty.value());
+    assert_eq!(Some(1), first.value());
+    assert_eq!(Some(3), last.value());
+    assert_eq!(6, sum.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn batched_observer_receives_values_per_window() {
+    use std::time::Duration;
+
+    let r = Reactive::new(0);
+    let batches: std::sync::Arc<std::sync::Mutex<Vec<Vec<i32>>>> = Default::default();
+
+    r.add_batched_observer(Duration::from_millis(100), {
+        let batches = batches.clone();
+        move |vals| batches.lock().unwrap().push(vals.to_vec())
+    });
+
+    r.update(|_| 1);
+    r.update(|_| 2);
+
+    std::thread::sleep(Duration::from_millis(250));
+
+    r.update(|_| 3);
+    drop(r);
+
+    std::thread::sleep(Duration::from_millis(50));
+
+    assert_eq!(vec![vec![1, 2], vec![3]], batches.lock().unwrap().clone());
+}
+
+#[test]
+fn can_compute_moving_average() {
+    let r: Reactive<i64> = Reactive::new(1);
+    let avg = r.moving_average(2);
+
+    assert_eq!(1.0, avg.value());
+
+    r.update(|_| 2);
+    assert_eq!(1.5, avg.value());
+
+    r.update(|_| 4);
+    assert_eq!(3.0, avg.value());
+
+    let f = Reactive::new(1.0f32);
+    let avg = f.moving_average(1);
+
+    f.update(|_| 5.0);
+    assert_eq!(5.0, avg.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn derive_diff_emits_every_change() {
+    let r = Reactive::new(10);
+    let diff = r.derive_diff();
+    let (tx, rx) = std::sync::mpsc::channel();
+    diff.observe_into(tx);
+
+    assert_eq!(0, diff.value());
+
+    r.update(|_| 15);
+    assert_eq!(5, diff.value());
+
+    // same difference again must still be emitted
+    r.update(|_| 20);
+    assert_eq!(5, diff.value());
+
+    r.update(|_| 12);
+    assert_eq!(-8, diff.value());
+    assert_eq!(vec![5, 5, -8], rx.try_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn delta_reactive_resets_when_value_is_unchanged() {
+    let r: Reactive<i64> = Reactive::new(10);
+    let delta = r.delta_reactive();
+
+    assert_eq!(0, delta.value());
+
+    r.update(|n| n - 4);
+    assert_eq!(-4, delta.value());
+
+    r.set(6);
+    assert_eq!(0, delta.value());
+
+    r.update(|n| n + 1);
+    assert_eq!(1, delta.value());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "subtract with overflow")]
+fn derive_diff_overflows_when_an_unsigned_value_decreases() {
+    let r: Reactive<u64> = Reactive::new(5);
+    let _diff = r.derive_diff();
+
+    r.update(|_| 3);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "subtract with overflow")]
+fn delta_reactive_overflows_when_an_unsigned_value_decreases() {
+    let r: Reactive<u64> = Reactive::new(5);
+    let _delta = r.delta_reactive();
+
+    r.update(|_| 3);
+}
+
+#[test]
+fn update_inplace_eq_only_notifies_observers_when_value_changes() {
+    let r: Reactive<String> = Reactive::default();
+
+    #[cfg(not(feature = "threadsafe"))]
+    let changes: std::rc::Rc<std::cell::RefCell<Vec<String>>> = Default::default();
+
+    #[cfg(not(feature = "threadsafe"))]
+    r.add_observer({
+        let changes = changes.clone();
+        move |val| changes.borrow_mut().push(val.clone())
+    });
+
+    #[cfg(feature = "threadsafe")]
+    let changes: std::sync::Arc<std::sync::Mutex<Vec<String>>> = Default::default();
+
+    #[cfg(feature = "threadsafe")]
+    r.add_observer({
+        let changes = changes.clone();
+        move |val| changes.lock().unwrap().push(val.clone())
+    });
+
+    r.update_inplace_eq(|s| s.push('a'));
+    r.update_inplace_eq(|s| {
+        s.push('x');
+        s.pop();
+    });
+    r.update_inplace_eq(|s| s.push('b'));
+
+    let expected = vec![String::from("a"), String::from("ab")];
+
+    #[cfg(not(feature = "threadsafe"))]
+    assert_eq!(expected, changes.borrow().clone());
+
+    #[cfg(feature = "threadsafe")]
+    assert_eq!(expected, changes.lock().unwrap().clone());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn can_broadcast_changes() {
+    use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+
+    let r = Reactive::new(0);
+    let sender = r.broadcast(2);
+
+    // no receivers yet, the value is dropped
+    r.update(|_| 1);
+
+    let mut a = sender.subscribe();
+    let mut b = sender.subscribe();
+
+    r.update(|_| 2);
+    r.update(|_| 3);
+
+    assert_eq!(2, a.recv().await.unwrap());
+    assert_eq!(3, a.recv().await.unwrap());
+    assert_eq!(Err(TryRecvError::Empty), a.try_recv());
+
+    r.update(|_| 4);
+
+    // `b` fell behind by more than the capacity
+    assert_eq!(Err(RecvError::Lagged(1)), b.recv().await);
+    assert_eq!(3, b.recv().await.unwrap());
+    assert_eq!(4, b.recv().await.unwrap());
+    assert_eq!(4, a.recv().await.unwrap());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn broadcast_closes_once_the_observer_and_sender_are_gone() {
+    use tokio::sync::broadcast::error::RecvError;
+
+    let r = Reactive::new(0);
+    let mut rx = r.broadcast(4).subscribe();
+
+    // the returned sender is gone but the observer still holds one
+    r.update(|_| 1);
+    assert_eq!(1, rx.recv().await.unwrap());
+
+    drop(r);
+    assert_eq!(Err(RecvError::Closed), rx.recv().await);
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn broadcast_receiver_fans_out_until_the_reactive_is_gone() {
+    use tokio::sync::broadcast::error::RecvError;
+
+    let r = Reactive::new(0);
+    let mut rx1 = r.broadcast_receiver(2);
+    let mut rx2 = rx1.resubscribe();
+
+    r.update(|_| 1);
+    assert_eq!(1, rx1.recv().await.unwrap());
+
+    r.update(|_| 2);
+    r.update(|_| 3);
+    assert_eq!(Err(RecvError::Lagged(1)), rx2.recv().await);
+    assert_eq!(2, rx2.recv().await.unwrap());
+    assert_eq!(3, rx2.recv().await.unwrap());
+
+    drop(r);
+    assert_eq!(2, rx1.recv().await.unwrap());
+    assert_eq!(3, rx1.recv().await.unwrap());
+    assert_eq!(Err(RecvError::Closed), rx1.recv().await);
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn broadcast_receiver_unregisters_once_every_receiver_is_dropped() {
+    let r = Reactive::new(0);
+    drop(r.broadcast_receiver(4));
+    assert!(r.has_observers());
+
+    r.update(|_| 1);
+    assert!(!r.has_observers());
+}
+
+#[test]
+fn can_track_running_extrema() {
+    let r = Reactive::new(10);
+    let min = r.min_ever();
+    let max = r.max_ever();
+
+    assert_eq!(10, min.value());
+    assert_eq!(10, max.value());
+
+    for n in [12, 3, 7, 20, 15] {
+        r.update(|_| n);
+    }
+
+    assert_eq!(3, min.value());
+    assert_eq!(20, max.value());
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn wait_until_resolves_with_first_matching_value() {
+    let r = Reactive::new(1);
+
+    assert_eq!(Ok(1), r.wait_until(|n| n % 2 == 1).await);
+
+    let even = r.wait_until(|n| n % 2 == 0);
+    let big = r.wait_until(|n| *n > 10);
+    let cancelled = r.wait_until(|n| *n > 100);
+
+    drop(cancelled);
+
+    let mut num_observers = usize::MAX;
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(2, num_observers);
+
+    for n in [3, 4, 6, 11, 12] {
+        r.update(|_| n);
+    }
+
+    assert_eq!(Ok(4), even.await);
+    assert_eq!(Ok(11), big.await);
+
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(0, num_observers);
+}
+
+#[test]
+fn can_try_derive() {
+    use reactivate::ReactiveResultExt;
+
+    let r = Reactive::new(String::from("🦀"));
+    let parsed = r.try_derive(|s| s.parse::<i32>().map_err(|_| format!("bad number: {}", s)));
+    let ok = parsed.ok();
+
+    assert_eq!(Err(String::from("bad number: 🦀")), parsed.value());
+    assert_eq!(None, ok.value());
+
+    r.update(|_| String::from("10"));
+    assert_eq!(Ok(10), parsed.value());
+    assert_eq!(Some(10), ok.value());
+
+    r.update(|_| String::from("ten"));
+    assert_eq!(Err(String::from("bad number: ten")), parsed.value());
+    assert_eq!(Some(10), ok.value());
+}
+
+#[test]
+fn update_inplace_detects_changes_across_clones() {
+    let r = Reactive::new(vec![1, 2, 3]);
+    let d = r.derive(|nums| nums.len());
+
+    let handle = r.clone();
+
+    for n in 4..100 {
+        handle.update_inplace(|nums| nums.push(n));
+        r.update_inplace(|nums| {
+            nums.push(0);
+            nums.pop();
+        });
+        assert_eq!(n as usize, d.value());
+    }
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn async_observers_can_read_their_reactive() {
+    use reactivate::AsyncReactive;
+
+    let r = AsyncReactive::new(1);
+    let seen: std::sync::Arc<std::sync::Mutex<Vec<i32>>> = Default::default();
+    r.add_async_observer({
+        let r = r.clone();
+        let seen = seen.clone();
+        move |_| {
+            let r = r.clone();
+            let seen = seen.clone();
+            async move {
+                let val = r.value().await;
+                seen.lock().unwrap().push(val);
+            }
+        }
+    })
+    .await;
+
+    r.update(|n| n + 1).await;
+    r.update_unchecked(|n| n * 10).await;
+
+    assert_eq!(vec![2, 20], *seen.lock().unwrap());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn async_reactive_inplace_updates_do_not_need_clone() {
+    use reactivate::{AsyncMerge, AsyncReactive};
+
+    #[derive(Hash)]
+    struct Counter(u32);
+
+    let r = AsyncReactive::new(Counter(0));
+    let seen: std::sync::Arc<std::sync::Mutex<Vec<u32>>> = Default::default();
+    r.add_observer({
+        let seen = seen.clone();
+        move |counter| seen.lock().unwrap().push(counter.0)
+    })
+    .await;
+
+    r.update_inplace(|counter| counter.0 += 1).await;
+    r.update_inplace(|_| {}).await;
+    r.update_inplace_unchecked(|counter| counter.0 += 1).await;
+
+    assert_eq!(vec![1, 2], *seen.lock().unwrap());
+
+    // no `Default` needed to merge either
+    #[derive(Clone, PartialEq)]
+    struct Id(u32);
+
+    let a = AsyncReactive::new(Id(1));
+    let b = AsyncReactive::new(2);
+    let d = (&a, &b).merge().await.derive(|(id, n)| id.0 + n).await;
+
+    a.set(Id(10)).await;
+    assert_eq!(12, d.value().await);
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn async_reactive_mirrors_reactive() {
+    use reactivate::{AsyncMerge, AsyncReactive};
+
+    let a = AsyncReactive::new(String::from("hazash"));
+    let b = AsyncReactive::new(0);
+    let d = (&a, &b).merge().await.derive(|(s, n)| s.len() + n).await;
+
+    let changes: std::sync::Arc<std::sync::Mutex<Vec<usize>>> = Default::default();
+    d.add_async_observer({
+        let changes = changes.clone();
+        move |val| {
+            let changes = changes.clone();
+            async move {
+                tokio::task::yield_now().await;
+                changes.lock().unwrap().push(val);
+            }
+        }
+    })
+    .await;
+
+    assert_eq!(6, d.value().await);
+
+    b.update(|_| 5).await;
+    b.update(|_| 5).await;
+    a.update_inplace(|s| s.push('!')).await;
+
+    assert_eq!(12, d.value().await);
+    assert_eq!(vec![11, 12], changes.lock().unwrap().clone());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn async_merge_does_not_miss_updates_racing_it() {
+    use reactivate::{AsyncMerge, AsyncReactive};
+    use std::sync::Arc;
+    use tokio::{sync::Notify, task::yield_now};
+
+    let a = AsyncReactive::new(0);
+    let b = AsyncReactive::new(0);
+
+    // keeps the observers of `a` locked by a notification until released
+    let release = Arc::new(Notify::new());
+    a.add_async_observer({
+        let release = release.clone();
+        move |_| {
+            let release = release.clone();
+            async move { release.notified().await }
+        }
+    })
+    .await;
+
+    let update = tokio::spawn({
+        let a = a.clone();
+        async move { a.update(|_| 1).await }
+    });
+    yield_now().await;
+
+    // the merge waits for the observers of `a` while `b` changes
+    let merge = tokio::spawn({
+        let (a, b) = (a.clone(), b.clone());
+        async move { (&a, &b).merge().await }
+    });
+    yield_now().await;
+    b.update(|_| 1).await;
+
+    release.notify_one();
+    update.await.unwrap();
+    let merged = merge.await.unwrap();
+
+    assert_eq!((1, 1), merged.value().await);
+    b.update(|_| 2).await;
+    assert_eq!((1, 2), merged.value().await);
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn async_merge_goes_up_to_16_tuples_like_merge() {
+    use reactivate::{AsyncMerge, AsyncReactive};
+
+    let r = AsyncReactive::new(1);
+    let merged = (
+        &r, &r, &r, &r, &r, &r, &r, &r, &r, &r, &r, &r, &r, &r, &r, &r,
+    )
+        .merge()
+        .await;
+
+    r.update(|_| 2).await;
+    assert_eq!(2, merged.value().await.15);
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn dropping_an_async_update_mid_notification_keeps_the_value_but_skips_observers() {
+    use reactivate::AsyncReactive;
+    use std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    let r = AsyncReactive::new(1);
+    let stalled = Arc::new(AtomicBool::new(false));
+    // hangs the first time only
+    r.add_async_observer({
+        let stalled = stalled.clone();
+        move |_| {
+            let first = !stalled.swap(true, Ordering::SeqCst);
+            async move {
+                if first {
+                    std::future::pending::<()>().await;
+                }
+            }
+        }
+    })
+    .await;
+    let d = r.derive(|n| n * 10).await;
+
+    let dropped = tokio::time::timeout(Duration::from_millis(10), r.update(|_| 2)).await;
+    assert!(dropped.is_err());
+
+    // the value was written, but the observers after the stalled one never ran
+    assert_eq!(2, r.value().await);
+    assert_eq!(10, d.value().await);
+
+    // the locks were released, and notifying again brings them up to date
+    r.notify().await;
+    assert_eq!(20, d.value().await);
+}
+
+#[test]
+fn broadcast_to_updates_all_targets() {
+    let source = Reactive::new(0);
+    let targets = [Reactive::new(0), Reactive::new(5), Reactive::new(0)];
+
+    let mut notifications = vec![];
+    for target in &targets {
+        let count = Reactive::new(0);
+        target.add_observer({
+            let count = count.clone();
+            move |_| count.update_unchecked(|n| n + 1)
+        });
+        notifications.push(count);
+    }
+
+    source.broadcast_to(&targets);
+
+    assert_eq!(
+        vec![0, 5, 0],
+        targets.iter().map(|t| t.value()).collect::<Vec<_>>()
+    );
+
+    source.update(|_| 5);
+
+    assert!(targets.iter().all(|t| t.value() == 5));
+    assert_eq!(
+        vec![1, 0, 1],
+        notifications.iter().map(|n| n.value()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn derive_index_tracks_element_and_bounds() {
+    use reactivate::ReactiveVecExt;
+
+    let r = Reactive::new(vec![1, 2]);
+    let d = r.derive_index(2);
+    let notifications = Reactive::new(0);
+    d.add_observer({
+        let notifications = notifications.clone();
+        move |_| notifications.update_unchecked(|n| n + 1)
+    });
+
+    assert_eq!(None, d.value());
+
+    r.update_inplace(|v| v.push(3));
+    assert_eq!(Some(3), d.value());
+
+    r.update_inplace(|v| v[0] = 100);
+    assert_eq!(Some(3), d.value());
+
+    r.update(|_| vec![]);
+    assert_eq!(None, d.value());
+
+    assert_eq!(2, notifications.value());
+}
+
+#[tokio::test]
+#[cfg(feature = "futures-signals")]
+async fn futures_signals_bridge_both_ways_without_echo() {
+    let r = Reactive::new(1);
+    let mutable = r.to_mutable();
+    let back = Reactive::from_signal(mutable.signal(), |fut| {
+        tokio::spawn(fut);
+    });
+
+    let notifications = Reactive::new(0);
+    r.add_observer({
+        let notifications = notifications.clone();
+        move |_| notifications.update_unchecked(|n| n + 1)
+    });
+
+    tokio::task::yield_now().await;
+    assert_eq!(1, back.value());
+
+    r.update(|_| 2);
+    assert_eq!(2, mutable.get());
+
+    tokio::task::yield_now().await;
+    assert_eq!(2, back.value());
+    assert_eq!(1, notifications.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn observe_on_runs_observer_on_dispatcher_thread() {
+    use std::sync::{mpsc, Arc, Mutex};
+
+    let (dispatcher, jobs) = mpsc::channel::<Box<dyn FnOnce() + Send>>();
+
+    let r = Reactive::new(0);
+    let seen: Arc<Mutex<Vec<(i32, std::thread::ThreadId)>>> = Default::default();
+    let handle = r.observe_on(dispatcher, {
+        let seen = seen.clone();
+        move |val| {
+            seen.lock()
+                .unwrap()
+                .push((*val, std::thread::current().id()))
+        }
+    });
+
+    std::thread::spawn({
+        let r = r.clone();
+        move || {
+            r.update(|_| 1);
+            r.update(|_| 2);
+        }
+    })
+    .join()
+    .unwrap();
+
+    assert!(seen.lock().unwrap().is_empty());
+
+    for job in jobs.try_iter() {
+        job();
+    }
+
+    let main = std::thread::current().id();
+    assert_eq!(vec![(1, main), (2, main)], seen.lock().unwrap().clone());
+
+    handle.remove();
+    r.update(|_| 3);
+    assert_eq!(0, jobs.try_iter().count());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn len_reactive_only_notifies_when_length_changes() {
+    use reactivate::{ReactiveHashMapExt, ReactiveVecExt};
+    use std::collections::HashMap;
+
+    let v = Reactive::new(vec![1, 2, 3]);
+    let v_len = v.len_reactive();
+    let v_notifications = Reactive::new(0);
+    v_len.add_observer({
+        let v_notifications = v_notifications.clone();
+        move |_| v_notifications.update_unchecked(|n| n + 1)
+    });
+
+    v.update_inplace(|v| v.swap(0, 2));
+    v.update_inplace(|v| v.push(4));
+
+    assert_eq!(4, v_len.value());
+    assert_eq!(1, v_notifications.value());
+
+    let m = Reactive::new(HashMap::from([("a", 1)]));
+    let m_len = m.len_reactive();
+    let m_empty = m.is_empty_reactive();
+
+    m.update_inplace_unchecked(|m| {
+        m.insert("a", 2);
+    });
+    assert_eq!(1, m_len.value());
+
+    m.update_inplace_unchecked(|m| m.clear());
+    assert_eq!(0, m_len.value());
+    assert!(m_empty.value());
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn add_observer_spawned_keeps_fifo_order_and_survives_shutdown() {
+    use std::sync::{Arc, Mutex};
+
+    let rt = tokio::runtime::Builder::new_multi_thread().build().unwrap();
+
+    let r = Reactive::new(0);
+    let seen: Arc<Mutex<Vec<i32>>> = Default::default();
+    r.add_observer_spawned(rt.handle(), {
+        let seen = seen.clone();
+        move |val| {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            seen.lock().unwrap().push(val);
+        }
+    });
+
+    for n in 1..=20 {
+        r.update(|_| n);
+    }
+
+    rt.shutdown_timeout(std::time::Duration::from_secs(5));
+    assert_eq!((1..=20).collect::<Vec<_>>(), seen.lock().unwrap().clone());
+
+    r.update(|_| 21);
+    r.update(|_| 22);
+    assert_eq!(20, seen.lock().unwrap().len());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn map_async_keeps_only_latest_result() {
+    let r = Reactive::new(1u64);
+    let d = r.map_async(&tokio::runtime::Handle::current(), |n| async move {
+        // older values take longer so they would finish last without cancellation
+        tokio::time::sleep(std::time::Duration::from_millis(50 / n)).await;
+        n * 10
+    });
+
+    assert_eq!(None, d.value());
+
+    r.update(|_| 2);
+    r.update(|_| 5);
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(Some(50), d.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn observe_into_removes_itself_once_receiver_is_dropped() {
+    let r = Reactive::new(0);
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let handle = r.observe_into(tx);
+
+    r.update(|n| n + 1);
+    assert_eq!(Ok(1), rx.try_recv());
+
+    drop(rx);
+    r.update(|n| n + 1);
+
+    // already removed by the failed send
+    assert!(!handle.remove());
+}
+
+#[tokio::test]
+#[cfg(feature = "tokio")]
+async fn can_bridge_tokio_watch_both_ways() {
+    let r = Reactive::new(1);
+    let rx = r.into_tokio_watch();
+
+    let mirror = Reactive::from_tokio_watch(rx, &tokio::runtime::Handle::current());
+    assert_eq!(1, mirror.value());
+
+    r.update(|n| n + 1);
+    tokio::task::yield_now().await;
+    assert_eq!(2, mirror.value());
+
+    r.update(|n| n + 1);
+    tokio::task::yield_now().await;
+    assert_eq!(3, mirror.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn subscription_receives_changes_across_threads() {
+    let r = Reactive::new(0);
+    let sub = r.subscribe_with_current();
+
+    let consumer = std::thread::spawn(move || sub.collect::<Vec<_>>());
+
+    for _ in 0..5 {
+        r.update(|n| n + 1);
+    }
+    drop(r);
+
+    assert_eq!(vec![0, 1, 2, 3, 4, 5], consumer.join().unwrap());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn bounded_subscription_keeps_latest_values() {
+    let r = Reactive::new(0);
+    let sub = r.subscribe_bounded(2);
+
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+
+    assert_eq!(Some(2), sub.try_recv());
+    assert_eq!(Some(3), sub.try_recv());
+    assert_eq!(None, sub.try_recv());
+}
+
+#[test]
+fn reactive_deque_reports_granular_changes() {
+    use reactivate::{DequeChange, ReactiveDeque};
+
+    let deque = ReactiveDeque::new();
+    let is_empty = deque.is_empty_reactive();
+    let (tx, rx) = std::sync::mpsc::channel();
+    deque.add_observer(move |change: &DequeChange<i32>| tx.send(change.clone()).unwrap());
+
+    deque.push_back(2);
+    deque.push_front(1);
+    deque.push_back(3);
+    assert_eq!(
+        (Some(1), Some(3), 3),
+        (deque.front(), deque.back(), deque.len())
+    );
+    assert!(!is_empty.value());
+
+    deque.retain(|n| n % 2 == 1);
+    assert_eq!(Some(3), deque.pop_back());
+    deque.clear();
+    assert_eq!(None, deque.pop_front());
+    deque.clear();
+    assert!(is_empty.value());
+
+    assert_eq!(
+        vec![
+            DequeChange::PushBack(2),
+            DequeChange::PushFront(1),
+            DequeChange::PushBack(3),
+            DequeChange::Retain(vec![2]),
+            DequeChange::PopBack(3),
+            DequeChange::Clear(vec![1]),
+        ],
+        rx.try_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn reactive_macro_expands_to_new_and_derives() {
+    let r = reactivate::reactive!(vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], r.value());
+
+    let d = reactivate::reactive!(vec![1, 2, 3] => |v: &Vec<i32>| v.len());
+    assert_eq!(3, d.value());
+
+    let d = reactivate::reactive!(10 => |v| v + 1 => |v| v * 2 => |v: &i32| v.to_string(),);
+    assert_eq!("22", d.value());
+}
+
+#[test]
+#[cfg(feature = "crossbeam")]
+fn can_select_over_crossbeam_subscriptions() {
+    let a = Reactive::new(0);
+    let b = Reactive::new(String::new());
+
+    let rx_a = a.subscribe_crossbeam(None);
+    let rx_b = b.subscribe_crossbeam(Some(1));
+
+    a.update(|n| n + 1);
+    b.update(|_| String::from("x"));
+
+    let mut from_a = vec![];
+    let mut from_b = vec![];
+    for _ in 0..2 {
+        crossbeam_channel::select! {
+            recv(rx_a) -> val => from_a.push(val.unwrap()),
+            recv(rx_b) -> val => from_b.push(val.unwrap()),
+        }
+    }
+
+    assert_eq!(vec![1], from_a);
+    assert_eq!(vec![String::from("x")], from_b);
+}
+
+#[test]
+#[cfg(feature = "crossbeam")]
+fn crossbeam_subscriptions_unregister_when_dropped() {
+    let r = Reactive::new(0);
+    let num_observers = |r: &Reactive<i32>| {
+        let mut n = 0;
+        r.with(|_, obs| n = obs.len());
+        n
+    };
+
+    let unbounded = r.subscribe_crossbeam(None);
+    let bounded = r.subscribe_crossbeam(Some(1));
+    let kept = bounded.receiver().clone();
+    assert_eq!(2, num_observers(&r));
+
+    r.update(|n| n + 1);
+    drop(unbounded);
+    drop(bounded);
+    assert_eq!(0, num_observers(&r));
+
+    r.update(|n| n + 1);
+    assert_eq!(Ok(1), kept.try_recv());
+    assert!(kept.try_recv().is_err());
+}
+
+#[test]
+fn clamp_between_follows_reactive_bounds() {
+    let r = Reactive::new(5.0);
+    let min = Reactive::new(0.0);
+    let max = Reactive::new(10.0);
+    let clamped = r.clamp_between(&min, &max);
+
+    r.update(|_| 12.0);
+    assert_eq!(10.0, clamped.value());
+
+    max.update(|_| 15.0);
+    assert_eq!(12.0, clamped.value());
+
+    r.update(|_| -3.0);
+    assert_eq!(0.0, clamped.value());
+
+    // max wins when the bounds cross
+    min.update(|_| 20.0);
+    assert_eq!(15.0, clamped.value());
+}
+
+#[test]
+fn combine_macro_matches_merge_and_derive() {
+    let a = Reactive::new(1);
+    let b = Reactive::new(2);
+    let c = Reactive::new(3);
+    let d = Reactive::new(4);
+    let e = Reactive::new(5);
+    let f = Reactive::new(6);
+    let g = Reactive::new(7);
+    let h = Reactive::new(String::from("8"));
+
+    let two = reactivate::combine!(a, b => |a, b| a * b);
+    let eight = reactivate::combine!(a, b, c, d, e, f, g, h => |a, b, c, d, e, f, g, h| {
+        format!("{}{}{}{}{}{}{}{}", a, b, c, d, e, f, g, h)
+    });
+    let merged = (&a, &b).merge().derive(|(a, b)| a * b);
+
+    assert_eq!(merged.value(), two.value());
+    assert_eq!("12345678", eight.value());
+
+    a.update(|_| 10);
+    h.update(|_| String::from("x"));
+
+    assert_eq!(merged.value(), two.value());
+    assert_eq!("10234567x", eight.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn iter_changes_yields_values_from_producer_thread() {
+    let r = Reactive::new(0);
+    let changes = r.iter_changes();
+
+    let producer = std::thread::spawn(move || {
+        for _ in 0..10 {
+            r.update(|n| n + 1);
+        }
+    });
+
+    assert_eq!(vec![1, 2, 3, 4, 5], changes.take(5).collect::<Vec<_>>());
+    producer.join().unwrap();
+}
+
+#[test]
+fn observe_distinct_by_only_fires_when_key_changes() {
+    let r = Reactive::new((String::from("a"), 0));
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    r.observe_distinct_by(
+        |(name, _)| name.len(),
+        move |(name, _)| tx.send(name.clone()).unwrap(),
+    );
+
+    r.update(|(name, n)| (name.clone(), n + 1));
+    r.update(|(_, n)| (String::from("b"), *n));
+    r.update(|(_, n)| (String::from("bc"), *n));
+    r.update(|(name, n)| (name.clone(), n + 1));
+    r.update(|(_, n)| (String::from("d"), *n));
+
+    assert_eq!(
+        vec![String::from("bc"), String::from("d")],
+        rx.try_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn threaded_observer_handles_values_in_order() {
+    let r = Reactive::new(0);
+    let seen: std::sync::Arc<std::sync::Mutex<Vec<i32>>> = Default::default();
+
+    let worker = r.add_threaded_observer({
+        let seen = seen.clone();
+        move |val| {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            seen.lock().unwrap().push(val);
+        }
+    });
+
+    for _ in 0..10 {
+        r.update(|n| n + 1);
+    }
+
+    worker.join().unwrap();
+    r.update(|n| n + 1);
+
+    assert_eq!((1..=10).collect::<Vec<_>>(), *seen.lock().unwrap());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn wait_for_blocks_until_predicate_holds() {
+    let r = Reactive::new(0);
+
+    assert_eq!(0, r.wait_for(|n| *n == 0));
+
+    let producer = std::thread::spawn({
+        let r = r.clone();
+        move || {
+            for _ in 0..100 {
+                r.update(|n| n + 1);
+            }
+        }
+    });
+
+    // the producer may already be past 50 by the time we start waiting
+    assert!(r.wait_for(|n| *n >= 50) >= 50);
+    producer.join().unwrap();
+
+    let start = std::time::Instant::now();
+    assert_eq!(
+        None,
+        r.wait_for_timeout(|n| *n > 100, std::time::Duration::from_millis(20))
+    );
+    assert!(start.elapsed() >= std::time::Duration::from_millis(20));
+}
+
+#[test]
+fn can_count_derived_descendants() {
+    let r = Reactive::new(10);
+    assert_eq!(0, r.descendant_count());
+
+    let a = r.derive(|val| val + 1);
+    let b = a.derive2(|val| val * 2, |val| val.to_string());
+    let _c = b.derive(|s| s.len());
+    let _d = r.derive(|val| val - 1);
+
+    assert_eq!(4, r.descendant_count());
+    assert_eq!(2, a.descendant_count());
+
+    a.clear_observers();
+    drop(b);
+    assert_eq!(2, r.descendant_count());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn wait_changed_returns_next_notified_value() {
+    let r = Reactive::new(0);
+
+    let waiter = std::thread::spawn({
+        let r = r.clone();
+        move || r.wait_changed()
+    });
+
+    // keep changing until the waiter has seen one of them
+    while !waiter.is_finished() {
+        r.update(|n| n + 1);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+
+    assert!(waiter.join().unwrap() >= 1);
+
+    assert_eq!(
+        None,
+        r.wait_changed_timeout(std::time::Duration::from_millis(10))
+    );
+}
+
+#[test]
+fn once_on_condition_fires_exactly_once() {
+    let r = Reactive::new(0);
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    let handle = r.once_on_condition(|n| *n >= 2, {
+        let tx = tx.clone();
+        move |n| tx.send(*n).unwrap()
+    });
+
+    r.update(|_| 1);
+    r.update(|_| 3);
+    r.update(|_| 0);
+    r.update(|_| 5);
+
+    assert_eq!(vec![3], rx.try_iter().collect::<Vec<_>>());
+    assert!(!handle.remove());
+
+    // already satisfied
+    let handle = r.once_on_condition(|n| *n == 5, move |n| tx.send(*n * 10).unwrap());
+    assert_eq!(vec![50], rx.try_iter().collect::<Vec<_>>());
+    assert!(!handle.remove());
+}
+
+#[test]
+fn once_on_condition_can_access_the_reactive_when_already_satisfied() {
+    let r = Reactive::new(5);
+
+    r.once_on_condition(|n| *n == 5, {
+        let r = r.clone();
+        move |n| {
+            let current = r.value();
+            r.update(|_| n + current)
+        }
+    });
+
+    assert_eq!(10, r.value());
+}
+
+#[test]
+fn constant_reactives_ignore_removable_observers() {
+    let r = Reactive::constant(10);
+
+    let handle = r.add_observer_with_context("ctx", |_, _| {});
+    let once = r.once_on_condition(|n| *n > 10, |_| {});
+    let distinct = r.observe_distinct_by(|n| n % 2, |_| {});
+
+    assert!(!r.has_observers());
+    assert!(!handle.remove());
+    assert!(!once.remove());
+    assert!(!distinct.remove());
+}
+
+#[test]
+#[cfg(feature = "graph-introspection")]
+fn to_dot_describes_derive_graph() {
+    let r = Reactive::new(String::from("\"hi\""));
+    let len = r.derive(|s| s.len());
+    let _even = len.derive(|n| n % 2 == 0);
+    let _upper = r.derive(|s| s.to_uppercase());
+
+    let dot = r.to_dot();
+
+    assert!(dot.starts_with("digraph reactivate {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains(r#"[label="alloc::string::String: \"\\\"hi\\\"\""];"#));
+    assert_eq!(1, dot.matches(r#"[label="usize"];"#).count());
+    assert_eq!(1, dot.matches(r#"[label="bool"];"#).count());
+    assert_eq!(3, dot.matches(" -> ").count());
+}
+
+#[test]
+#[cfg(feature = "rwlock")]
+fn rw_reactive_readers_do_not_block_each_other() {
+    use reactivate::RwReactive;
+
+    let r = RwReactive::new(vec![1, 2, 3]);
+    let len = r.derive(|v| v.len());
+    let guard = r.borrowed();
+
+    let reader = std::thread::spawn({
+        let r = r.clone();
+        move || r.value()
+    });
+
+    assert_eq!(vec![1, 2, 3], reader.join().unwrap());
+    assert_eq!(3, guard.len());
+    drop(guard);
+
+    r.update_inplace(|v| v.push(4));
+    assert_eq!(4, len.value());
+}
+
+#[test]
+#[cfg(feature = "rwlock")]
+fn rw_reactive_observers_can_read_their_reactive() {
+    use reactivate::RwReactive;
+    use std::sync::{Arc, Mutex};
+
+    let r = RwReactive::new(1);
+    let seen: Arc<Mutex<Vec<i32>>> = Default::default();
+    r.add_observer({
+        let r = r.clone();
+        let seen = seen.clone();
+        move |_| seen.lock().unwrap().push(r.value())
+    });
+
+    r.set(2);
+    r.update(|n| n + 1);
+    r.update_inplace(|n| *n += 1);
+    r.update_unchecked(|n| n + 1);
+    r.update_inplace_unchecked(|n| *n += 1);
+    r.notify();
+
+    assert_eq!(vec![2, 3, 4, 5, 6, 6], seen.lock().unwrap().clone());
+}
+
+#[test]
+fn merge_latest_waits_for_both_sources() {
+    use std::sync::{Arc, Mutex};
+
+    let a = Reactive::new(0);
+    let b = Reactive::new(String::new());
+    let merged = a.merge_latest(&b);
+
+    let changes: Arc<Mutex<Vec<_>>> = Default::default();
+    merged.add_observer({
+        let changes = changes.clone();
+        move |val| changes.lock().unwrap().push(val.clone())
+    });
+
+    a.update(|_| 1);
+    a.update(|_| 2);
+    assert!(changes.lock().unwrap().is_empty());
+
+    b.update(|_| String::from("x"));
+    a.update(|_| 3);
+
+    assert_eq!(
+        vec![Some((2, String::from("x"))), Some((3, String::from("x")))],
+        changes.lock().unwrap().clone()
+    );
+}
+
+#[test]
+fn merge_with_defaults_tracks_both_sides() {
+    let a: Reactive<Option<usize>> = Reactive::new(None);
+    let b: Reactive<Option<&str>> = Reactive::new(None);
+    let merged = a.merge_with_defaults(&b);
+
+    assert_eq!((None, None), merged.value());
+
+    b.update(|_| Some("b"));
+    assert_eq!((None, Some("b")), merged.value());
+
+    a.update(|_| Some(1));
+    b.update(|_| None);
+    assert_eq!((Some(1), None), merged.value());
+}
+
+#[test]
+fn second_init_once_is_a_no_op() {
+    let r: Reactive<usize> = Reactive::default();
+    let d = r.derive(|n| n * 2);
+
+    assert!(r.init_once(21));
+    assert!(!r.init_once(50));
+
+    assert_eq!(21, r.value());
+    assert_eq!(42, d.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn init_once_is_won_by_exactly_one_thread() {
+    let r: Reactive<usize> = Reactive::default();
+
+    let winners = (1..=8)
+        .map(|i| {
+            let r = r.clone();
+            std::thread::spawn(move || r.init_once(i))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .filter(|won| *won)
+        .count();
+
+    assert_eq!(1, winners);
+    assert_ne!(0, r.value());
+}
+
+#[test]
+fn side_effect_returns_the_same_reactive() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let r = Reactive::new(10).side_effect({
+        let calls = calls.clone();
+        move |_| {
+            calls.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+    let d = r.derive(|n| n + 1);
+
+    r.update(|n| n * 2);
+
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+    assert_eq!(21, d.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn set_executor_runs_observers_through_the_executor() {
+    use std::sync::{mpsc, Arc, Mutex};
+
+    let (tx, jobs) = mpsc::channel::<reactivate::Job>();
+    let tx = Mutex::new(tx);
+
+    let r = Reactive::new(0);
+    let d = r.derive(|n| n * 10);
+
+    r.set_executor(move |job| tx.lock().unwrap().send(job).unwrap());
+
+    let seen: Arc<Mutex<Vec<i32>>> = Default::default();
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+
+    r.update(|_| 1);
+    r.update(|_| 2);
+
+    // nothing runs until the executor gets to the jobs
+    assert_eq!(0, d.value());
+    assert!(seen.lock().unwrap().is_empty());
+
+    std::thread::spawn(move || jobs.try_iter().for_each(|job| job()))
+        .join()
+        .unwrap();
+
+    assert_eq!(20, d.value());
+    assert_eq!(vec![1, 2], seen.lock().unwrap().clone());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn set_executor_inline_jobs_can_update_the_reactive() {
+    let r = Reactive::new(0);
+    let d = r.derive(|n| n * 10);
+
+    r.set_executor(|job| job());
+    r.add_observer({
+        let r = r.clone();
+        move |val| {
+            if *val < 3 {
+                r.update(|n| n + 1);
+            }
+        }
+    });
+
+    r.update(|_| 1);
+
+    assert_eq!(3, r.value());
+    assert_eq!(30, d.value());
+}
+
+#[test]
+#[cfg(feature = "arcswap")]
+fn reactive_cell_readers_keep_their_snapshot() {
+    use reactivate::ReactiveCell;
+
+    let r = ReactiveCell::new(vec![1, 2, 3]);
+    let sum = r.derive(|nums| nums.iter().sum::<i32>());
+    let snapshot = r.value_arc();
+
+    r.update_inplace(|nums| nums.push(4));
+    r.update_inplace(|_| {});
+
+    assert_eq!(vec![1, 2, 3], *snapshot);
+    assert_eq!(vec![1, 2, 3, 4], r.value());
+    assert_eq!(10, sum.value());
+
+    let reader = std::thread::spawn({
+        let r = r.clone();
+        move || r.value_arc().len()
+    });
+    assert_eq!(4, reader.join().unwrap());
+}
+
+#[test]
+fn on_drop_hooks_run_in_order_after_the_last_clone_is_dropped() {
+    use std::sync::{Arc, Mutex};
+
+    let calls: Arc<Mutex<Vec<&str>>> = Default::default();
+
+    let r = Reactive::new(0);
+    r.on_drop({
+        let calls = calls.clone();
+        move || calls.lock().unwrap().push("first")
+    });
+    r.on_drop({
+        let calls = calls.clone();
+        move || calls.lock().unwrap().push("second")
+    });
+
+    let r2 = r.clone();
+    drop(r);
+    assert!(calls.lock().unwrap().is_empty());
+
+    drop(r2);
+    assert_eq!(vec!["first", "second"], calls.lock().unwrap().clone());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn coalescing_skips_intermediate_values_while_notifying() {
+    use std::sync::{mpsc, Arc, Mutex};
+
+    let r = Reactive::new(0);
+    let seen: Arc<Mutex<Vec<i32>>> = Default::default();
+    let (entered_tx, entered_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let release_rx = Mutex::new(release_rx);
+
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| {
+            seen.lock().unwrap().push(*val);
+            if *val == 1 {
+                entered_tx.send(()).unwrap();
+                release_rx.lock().unwrap().recv().unwrap();
+            }
+        }
+    });
+
+    let coalescing = r.coalescing();
+    let notifier = std::thread::spawn({
+        let coalescing = coalescing.clone();
+        move || coalescing.update(|_| 1)
+    });
+
+    // the first notification is in progress, these only mark the reactive as dirty
+    entered_rx.recv().unwrap();
+    coalescing.update(|_| 2);
+    coalescing.update(|_| 3);
+    coalescing.update(|_| 4);
+    release_tx.send(()).unwrap();
+
+    notifier.join().unwrap();
+
+    assert_eq!(vec![1, 4], seen.lock().unwrap().clone());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn coalescing_recovers_from_panicking_observers() {
+    use std::sync::{Arc, Mutex};
+
+    let r = Reactive::new(0);
+    let seen: Arc<Mutex<Vec<i32>>> = Default::default();
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| {
+            assert_ne!(1, *val);
+            seen.lock().unwrap().push(*val);
+        }
+    });
+
+    let coalescing = r.coalescing();
+    std::thread::spawn({
+        let coalescing = coalescing.clone();
+        move || coalescing.update(|_| 1)
+    })
+    .join()
+    .unwrap_err();
+
+    coalescing.update(|_| 2);
+    assert_eq!(vec![2], seen.lock().unwrap().clone());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn atomic_reactive_notifies_only_on_change() {
+    use reactivate::AtomicReactive;
+
+    let r = AtomicReactive::new(false);
+    let d = r.derive(|flag| if *flag { "on" } else { "off" });
+
+    assert!(!r.update(|flag| !flag));
+    assert!(r.update(|_| true));
+
+    assert!(r.value());
+    assert_eq!("on", d.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn atomic_reactive_concurrent_updates_are_not_lost() {
+    use reactivate::AtomicReactive;
+    use std::sync::{Arc, Mutex};
+
+    let r = AtomicReactive::new(0u64);
+    let last_seen: Arc<Mutex<u64>> = Default::default();
+    r.add_observer({
+        let last_seen = last_seen.clone();
+        move |val| {
+            let mut last_seen = last_seen.lock().unwrap();
+            assert!(*val >= *last_seen);
+            *last_seen = *val;
+        }
+    });
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let r = r.clone();
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    r.update(|n| n + 1);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(4000, r.value());
+    assert_eq!(4000, *last_seen.lock().unwrap());
+}
+
+#[test]
+fn transform_and_try_transform_follow_the_source() {
+    let r: Reactive<u16> = Reactive::new(200);
+    let wide: Reactive<u32> = r.transform();
+    let narrow: Reactive<Result<u8, std::num::TryFromIntError>> = r.try_transform();
+
+    assert_eq!(200, wide.value());
+    assert_eq!(Ok(200), narrow.value());
+
+    r.update(|_| 300);
+
+    assert_eq!(300, wide.value());
+    assert!(narrow.value().is_err());
+}
+
+#[test]
+fn deep_copy_is_independent_of_the_original() {
+    let r = Reactive::new(vec![1, 2]);
+    let d = r.derive(|v| v.len());
+
+    let copy = r.deep_copy();
+    copy.update_inplace(|v| v.push(3));
+
+    assert_eq!(vec![1, 2], r.value());
+    assert_eq!(2, d.value());
+    assert_eq!(vec![1, 2, 3], copy.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn panicking_observer_poisons_but_does_not_break_the_reactive() {
+    use reactivate::ReactiveError;
+
+    let r = Reactive::new(0);
+    let d = r.derive(|n| n * 2);
+    r.add_observer(|n| {
+        if *n == 3 {
+            panic!("observer bug");
+        }
+    });
+
+    let result = std::thread::spawn({
+        let r = r.clone();
+        move || r.update(|_| 3)
+    })
+    .join();
+    assert!(result.is_err());
+
+    assert!(r.is_poisoned());
+    assert_eq!(Err(ReactiveError::Poisoned), r.try_value());
+    assert_eq!(Err(ReactiveError::Poisoned), r.try_update(|_| 4));
+    assert_eq!(3, r.value());
+
+    r.recover();
+
+    assert_eq!(Ok(3), r.try_value());
+    assert_eq!(Ok(()), r.try_update(|_| 5));
+    assert_eq!(10, d.value());
+}
+
+#[test]
+fn zip_fold_starts_over_from_init_on_every_change() {
+    let a = Reactive::new(String::from("a"));
+    let b = Reactive::new(String::from("b"));
+
+    let joined = Reactive::zip_fold(&[&a, &b], String::from(">"), |acc, s| acc + s);
+    assert_eq!(">ab", joined.value());
+
+    a.update(|_| String::from("x"));
+    b.update(|_| String::from("y"));
+    assert_eq!(">xy", joined.value());
+
+    let empty: Reactive<usize> =
+        Reactive::zip_fold(&[] as &[&Reactive<usize>], 7, |acc, n| acc + n);
+    assert_eq!(7, empty.value());
+}
+
+#[test]
+fn either_notifies_on_every_change_of_either_source() {
+    use reactivate::Either;
+    use std::sync::{Arc, Mutex};
+
+    let a = Reactive::new(0);
+    let b = Reactive::new(String::new());
+    let either = a.either(&b);
+
+    let changes: Arc<Mutex<Vec<_>>> = Default::default();
+    either.add_observer({
+        let changes = changes.clone();
+        move |val| changes.lock().unwrap().push(val.clone())
+    });
+
+    b.update(|_| String::from("x"));
+    a.update(|_| 1);
+    b.update(|_| String::from("y"));
+
+    assert_eq!(
+        vec![
+            Either::Right(String::from("x")),
+            Either::Left(1),
+            Either::Right(String::from("y"))
+        ],
+        changes.lock().unwrap().clone()
+    );
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn timeout_variants_give_up_while_an_observer_is_stuck() {
+    use reactivate::ReactiveError;
+    use std::{sync::mpsc, time::Duration};
+
+    let r = Reactive::new(0);
+    let (entered_tx, entered_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let release_rx = std::sync::Mutex::new(release_rx);
+
+    r.add_observer(move |val| {
+        if *val == 1 {
+            entered_tx.send(()).unwrap();
+            release_rx.lock().unwrap().recv().unwrap();
+        }
+    });
+
+    let stuck = std::thread::spawn({
+        let r = r.clone();
+        move || r.set(1)
+    });
+    entered_rx.recv().unwrap();
+
+    let timeout = Duration::from_millis(20);
+    // the value itself isn't locked while the observers run, only the observers are
+    assert_eq!(Ok(1), r.value_timeout(timeout));
+    assert_eq!(Err(ReactiveError::Timeout), r.set_timeout(timeout, 5));
+    assert_eq!(
+        Err(ReactiveError::Timeout),
+        r.update_timeout(timeout, |n| n + 1)
+    );
+
+    release_tx.send(()).unwrap();
+    stuck.join().unwrap();
+
+    assert_eq!(Ok(1), r.value_timeout(timeout));
+    assert_eq!(Ok(()), r.update_timeout(timeout, |n| n + 1));
+    assert_eq!(2, r.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn debounce_inplace_only_hands_over_the_last_value() {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    let r = Reactive::new(vec![0]);
+    let debounced = r.debounce_inplace(Duration::from_millis(30));
+
+    let seen: Arc<Mutex<Vec<Vec<i32>>>> = Default::default();
+    debounced.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(val.clone())
+    });
+
+    for n in 1..=5 {
+        r.update_inplace(|v| v.push(n));
+    }
+
+    std::thread::sleep(Duration::from_millis(200));
+
+    assert_eq!(vec![vec![0, 1, 2, 3, 4, 5]], seen.lock().unwrap().clone());
+}
+
+#[test]
+fn add_observer_on_detects_rising_edges() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let r = Reactive::new(false);
+    let rising = Arc::new(AtomicUsize::new(0));
+
+    r.add_observer_on(|old, new| !old && *new, {
+        let rising = rising.clone();
+        move |_| {
+            rising.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    r.set(false);
+    r.set(true);
+    r.set(true);
+    r.set(false);
+    r.set(true);
+
+    assert_eq!(2, rising.load(Ordering::SeqCst));
+}
+
+#[test]
+fn reactive_is_a_single_pointer_wide() {
+    use std::mem::size_of;
+
+    assert_eq!(size_of::<usize>(), size_of::<Reactive<u8>>());
+    assert_eq!(size_of::<usize>(), size_of::<Reactive<String>>());
+    // the constness is part of the shared state too
+    assert_eq!(
+        size_of::<Reactive<u8>>(),
+        std::mem::size_of_val(&Reactive::constant(1u8))
+    );
+    assert!(Reactive::constant(1).clone().is_constant());
+}
+
+#[test]
+fn observer_handle_outlives_the_original_handle_but_not_the_reactive() {
+    let r = Reactive::new(10);
+    let clone = r.clone();
+
+    let first = r.add_observer_with_context((), |_, _| {});
+    let second = r.add_observer_with_context((), |_, _| {});
+    drop(r);
+
+    // `clone` still keeps the shared state alive
+    assert!(first.remove());
+
+    drop(clone);
+    assert!(!second.remove());
+}
+
+#[test]
+fn can_inspect_observers() {
+    let r = Reactive::new(10);
+    assert_eq!(0, r.inspect_observers(|observers| observers.len()));
+
+    let _d = r.derive(|val| val + 1);
+    let handle = r.add_observer_with_context((), |_, _| {});
+    assert_eq!(2, r.inspect_observers(|observers| observers.len()));
+
+    handle.remove();
+    assert_eq!(1, r.inspect_observers(|observers| observers.len()));
+}
+
+#[test]
+fn has_observer_matching_checks_ids_and_type_names() {
+    fn audit(_: &mut Vec<i32>, _: &i32) {}
+
+    let r = Reactive::new(10);
+    assert!(!r.has_observer_matching(|_| true));
+
+    let first = r.add_observer_with_context((), |_, _| {});
+    r.add_observer_ctx(Vec::new(), audit);
+    let _d = r.derive(|val| val + 1);
+    let last = r.add_observer_with_context((), |_, _| {});
+
+    assert!(r.has_observer_matching(|info| info.id() == first.id()));
+    assert!(r.has_observer_matching(|info| info.id() == last.id()));
+    assert_ne!(first.id(), last.id());
+
+    assert!(r.has_observer_matching(|info| info.type_name().contains("add_observer_ctx")));
+    assert!(r.has_observer_matching(|info| info.type_name().contains("derive")));
+    assert!(!r.has_observer_matching(|info| info.type_name().contains("derive2")));
+
+    first.remove();
+    assert!(!r.has_observer_matching(|info| info.id() == first.id()));
+    assert!(r.has_observer_matching(|info| info.id() == last.id()));
+}
+
+#[test]
+fn has_observers_tracks_registered_observers() {
+    let r = Reactive::new(10);
+    assert!(!r.has_observers());
+
+    let handle = r.add_observer_with_context((), |_, _| {});
+    assert!(r.has_observers());
+
+    handle.remove();
+    assert!(!r.has_observers());
+
+    let _d = r.derive(|val| val + 1);
+    assert!(r.has_observers());
+
+    r.clear_observers();
+    assert!(!r.has_observers());
+}
+
+#[test]
+fn sync_to_mirrors_updates_until_removed() {
+    let source = Reactive::new(String::from("a"));
+    let target = Reactive::new(String::new());
+    let lengths = Reactive::new(0);
+
+    let handle = source.sync_to(&target);
+    source.sync_derived_to(&lengths, |s| s.len());
+
+    // the current value is not copied over
+    assert_eq!("", target.value());
+
+    source.update(|s| format!("{s}b"));
+    assert_eq!("ab", target.value());
+    assert_eq!(2, lengths.value());
+
+    assert!(handle.remove());
+    source.update(|s| format!("{s}c"));
+    assert_eq!("ab", target.value());
+    assert_eq!(3, lengths.value());
+}
+
+#[test]
+fn observers_can_read_the_reactive_they_observe() {
+    use std::sync::{Arc, Mutex};
+
+    let r = Reactive::new(0);
+    let seen = Arc::new(Mutex::new(vec![]));
+
+    r.add_observer({
+        let r = r.clone();
+        let seen = seen.clone();
+        move |val| {
+            // would deadlock (threadsafe) or panic (local) if the value was still locked
+            assert_eq!(*val, r.value());
+            r.with_value(|curr| seen.lock().unwrap().push(*curr));
+        }
+    });
+
+    r.set(1);
+    r.update(|n| n + 1);
+    r.update_unchecked(|n| n + 1);
+    r.update_inplace_eq(|n| *n += 1);
+    r.notify();
+
+    assert_eq!(vec![1, 2, 3, 4, 4], seen.lock().unwrap().clone());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn unlocked_notifications_keep_update_order() {
+    use std::sync::{Arc, Mutex};
+
+    let r = Reactive::new(0);
+    let seen = Arc::new(Mutex::new(vec![]));
+
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..250 {
+                    r.update(|n| n + 1);
+                }
+            });
+        }
+    });
+
+    assert_eq!((1..=1000).collect::<Vec<_>>(), *seen.lock().unwrap());
+}
+
+#[test]
+fn combine_reduce_keeps_the_order_of_the_inputs() {
+    let words = ["a", "b", "c", "d", "e"].map(|w| Reactive::new(String::from(w)));
+    let inputs = words.iter().collect::<Vec<_>>();
+
+    // concatenation is associative but not commutative
+    let joined = Reactive::combine_reduce(&inputs, String::new(), |acc, w| acc + w);
+    assert_eq!("abcde", joined.value());
+
+    words[3].update(|_| String::from("D"));
+    words[0].update(|_| String::from("A"));
+    assert_eq!("AbcDe", joined.value());
+
+    let empty = Reactive::combine_reduce(&[], 0, |a, b| a + b);
+    assert_eq!(0, empty.value());
+}
+
+#[test]
+fn observers_can_update_their_own_reactive() {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum State {
+        Idle,
+        Connecting,
+        Connected,
+        Ready,
+    }
+
+    let state = Reactive::new(State::Idle);
+    let seen = Arc::new(Mutex::new(vec![]));
+
+    // auto transitions
+    state.add_observer({
+        let state = state.clone();
+        move |s| match s {
+            State::Connected => state.update(|_| State::Ready),
+            State::Connecting => state.update(|_| State::Connected),
+            _ => {}
+        }
+    });
+    state.add_observer({
+        let seen = seen.clone();
+        move |s| seen.lock().unwrap().push(*s)
+    });
+
+    state.update(|_| State::Connecting);
+
+    // every deferred update got its own full pass, in order
+    assert_eq!(
+        vec![State::Connecting, State::Connected, State::Ready],
+        *seen.lock().unwrap()
+    );
+    assert_eq!(State::Ready, state.value());
+
+    // and the reactive is usable as usual afterwards
+    state.set(State::Idle);
+    assert_eq!(State::Idle, seen.lock().unwrap()[3]);
+}
+
+#[test]
+#[should_panic(expected = "observers updated their own reactive")]
+fn endless_self_updates_panic() {
+    let r = Reactive::new(0);
+    r.add_observer({
+        let r = r.clone();
+        move |_| r.update(|n| n + 1)
+    });
+
+    r.update(|n| n + 1);
+}
+
+#[test]
+fn observers_can_update_their_own_reactive_in_place() {
+    use reactivate::FnvBuildHasher;
+    use std::sync::{Arc, Mutex};
+
+    // each one bumps the value through a different method
+    let bumps: Vec<fn(&Reactive<i32>)> = vec![
+        |r| r.update_inplace(|n| *n += 1),
+        |r| r.update_inplace_unchecked(|n| *n += 1),
+        |r| r.update_inplace_with_hasher(&FnvBuildHasher, |n| *n += 1),
+        |r| {
+            r.with(|n, observers| {
+                // the observers are busy notifying the current value
+                assert!(observers.is_empty());
+                *n += 1;
+            })
+        },
+    ];
+
+    for bump in bumps {
+        let r = Reactive::new(0);
+        let seen = Arc::new(Mutex::new(vec![]));
+
+        r.add_observer({
+            let r = r.clone();
+            move |n| {
+                if *n == 1 {
+                    bump(&r)
+                }
+            }
+        });
+        r.add_observer({
+            let seen = seen.clone();
+            move |n| seen.lock().unwrap().push(*n)
+        });
+
+        r.set(1);
+        assert_eq!(vec![1, 2], *seen.lock().unwrap());
+        assert_eq!(2, r.value());
+    }
+}
+
+#[test]
+fn observers_can_notify_locked_their_own_reactive() {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    };
+
+    let r = Reactive::new(0);
+    let seen = Arc::new(Mutex::new(vec![]));
+    let odd_call = AtomicBool::new(false);
+
+    // every other call notifies again
+    r.add_observer({
+        let r = r.clone();
+        move |_| {
+            if !odd_call.fetch_xor(true, Ordering::SeqCst) {
+                r.notify_locked();
+            }
+        }
+    });
+    r.add_observer({
+        let seen = seen.clone();
+        move |n| seen.lock().unwrap().push(*n)
+    });
+
+    r.set(1);
+    // the value is still locked while these observers run
+    r.update_inplace_unchecked(|n| *n = 2);
+    r.notify_locked();
+
+    assert_eq!(vec![1, 1, 2, 2, 2, 2], *seen.lock().unwrap());
+}
+
+#[test]
+fn merge_observers_can_update_the_merged_reactives() {
+    let a = Reactive::new(0);
+    let b = Reactive::new(0);
+    let merged = (&a, &b).merge();
+
+    // b follows a
+    merged.add_observer({
+        let b = b.clone();
+        move |(a, _)| b.update(|_| *a)
+    });
+
+    a.update(|_| 5);
+
+    assert_eq!(5, b.value());
+    assert_eq!((5, 5), merged.value());
+}
+
+#[test]
+fn relay_passes_values_through_and_reports_changes() {
+    use std::sync::{Arc, Mutex};
+
+    let r = Reactive::new(1);
+    let seen = Arc::new(Mutex::new(vec![]));
+
+    let relay = r.relay({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+    let d = relay.derive(|val| val * 10);
+
+    r.update(|n| n + 1);
+    r.set(2); // same value, not reported
+    r.update(|n| n + 1);
+
+    assert_eq!(3, relay.value());
+    assert_eq!(30, d.value());
+    assert_eq!(vec![2, 3], *seen.lock().unwrap());
+    assert_eq!(2, r.descendant_count());
+}
+
+#[test]
+fn with_mut_always_notifies_and_returns_the_result() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let r = Reactive::new(String::from("ab"));
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    r.add_observer({
+        let r = r.clone();
+        let calls = calls.clone();
+        move |val| {
+            assert_eq!(*val, r.value());
+            calls.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    assert_eq!(Some('b'), r.with_mut(|s| s.pop()));
+    assert_eq!(2, r.with_mut(|s| s.len() + 1)); // unchanged, still notifies
+
+    assert_eq!("a", r.value());
+    assert_eq!(2, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn local_reactive_works_alongside_reactive() {
+    use reactivate::LocalReactive;
+    use std::{cell::RefCell, rc::Rc};
+
+    let shared = Reactive::new(1);
+    let local = LocalReactive::new(1);
+
+    // observers of a local reactive don't need to be `Send`
+    let log = Rc::new(RefCell::new(vec![]));
+    local.add_observer({
+        let log = log.clone();
+        let local = local.clone();
+        move |val| {
+            assert_eq!(*val, local.value());
+            log.borrow_mut().push(*val);
+        }
+    });
+    let doubled = local.derive(|val| val * 2);
+
+    shared.add_observer({
+        let shared = shared.clone();
+        move |val| assert_eq!(*val, shared.value())
+    });
+
+    local.update(|n| n + 1);
+    local.set(2);
+    local.update_unchecked(|n| n + 1);
+    shared.update(|n| n + 1);
+
+    assert_eq!(vec![2, 2, 3], *log.borrow());
+    assert_eq!(6, doubled.value());
+    assert_eq!(2, shared.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn local_and_sync_reactives_have_the_whole_reactive_api() {
+    use reactivate::{LocalReactive, SyncReactive};
+    use std::{cell::Cell, rc::Rc};
+
+    let dropped = Rc::new(Cell::new(false));
+    let local = LocalReactive::new(1);
+    local.on_drop({
+        let dropped = dropped.clone();
+        move || dropped.set(true)
+    });
+    let label = local.derive2(|n| n * 10, |n| format!("{}!", n));
+
+    assert!(local.update_if(|n| *n < 5, |n| n + 1));
+    assert_eq!("20!", label.value());
+    assert_eq!(1, local.descendant_count());
+
+    drop(local);
+    assert!(dropped.get());
+
+    let sync = SyncReactive::new(1);
+    let sum = sync.derive2(|n| n * 10, |n| n + 1);
+    assert!(!sync.update_if(|n| *n > 5, |n| n + 1));
+    sync.update_inplace(|n| *n += 1);
+    assert_eq!(21, sum.value());
+    assert_eq!(1, sync.descendant_count());
+}
+
+#[test]
+fn local_reactive_observers_can_update_their_own_reactive() {
+    use reactivate::LocalReactive;
+    use std::{cell::RefCell, rc::Rc};
+
+    let r = LocalReactive::new(0);
+    let log = Rc::new(RefCell::new(vec![]));
+    r.add_observer({
+        let r = r.clone();
+        move |val| {
+            if *val < 3 {
+                r.update(|n| n + 1);
+            }
+        }
+    });
+    r.add_observer({
+        let log = log.clone();
+        move |val| log.borrow_mut().push(*val)
+    });
+    let doubled = r.derive(|n| n * 2);
+
+    r.set(1);
+    assert_eq!(3, r.value());
+    assert_eq!(vec![1, 2, 3], *log.borrow());
+    assert_eq!(6, doubled.value());
+
+    // in-place updates lend the value to the observers, which can still read it
+    r.add_observer({
+        let r = r.clone();
+        move |val| assert_eq!(*val, r.value())
+    });
+    r.update_inplace(|n| *n += 1);
+    assert_eq!(vec![1, 2, 3, 4], *log.borrow());
+}
+
+#[test]
+fn local_reactives_merge_and_derive_with_reactives() {
+    use reactivate::LocalReactive;
+
+    let local = LocalReactive::new(vec![1]);
+    let shared = Reactive::new(String::from("a"));
+
+    let merged = (&local, &shared).merge();
+    let len = local.derive_reactive(|v| v.len());
+
+    local.update_inplace(|v| v.push(2));
+    local.update_inplace(|v| v.sort());
+    shared.update(|_| String::from("b"));
+
+    assert_eq!((vec![1, 2], String::from("b")), merged.value());
+    assert_eq!(2, len.value());
+
+    #[cfg(not(feature = "threadsafe"))]
+    {
+        let upper = shared.derive_local(|s| s.to_uppercase());
+        shared.update(|_| String::from("c"));
+        assert_eq!("C", upper.value());
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn sync_reactives_merge_into_a_sync_reactive() {
+    use reactivate::{MergeSync, SyncReactive};
+    use std::sync::{Arc, Mutex};
+
+    let name = SyncReactive::new(String::from("a"));
+    let jobs = SyncReactive::new(1);
+    let failed = SyncReactive::new(0);
+
+    let merged = ((&name, &jobs), &failed, &jobs).merge_sync();
+    let seen: Arc<Mutex<Vec<(i32, i32)>>> = Default::default();
+    merged.add_observer({
+        let merged = merged.clone();
+        let seen = seen.clone();
+        move |val| {
+            // the merged value is released before its observers are called
+            merged.with_value(|curr| assert_eq!(val, curr));
+            seen.lock().unwrap().push((val.0 .1, val.2));
+        }
+    });
+    let ok = merged.derive(|((_, jobs), failed, _)| jobs - failed);
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let jobs = jobs.clone();
+            std::thread::spawn(move || jobs.update(|n| n + 1))
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    failed.set(2);
+    name.update(|_| String::from("b"));
+
+    assert_eq!(((String::from("b"), 5), 2, 5), merged.value());
+    assert_eq!(3, ok.value());
+
+    // `jobs` is merged twice, so each of its changes notifies twice
+    let seen = seen.lock().unwrap();
+    assert_eq!(10, seen.len());
+    assert_eq!([(2, 1), (2, 2)], seen[..2]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn sync_reactive_observers_can_update_their_own_reactive() {
+    use reactivate::SyncReactive;
+    use std::sync::{Arc, Mutex};
+
+    let r = SyncReactive::new(0);
+    let seen: Arc<Mutex<Vec<i32>>> = Default::default();
+    r.add_observer({
+        let r = r.clone();
+        move |val| {
+            if *val < 3 {
+                r.update(|n| n + 1);
+            }
+        }
+    });
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+    let doubled = r.derive(|n| n * 2);
+
+    std::thread::spawn({
+        let r = r.clone();
+        move || r.set(1)
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(3, r.value());
+    assert_eq!(vec![1, 2, 3], *seen.lock().unwrap());
+    assert_eq!(6, doubled.value());
+
+    r.update_unchecked(|n| n - 2);
+    assert_eq!(3, r.value());
+    assert_eq!(vec![1, 2, 3, 1, 2, 3], *seen.lock().unwrap());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn sync_reactives_can_be_shared_in_any_build() {
+    use reactivate::SyncReactive;
+    use std::sync::{Arc, Mutex};
+
+    let r = SyncReactive::new(vec![1]);
+    let seen: Arc<Mutex<Vec<usize>>> = Default::default();
+    r.add_observer({
+        let r = r.clone();
+        let seen = seen.clone();
+        move |v| {
+            // the value is released before the observers are called
+            r.with_value(|curr| assert_eq!(v, curr));
+            seen.lock().unwrap().push(v.len());
+        }
+    });
+    let sum = r.derive(|v| v.iter().sum::<i32>());
+
+    std::thread::spawn({
+        let r = r.clone();
+        move || {
+            r.update(|v| [v.as_slice(), &[2]].concat());
+            r.set(vec![1, 2, 3]);
+        }
+    })
+    .join()
+    .unwrap();
+
+    let counter = Reactive::new(1);
+    let doubled = counter.derive_sync(|n| n * 2);
+    counter.update(|n| n + 1);
+
+    assert_eq!(vec![2, 3], *seen.lock().unwrap());
+    assert_eq!(6, sum.value());
+    assert_eq!(
+        4,
+        std::thread::spawn(move || doubled.value()).join().unwrap()
+    );
+
+    #[cfg(feature = "threadsafe")]
+    {
+        let merged = (&r, &counter).merge();
+        r.update(|_| vec![4]);
+        assert_eq!((vec![4], 2), merged.value());
+    }
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn when_stable_for_only_emits_settled_values() {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    let r = Reactive::new(0);
+    let stable = r.when_stable_for(Duration::from_millis(50));
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    stable.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+
+    // keeps changing faster than the stability window
+    for n in 1..=5 {
+        r.update(|_| n);
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    assert!(seen.lock().unwrap().is_empty());
+
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(vec![5], *seen.lock().unwrap());
+
+    // changed and changed back: the settled value is the same, nothing is emitted
+    r.update(|_| 6);
+    r.update(|_| 5);
+    std::thread::sleep(Duration::from_millis(200));
+    assert_eq!(vec![5], *seen.lock().unwrap());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn atomic_reactive_store_and_fetch_add_only_notify_on_change() {
+    use reactivate::AtomicReactive;
+    use std::sync::{Arc, Mutex};
+
+    let r = AtomicReactive::new(10i64);
+    let seen = Arc::new(Mutex::new(vec![]));
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+
+    assert_eq!(10, r.store(10));
+    assert_eq!(10, r.store(20));
+    assert_eq!(20, r.fetch_add(0));
+    assert_eq!(20, r.fetch_add(-5));
+
+    assert_eq!(15, r.load());
+    assert_eq!(vec![20, 15], *seen.lock().unwrap());
+
+    let flag = AtomicReactive::new(false);
+    assert!(!flag.store(true));
+    assert!(flag.load());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn atomic_reactive_observers_can_store_into_their_own_reactive() {
+    use reactivate::AtomicReactive;
+    use std::sync::{Arc, Mutex};
+
+    let r = AtomicReactive::new(0u32);
+    let seen = Arc::new(Mutex::new(vec![]));
+    r.add_observer({
+        let r = r.clone();
+        move |val| {
+            if *val % 2 == 1 {
+                r.store(val + 1);
+            }
+        }
+    });
+    r.add_observer({
+        let seen = seen.clone();
+        move |val| seen.lock().unwrap().push(*val)
+    });
+
+    r.store(1);
+
+    assert_eq!(2, r.load());
+    // the second observer finishes the first pass before the stored value is notified
+    assert_eq!(vec![1, 2], *seen.lock().unwrap());
+}
+
+#[test]
+fn partition_routes_values_by_predicate() {
+    let r = Reactive::new(String::from("error: disk full"));
+    let (errors, others) = r.partition(|line| line.starts_with("error"));
+
+    assert_eq!(Some(String::from("error: disk full")), errors.value());
+    assert_eq!(None, others.value());
+
+    r.update(|_| String::from("info: retrying"));
+    assert_eq!(None, errors.value());
+    assert_eq!(Some(String::from("info: retrying")), others.value());
+
+    let error_count = errors.derive(|e| e.is_some() as u32);
+    r.update(|_| String::from("error: still full"));
+    assert_eq!(1, error_count.value());
+    assert_eq!(3, r.descendant_count());
+}
+
+#[test]
+fn derive_with_context_only_recomputes_when_the_source_changes() {
+    let computations = Reactive::new(0);
+    let name = Reactive::new("zahash");
+    let locale = Reactive::new("en");
+
+    let greeting = name.derive_with_context(&locale, {
+        let computations = computations.clone();
+        move |name, locale| {
+            computations.update(|n| n + 1);
+            match *locale {
+                "en" => format!("hello {}", name),
+                _ => format!("hola {}", name),
+            }
+        }
+    });
+    let notified = Reactive::new(0);
+    greeting.add_observer({
+        let notified = notified.clone();
+        move |_| notified.update(|n| n + 1)
+    });
+
+    locale.update(|_| "es");
+    assert_eq!("hello zahash", greeting.value());
+    assert_eq!(1, computations.value());
+    assert_eq!(0, notified.value());
+
+    name.update(|_| "hazash");
+    assert_eq!("hola hazash", greeting.value());
+    assert_eq!(2, computations.value());
+    assert_eq!(1, notified.value());
+}
+
+#[test]
+fn derive_with_context_can_read_its_own_source() {
+    let r = Reactive::new(1);
+    let squared = r.derive_with_context(&r, |a, b| a * b);
+    assert_eq!(1, squared.value());
+
+    r.update(|_| 3);
+    assert_eq!(9, squared.value());
+
+    r.set(4);
+    assert_eq!(16, squared.value());
+}
+
+#[test]
+fn derive_with_context_can_read_a_reactive_derived_from_its_source() {
+    let r = Reactive::new(2);
+    let doubled = r.derive(|n| n * 2);
+    let sum = r.derive_with_context(&doubled, |a, b| a + b);
+    assert_eq!(6, sum.value());
+
+    // `doubled` observes `r` first, so `sum` sees it already updated
+    r.update(|_| 5);
+    assert_eq!(15, sum.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn reactive_can_be_a_lazily_initialized_static() {
+    use std::sync::LazyLock;
+
+    static COUNTER: LazyLock<Reactive<u32>> = LazyLock::new(|| Reactive::new(0));
+    static DOUBLED: LazyLock<Reactive<u32>> = LazyLock::new(|| COUNTER.derive(|n| n * 2));
+
+    // derived before any update, so the updates below have to propagate
+    assert_eq!(0, DOUBLED.value());
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| COUNTER.update_and_get(|n| n + 1));
+        }
+    });
+
+    assert_eq!(4, COUNTER.value());
+    assert_eq!(8, DOUBLED.value());
+}
+
+#[test]
+#[cfg(feature = "threadsafe")]
+fn reactive_can_be_a_const_initialized_static() {
+    use reactivate::StaticReactive;
+
+    static COUNTER: StaticReactive<u32> = Reactive::const_new(0);
+
+    // derived before any update, so the updates below have to propagate
+    let doubled = COUNTER.derive(|n| n * 2);
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| COUNTER.update_and_get(|n| n + 1));
+        }
+    });
+
+    assert_eq!(4, COUNTER.value());
+    assert_eq!(8, doubled.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn sync_reactive_can_be_a_const_initialized_static_in_any_build() {
+    use reactivate::{StaticReactive, SyncReactive};
+
+    static NAME: StaticReactive<String> = SyncReactive::const_new(String::new());
+
+    let len = NAME.derive(|name| name.len());
+    std::thread::spawn(|| NAME.set(String::from("crab")))
+        .join()
+        .unwrap();
+
+    assert_eq!("crab", NAME.value());
+    assert_eq!(4, len.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn reactive_base_can_be_a_const_initialized_static() {
+    use reactivate::SyncReactiveBase;
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    };
+
+    static COUNTER: Mutex<SyncReactiveBase<u32>> = Mutex::new(SyncReactiveBase::new(0));
+    static SEEN: AtomicU32 = AtomicU32::new(0);
+
+    COUNTER.lock().unwrap().add_observer(|n| {
+        SEEN.store(*n, Ordering::SeqCst);
+    });
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| COUNTER.lock().unwrap().update(|n| n + 1));
+        }
+    });
+
+    assert_eq!(4, COUNTER.lock().unwrap().value());
+    assert_eq!(4, SEEN.load(Ordering::SeqCst));
+}
+
+#[test]
+fn multi_derive_updates_both_outputs_from_one_observer() {
+    let r = Reactive::new(10);
+    let (doubled, label) = r.multi_derive(|n| n * 2, |n| format!("#{}", n));
+
+    let mut num_observers = 0;
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(1, num_observers);
+    assert_eq!(2, r.descendant_count());
+
+    r.update(|n| n + 1);
+
+    assert_eq!(22, doubled.value());
+    assert_eq!("#11", label.value());
+}
+
+#[test]
+fn with_invariant_is_enforced_on_every_write() {
+    let r = Reactive::new(-5).with_invariant(|n: i32| n.clamp(0, 100));
+    assert_eq!(0, r.value());
+
+    let notified = Reactive::new(0);
+    r.add_observer({
+        let notified = notified.clone();
+        move |_| notified.update(|n| n + 1)
+    });
+
+    r.set(150);
+    assert_eq!(100, r.value());
+
+    r.update(|n| n + 1);
+    assert_eq!(100, r.value());
+    assert_eq!(1, notified.value());
+
+    r.update_inplace(|n| *n = -20);
+    assert_eq!(0, r.value());
+
+    r.with_mut(|n| *n = 42);
+    assert_eq!(42, r.value());
+    assert_eq!(3, notified.value());
+}
+
+#[test]
+fn reactive_base_notifies_its_observers_on_change() {
+    use reactivate::ReactiveBase;
+    use std::sync::{Arc, Mutex};
+
+    let seen = Arc::new(Mutex::new(vec![]));
+    let mut base = ReactiveBase::new(vec![1, 2]);
+    base.add_observer({
+        let seen = seen.clone();
+        move |nums: &Vec<i32>| seen.lock().unwrap().push(nums.len())
+    });
+    assert!(base.has_observers());
+
+    base.set(vec![1]);
+    base.update(|nums| nums.clone());
+    base.update_inplace(|nums| nums.push(3));
+    base.update_inplace(|nums| nums.sort());
+    base.update_unchecked(|nums| nums.clone());
+    base.update_inplace_unchecked(|nums| nums.clear());
+    base.notify();
+
+    assert_eq!(vec![1, 2, 2, 0, 0], *seen.lock().unwrap());
+
+    base.clear_observers();
+    base.set(vec![4]);
+    assert_eq!(5, seen.lock().unwrap().len());
+    assert_eq!(vec![4], base.into_inner());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn local_reactive_base_observers_dont_need_to_be_send() {
+    use reactivate::{LocalReactiveBase, SyncReactiveBase};
+    use std::{cell::RefCell, rc::Rc};
+
+    let seen = Rc::new(RefCell::new(vec![]));
+    let mut local = LocalReactiveBase::new(1);
+    local.add_observer({
+        let seen = seen.clone();
+        move |n| seen.borrow_mut().push(*n)
+    });
+    local.update(|n| n + 1);
+    assert_eq!(vec![2], *seen.borrow());
+
+    let mut sync = SyncReactiveBase::new(1);
+    sync.add_observer(|n| assert_eq!(2, *n));
+    let sync = std::thread::spawn(move || {
+        sync.set(2);
+        sync
+    })
+    .join()
+    .unwrap();
+    assert_eq!(2, sync.value());
+}
+
+#[test]
+fn map_default_falls_back_to_the_default_value() {
+    let r: Reactive<Option<u32>> = Reactive::new(Some(3));
+    let d = r.map_default();
+    assert_eq!(3, d.value());
+
+    r.update(|_| None);
+    assert_eq!(0, d.value());
+
+    r.update(|_| Some(7));
+    assert_eq!(7, d.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn record_timed_history_keeps_the_latest_changes_in_order() {
+    let r = Reactive::new(0);
+    let history = r.record_timed_history(3);
+    assert!(history.is_empty());
+
+    for _ in 0..5 {
+        r.update(|n| n + 1);
+    }
+    r.update(|n| *n); // unchanged, not recorded
+
+    let entries = history.entries();
+    let values: Vec<_> = entries.iter().map(|(_, val)| *val).collect();
+    assert_eq!(vec![3, 4, 5], values);
+    assert!(entries.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    assert_eq!(2, history.intervals().len());
+
+    history.clear();
+    assert_eq!(0, history.len());
+
+    drop(history);
+    r.update(|n| n + 1);
+
+    let mut num_observers = 0;
+    r.with(|_, obs| num_observers = obs.len());
+    assert_eq!(0, num_observers);
+}
+
+#[test]
+fn shared_reactive_snapshots_are_not_affected_by_updates() {
+    use std::sync::Arc;
+
+    let r = Reactive::new_shared(vec![1, 2, 3]);
+    let len = r.derive_shared(|nums| nums.len());
+
+    let snapshot = r.value_arc();
+    r.update_cow(|nums| nums.push(4));
+    assert_eq!(vec![1, 2, 3], *snapshot);
+    assert_eq!(vec![1, 2, 3, 4], *r.value_arc());
+
+    // nobody else holds the current value so it is changed without a copy
+    drop(snapshot);
+    let before = Arc::as_ptr(&r.value_arc());
+    r.update_cow(|nums| nums.push(5));
+    assert_eq!(before, Arc::as_ptr(&r.value_arc()));
+
+    r.set(Arc::new(vec![]));
+    assert_eq!(0, len.value());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn subscribe_to_sink_sends_every_change_and_flushes_on_removal() {
+    use reactivate::{ReactiveSink, WriteSink};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Buffered {
+        pending: Vec<i32>,
+        flushed: Vec<i32>,
+    }
+
+    impl ReactiveSink<i32> for Buffered {
+        fn send(&mut self, value: &i32) {
+            self.pending.push(*value);
+        }
+
+        fn flush(&mut self) {
+            self.flushed.append(&mut self.pending);
+        }
+    }
+
+    let r = Reactive::new(0);
+    let collected = Arc::new(Mutex::new(vec![]));
+    let buffered = Arc::new(Mutex::new(Buffered::default()));
+    let written = Arc::new(Mutex::new(WriteSink::new(vec![])));
+
+    r.subscribe_to_sink(collected.clone());
+    let handle = r.subscribe_to_sink(buffered.clone());
+    r.subscribe_to_sink(written.clone());
+
+    r.update(|n| n + 1);
+    r.update(|n| n + 1);
+
+    assert_eq!(vec![1, 2], *collected.lock().unwrap());
+    assert_eq!(vec![1, 2], buffered.lock().unwrap().pending);
+    assert!(buffered.lock().unwrap().flushed.is_empty());
+
+    assert!(handle.remove());
+    assert_eq!(vec![1, 2], buffered.lock().unwrap().flushed);
+
+    r.clear_observers();
+    let written = Arc::try_unwrap(written).ok().unwrap().into_inner().unwrap();
+    assert_eq!("1\n2\n", String::from_utf8(written.into_inner()).unwrap());
+}
+
+#[test]
+fn merge_ref_does_not_clone_the_tuple_for_the_observers() {
+    use reactivate::MergeRef;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(PartialEq, Debug)]
+    struct Counted;
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::SeqCst);
+            Counted
+        }
+    }
+
+    let a = Reactive::new(Counted);
+    let b = Reactive::new(0);
+
+    let merged = (&a, &b).merge();
+    let _n = merged.derive(|(_, n)| *n);
+    let merged_ref = (&a, &b).merge_ref();
+    let n_ref = merged_ref.derive(|merged| merged.1);
+
+    CLONES.store(0, Ordering::SeqCst);
+    for _ in 0..3 {
+        b.update(|n| n + 1);
+    }
+
+    // `merge` clones the whole tuple (and `Counted` with it) for its observers on every change,
+    // `merge_ref` only clones the `Arc`
+    assert_eq!(3, CLONES.load(Ordering::SeqCst));
+    assert_eq!(3, n_ref.value());
+    assert_eq!((Counted, 3), *merged_ref.value());
+}
+
+#[test]
+fn merge_of_shared_reactives_shares_the_values() {
+    use std::sync::Arc;
+
+    // `NoDefault` values can be merged too
+    #[derive(Clone, PartialEq, Debug)]
+    struct NoDefault(Vec<u8>);
+
+    let a = Reactive::new_shared(NoDefault(vec![0; 1024]));
+    let b = Reactive::new(0);
+    let merged = (&a, &b).merge();
+    assert!(Arc::ptr_eq(&a.value_arc(), &merged.value().0));
+
+    a.set(Arc::new(NoDefault(vec![1; 1024])));
+    b.update(|n| n + 1);
+
+    let (val, n) = merged.value();
+    assert!(Arc::ptr_eq(&a.value_arc(), &val));
+    assert_eq!(1, n);
+}
@@ -0,0 +1,130 @@
+//! Model checked with loom, which explores every interleaving of the threads.
+//!
+//! ```text
+//! RUSTFLAGS="--cfg loom" cargo test --release --features threadsafe --test loom_tests
+//! ```
+#![cfg(all(loom, feature = "threadsafe"))]
+
+use loom::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+use reactivate::{Merge, Reactive};
+
+#[test]
+fn concurrent_update_inplace() {
+    loom::model(|| {
+        let r: Reactive<Vec<usize>> = Reactive::default();
+        let notified = Arc::new(AtomicUsize::new(0));
+
+        r.add_observer({
+            let notified = notified.clone();
+            move |_| {
+                notified.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let handle = thread::spawn({
+            let r = r.clone();
+            move || r.update_inplace(|nums| nums.push(1))
+        });
+        r.update_inplace(|nums| nums.push(2));
+        handle.join().unwrap();
+
+        let mut nums = r.value();
+        nums.sort();
+        assert_eq!(vec![1, 2], nums);
+        assert_eq!(2, notified.load(Ordering::SeqCst));
+    });
+}
+
+#[test]
+fn add_observer_racing_notify() {
+    loom::model(|| {
+        let r = Reactive::new(0);
+        let seen = Arc::new(AtomicUsize::new(0));
+
+        let handle = thread::spawn({
+            let r = r.clone();
+            let seen = seen.clone();
+            move || {
+                r.add_observer(move |val| {
+                    seen.store(*val, Ordering::SeqCst);
+                });
+            }
+        });
+        r.update(|n| n + 1);
+        handle.join().unwrap();
+
+        // the observer either saw the update or was added after it
+        assert!(seen.load(Ordering::SeqCst) <= 1);
+
+        r.update(|n| n + 1);
+        assert_eq!(2, seen.load(Ordering::SeqCst));
+    });
+}
+
+#[test]
+fn derive_racing_parent_updates() {
+    loom::model(|| {
+        let r = Reactive::new(1);
+
+        let handle = thread::spawn({
+            let r = r.clone();
+            move || r.update(|n| n + 1)
+        });
+        let d = r.derive(|n| n * 10);
+        handle.join().unwrap();
+
+        assert_eq!(r.value() * 10, d.value());
+    });
+}
+
+#[test]
+fn merge_racing_source_updates() {
+    loom::model(|| {
+        let a = Reactive::new(1);
+        let b = Reactive::new(10);
+
+        let handle = thread::spawn({
+            let a = a.clone();
+            move || a.update(|n| n + 1)
+        });
+        let merged = (&a, &b).merge();
+        handle.join().unwrap();
+
+        assert_eq!((a.value(), b.value()), merged.value());
+    });
+}
+
+#[test]
+fn observers_reading_their_reactive_during_concurrent_updates() {
+    loom::model(|| {
+        let r = Reactive::new(0);
+        let max_seen = Arc::new(AtomicUsize::new(0));
+
+        r.add_observer({
+            let r = r.clone();
+            let max_seen = max_seen.clone();
+            move |_| {
+                max_seen.fetch_max(r.value(), Ordering::SeqCst);
+            }
+        });
+
+        let handle = thread::spawn({
+            let r = r.clone();
+            move || r.update(|n| n + 1)
+        });
+        r.update(|n| n + 1);
+        handle.join().unwrap();
+
+        assert_eq!(2, r.value());
+        assert_eq!(2, max_seen.load(Ordering::SeqCst));
+
+        // the observer holds a clone of the reactive
+        r.clear_observers();
+    });
+}
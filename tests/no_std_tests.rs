@@ -0,0 +1,93 @@
+//! The core API that has to keep working without the `std` feature.
+//!
+//! ```text
+//! cargo test --no-default-features --test no_std_tests
+//! ```
+//!
+//! The test harness itself needs `std`, but the crate is built as `no_std` (+ `alloc`) here.
+#![cfg(not(feature = "threadsafe"))]
+
+use reactivate::{FnvBuildHasher, Reactive, ReactiveBase};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn new_set_and_update() {
+    let r = Reactive::new(10);
+    assert_eq!(10, r.value());
+
+    r.set(20);
+    assert_eq!(20, r.value());
+
+    r.update(|n| n + 1);
+    r.update_unchecked(|n| n * 2);
+    assert_eq!(42, r.value());
+}
+
+#[test]
+fn derive_follows_the_parent() {
+    let r = Reactive::new(10);
+    let d = r.derive(|n| n + 5);
+    let dd = d.derive2(|n| n * 2, |n| n.to_string());
+
+    r.update(|_| 20);
+
+    assert_eq!(25, d.value());
+    assert_eq!("50", dd.value());
+    assert_eq!(2, r.descendant_count());
+}
+
+#[test]
+fn observers_are_notified_and_removable() {
+    let r = Reactive::new(vec![1, 2, 3]);
+    let seen = Rc::new(RefCell::new(vec![]));
+
+    r.add_observer({
+        let seen = seen.clone();
+        move |nums: &Vec<i32>| seen.borrow_mut().push(nums.len())
+    });
+    let handle = r.observe_distinct_by(|nums| nums.first().copied(), |_| panic!("unchanged"));
+
+    r.update_inplace(|nums| nums.push(4));
+    r.update_inplace(|nums| nums.sort());
+    r.update_inplace_with_hasher(&FnvBuildHasher, |nums| nums.push(5));
+
+    assert_eq!(vec![4, 5], *seen.borrow());
+    assert!(handle.remove());
+
+    r.clear_observers();
+    r.notify();
+    assert_eq!(vec![4, 5], *seen.borrow());
+}
+
+#[test]
+fn observers_can_update_their_own_reactive() {
+    let r = Reactive::new(0);
+
+    r.add_observer({
+        let r = r.clone();
+        move |n| {
+            if *n < 3 {
+                r.update(|n| n + 1);
+            }
+        }
+    });
+    r.set(1);
+
+    assert_eq!(3, r.value());
+    r.clear_observers();
+}
+
+#[test]
+fn reactive_base_without_send_observers() {
+    let seen = Rc::new(RefCell::new(vec![]));
+    let mut base = ReactiveBase::new(1);
+    base.add_observer({
+        let seen = seen.clone();
+        move |n: &i32| seen.borrow_mut().push(*n)
+    });
+
+    base.update(|n| n + 1);
+    base.update(|n| *n);
+
+    assert_eq!(vec![2], *seen.borrow());
+}
@@ -0,0 +1,5 @@
+use reactivate::reactive;
+
+fn main() {
+    let _ = reactive!(10 =>);
+}
@@ -0,0 +1,11 @@
+//! Compile-fail tests for the macros, checked with trybuild.
+//!
+//! ```text
+//! TRYBUILD=overwrite cargo test --test ui_tests # to update the expected errors
+//! ```
+
+#[test]
+fn macros_reject_malformed_input() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}